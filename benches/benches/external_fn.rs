@@ -0,0 +1,176 @@
+//! Benchmark of calling native functions in a tight loop.
+
+#![feature(test)]
+
+extern crate test;
+
+use test::Bencher;
+
+fn noop() {}
+
+#[bench]
+fn external_unit_fn(b: &mut Bencher) -> runestick::Result<()> {
+    let mut module = runestick::Module::new();
+    module.function(&["noop"], noop)?;
+
+    let mut context = runestick::Context::with_default_modules()?;
+    context.install(&module)?;
+
+    let vm = rune_tests::vm_with_source(
+        &context,
+        r#"
+        pub fn main() {
+            let n = 0;
+
+            while n < 1000000 {
+                noop();
+                n += 1;
+            }
+        }
+        "#,
+    )?;
+
+    let entry = runestick::Hash::type_hash(&["main"]);
+
+    b.iter(|| {
+        let execution = vm.clone().execute(entry, ());
+        let mut execution = execution.expect("successful setup");
+        execution.complete().expect("successful execution")
+    });
+
+    Ok(())
+}
+
+fn static_label() -> &'static str {
+    "label"
+}
+
+fn string_label() -> String {
+    String::from("label")
+}
+
+/// Compare returning a `&'static str` against returning a `String`.
+fn external_label_fn<F>(b: &mut Bencher, f: F) -> runestick::Result<()>
+where
+    F: runestick::module::Function<()>,
+{
+    let mut module = runestick::Module::new();
+    module.function(&["label"], f)?;
+
+    let mut context = runestick::Context::with_default_modules()?;
+    context.install(&module)?;
+
+    let vm = rune_tests::vm_with_source(
+        &context,
+        r#"
+        pub fn main() {
+            let n = 0;
+
+            while n < 1000000 {
+                label();
+                n += 1;
+            }
+        }
+        "#,
+    )?;
+
+    let entry = runestick::Hash::type_hash(&["main"]);
+
+    b.iter(|| {
+        let execution = vm.clone().execute(entry, ());
+        let mut execution = execution.expect("successful setup");
+        execution.complete().expect("successful execution")
+    });
+
+    Ok(())
+}
+
+#[bench]
+fn external_static_str_fn(b: &mut Bencher) -> runestick::Result<()> {
+    external_label_fn(b, static_label)
+}
+
+#[bench]
+fn external_string_fn(b: &mut Bencher) -> runestick::Result<()> {
+    external_label_fn(b, string_label)
+}
+
+fn add_int(a: i64, b: i64) -> i64 {
+    a.wrapping_add(b)
+}
+
+fn mul_float(a: f64, b: f64) -> f64 {
+    a * b
+}
+
+#[bench]
+fn external_int_fn(b: &mut Bencher) -> runestick::Result<()> {
+    let mut module = runestick::Module::new();
+    module.function(&["add_int"], add_int)?;
+
+    let mut context = runestick::Context::with_default_modules()?;
+    context.install(&module)?;
+
+    let vm = rune_tests::vm_with_source(
+        &context,
+        r#"
+        pub fn main() {
+            let n = 0;
+            let acc = 0;
+
+            while n < 1000000 {
+                acc = add_int(acc, n);
+                n += 1;
+            }
+
+            acc
+        }
+        "#,
+    )?;
+
+    let entry = runestick::Hash::type_hash(&["main"]);
+
+    b.iter(|| {
+        let execution = vm.clone().execute(entry, ());
+        let mut execution = execution.expect("successful setup");
+        execution.complete().expect("successful execution")
+    });
+
+    Ok(())
+}
+
+#[bench]
+fn external_float_fn(b: &mut Bencher) -> runestick::Result<()> {
+    let mut module = runestick::Module::new();
+    module.function(&["mul_float"], mul_float)?;
+
+    let mut context = runestick::Context::with_default_modules()?;
+    context.install(&module)?;
+
+    let vm = rune_tests::vm_with_source(
+        &context,
+        r#"
+        pub fn main() {
+            let n = 0;
+            let acc = 1.0;
+
+            while n < 1000000 {
+                acc = mul_float(acc, 1.0);
+                n += 1;
+            }
+
+            acc
+        }
+        "#,
+    )?;
+
+    let entry = runestick::Hash::type_hash(&["main"]);
+
+    b.iter(|| {
+        let execution = vm.clone().execute(entry, ());
+        let mut execution = execution.expect("successful setup");
+        execution.complete().expect("successful execution")
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! Benchmark of building a large vector, with and without preallocating its
+//! capacity.
+
+#![feature(test)]
+
+extern crate test;
+
+use test::Bencher;
+
+#[bench]
+fn vec_push(b: &mut Bencher) -> runestick::Result<()> {
+    let vm = rune_tests::rune_vm! {
+        pub fn main() {
+            let v = [];
+            let n = 0;
+
+            while n < 100000 {
+                v.push(n);
+                n += 1;
+            }
+
+            v.len()
+        }
+    };
+
+    let entry = runestick::Hash::type_hash(&["main"]);
+
+    b.iter(|| {
+        let execution = vm.clone().execute(entry, ());
+        let mut execution = execution.expect("successful setup");
+        execution.complete().expect("successful execution")
+    });
+
+    Ok(())
+}
+
+#[bench]
+fn vec_push_with_capacity(b: &mut Bencher) -> runestick::Result<()> {
+    let vm = rune_tests::rune_vm! {
+        pub fn main() {
+            let v = Vec::with_capacity(100000);
+            let n = 0;
+
+            while n < 100000 {
+                v.push(n);
+                n += 1;
+            }
+
+            v.len()
+        }
+    };
+
+    let entry = runestick::Hash::type_hash(&["main"]);
+
+    b.iter(|| {
+        let execution = vm.clone().execute(entry, ());
+        let mut execution = execution.expect("successful setup");
+        execution.complete().expect("successful execution")
+    });
+
+    Ok(())
+}
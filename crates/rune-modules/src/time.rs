@@ -31,9 +31,14 @@
 //! }
 //! ```
 
-use runestick::{Any, ContextError, Module};
+use runestick::{ContextError, Module};
+use std::time::Duration;
 
 /// Construct the `time` module.
+///
+/// Durations are the same `Duration` type as the one in `std::time`, so
+/// `time::Duration::from_secs` is kept as an alias for
+/// `std::time::Duration::from_secs`.
 pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     let mut module = Module::with_crate("time");
     module.function(&["Duration", "from_secs"], Duration::from_secs)?;
@@ -41,21 +46,7 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     Ok(module)
 }
 
-#[derive(Debug, Clone, Copy, Any)]
-struct Duration {
-    inner: tokio::time::Duration,
-}
-
-impl Duration {
-    /// Construct a duration from seconds.
-    fn from_secs(secs: u64) -> Self {
-        Self {
-            inner: tokio::time::Duration::from_secs(secs),
-        }
-    }
-}
-
-/// Convert any value to a json string.
+/// Sleep for the given duration.
 async fn sleep(duration: &Duration) {
-    tokio::time::sleep(duration.inner).await;
+    tokio::time::sleep(*duration).await;
 }
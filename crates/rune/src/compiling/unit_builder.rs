@@ -452,6 +452,7 @@ impl UnitBuilder {
             CompileMetaKind::AsyncBlock { .. } => (),
             CompileMetaKind::Const { .. } => (),
             CompileMetaKind::ConstFn { .. } => (),
+            CompileMetaKind::Global { .. } => (),
             CompileMetaKind::Import { .. } => (),
         }
 
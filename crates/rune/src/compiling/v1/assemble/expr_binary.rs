@@ -180,6 +180,10 @@ fn compile_assign_binop(
                 }
             }
         }
+        // <expr>[<index>] <op> <value>
+        ast::Expr::Index(expr_index) => {
+            return compile_assign_index_binop(c, expr_index, rhs, bin_op, needs);
+        }
         _ => None,
     };
 
@@ -220,3 +224,77 @@ fn compile_assign_binop(
 
     Ok(())
 }
+
+/// Compile an assign operation on an index, like `values[n] += 1`.
+///
+/// This is compiled into an index get, followed by the binary operation and
+/// an index set. The target and the index are stored in anonymous variables so
+/// that they're only evaluated once.
+fn compile_assign_index_binop(
+    c: &mut Compiler<'_>,
+    expr_index: &ast::ExprIndex,
+    rhs: &ast::Expr,
+    bin_op: ast::BinOp,
+    needs: Needs,
+) -> CompileResult<()> {
+    let span = expr_index.span().join(rhs.span());
+
+    let op = match bin_op {
+        ast::BinOp::AddAssign => InstOp::Add,
+        ast::BinOp::SubAssign => InstOp::Sub,
+        ast::BinOp::MulAssign => InstOp::Mul,
+        ast::BinOp::DivAssign => InstOp::Div,
+        ast::BinOp::RemAssign => InstOp::Rem,
+        ast::BinOp::BitAndAssign => InstOp::BitAnd,
+        ast::BinOp::BitXorAssign => InstOp::BitXor,
+        ast::BinOp::BitOrAssign => InstOp::BitOr,
+        ast::BinOp::ShlAssign => InstOp::Shl,
+        ast::BinOp::ShrAssign => InstOp::Shr,
+        _ => {
+            return Err(CompileError::new(
+                span,
+                CompileErrorKind::UnsupportedBinaryExpr,
+            ));
+        }
+    };
+
+    expr_index.target.assemble(c, Needs::Value)?.apply(c)?;
+    let target = c.scopes.decl_anon(expr_index.target.span())?;
+
+    expr_index.index.assemble(c, Needs::Value)?.apply(c)?;
+    let index = c.scopes.decl_anon(expr_index.index.span())?;
+
+    c.asm.push(
+        Inst::IndexGet {
+            target: InstAddress::Offset(target),
+            index: InstAddress::Offset(index),
+        },
+        span,
+    );
+    c.scopes.decl_anon(span)?;
+
+    rhs.assemble(c, Needs::Value)?.apply(c)?;
+    c.scopes.decl_anon(rhs.span())?;
+
+    c.asm.push(
+        Inst::Op {
+            op,
+            a: InstAddress::Top,
+            b: InstAddress::Top,
+        },
+        span,
+    );
+    c.scopes.undecl_anon(span, 1)?;
+
+    c.asm.push(Inst::Copy { offset: target }, span);
+    c.asm.push(Inst::Copy { offset: index }, span);
+    c.asm.push(Inst::IndexSet, span);
+    c.asm.push(Inst::PopN { count: 2 }, span);
+    c.scopes.undecl_anon(span, 3)?;
+
+    if needs.value() {
+        c.asm.push(Inst::unit(), span);
+    }
+
+    Ok(())
+}
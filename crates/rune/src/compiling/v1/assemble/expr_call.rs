@@ -132,7 +132,19 @@ impl Assemble for ast::ExprCall {
                         .remove_tuple_call_parens(c.source_id, span, tuple, c.context());
                 }
             }
-            CompileMetaKind::Function { .. } => (),
+            CompileMetaKind::Function { type_hash, .. } => {
+                if c.context.is_const_fn(*type_hash) {
+                    let from = c.query.item_for(self)?;
+
+                    if let Some(value) =
+                        c.try_call_native_const_fn(self, &meta, &from, self.args.as_slice())?
+                    {
+                        value.assemble_const(c, Needs::Value, self.span())?;
+                        c.scopes.pop(guard, span)?;
+                        return Ok(Asm::top(span));
+                    }
+                }
+            }
             CompileMetaKind::ConstFn { id, .. } => {
                 let from = c.query.item_for(self)?;
                 let const_fn = c.query.const_fn_for((self.span(), *id))?;
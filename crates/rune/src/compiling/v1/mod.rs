@@ -10,8 +10,8 @@ use crate::{
     UnitBuilder,
 };
 use runestick::{
-    CompileItem, CompileMeta, CompileMetaKind, ConstValue, Context, Inst, InstValue, Item, Label,
-    Source, Span, TypeCheck,
+    CompileItem, CompileMeta, CompileMetaKind, ConstValue, Context, Hash, Inst, InstValue, Item,
+    Label, Source, Span, TypeCheck,
 };
 use std::rc::Rc;
 use std::sync::Arc;
@@ -210,6 +210,16 @@ impl<'a> Compiler<'a> {
                 CompileMetaKind::Const { const_value, .. } => {
                     const_value.assemble_const(self, Needs::Value, span)?;
                 }
+                CompileMetaKind::Global { hash } => {
+                    self.asm.push_with_comment(
+                        Inst::Call {
+                            hash: *hash,
+                            args: 0,
+                        },
+                        span,
+                        meta.to_string(),
+                    );
+                }
                 _ => {
                     return Err(CompileError::expected_meta(
                         span,
@@ -941,6 +951,85 @@ impl<'a> Compiler<'a> {
         let value = interpreter.eval_value(&query_const_fn.ir_fn.ir, Used::Used)?;
         Ok(value.into_const(spanned)?)
     }
+
+    /// Try to evaluate a call to a native function registered through
+    /// [Module::const_function][runestick::Module::const_function] at compile
+    /// time.
+    ///
+    /// Returns `None` if any of the arguments isn't a constant expression or
+    /// refers to a local variable, or if the function fails when called. In
+    /// that case the call should be compiled as usual.
+    pub(crate) fn try_call_native_const_fn<S>(
+        &mut self,
+        spanned: S,
+        meta: &CompileMeta,
+        from: &CompileItem,
+        args: &[(ast::Expr, Option<T![,]>)],
+    ) -> Result<Option<ConstValue>, CompileError>
+    where
+        S: Copy + Spanned,
+    {
+        let mut ir_query = self.query.as_ir_query();
+
+        let mut compiler = IrCompiler {
+            storage: self.storage.clone(),
+            source: self.source.clone(),
+            query: &mut *ir_query,
+        };
+
+        let mut compiled = Vec::new();
+
+        for (a, _) in args {
+            let ir = match compiler.compile(a) {
+                Ok(ir) => ir,
+                Err(..) => return Ok(None),
+            };
+
+            // NB: the interpreter has no view of local variables, so a local
+            // which shadows a constant would otherwise resolve to the
+            // constant.
+            if ir.any_name(&mut |name| self.scopes.contains_var(name)) {
+                return Ok(None);
+            }
+
+            compiled.push(ir);
+        }
+
+        let mut interpreter = IrInterpreter {
+            budget: IrBudget::new(1_000_000),
+            scopes: Default::default(),
+            module: from.module.clone(),
+            item: from.item.clone(),
+            consts: self.consts.clone(),
+            query: &mut *ir_query,
+        };
+
+        let mut values = Vec::new();
+
+        for ir in compiled {
+            let value = match interpreter.eval_value(&ir, Used::Used) {
+                Ok(value) => value,
+                Err(..) => return Ok(None),
+            };
+
+            match value.into_const(spanned) {
+                Ok(value) => values.push(value),
+                Err(..) => return Ok(None),
+            }
+        }
+
+        drop(ir_query);
+
+        let hash = Hash::type_hash(&meta.item.item);
+        let runtime = self.query.const_fn_runtime(self.context);
+
+        // NB: errors are deferred to runtime, where they can be handled by
+        // the script like for any other call.
+        match self.context.call_const_fn(&runtime, hash, values) {
+            Ok(value) => Ok(Some(value)),
+            Err(..) => Ok(None),
+        }
+    }
 }
 
 /// Test if the given pattern is open or not.
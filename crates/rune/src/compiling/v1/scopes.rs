@@ -242,6 +242,14 @@ impl Scopes {
         Ok(None)
     }
 
+    /// Test if a local with the given name is declared in any scope, without
+    /// marking it as used.
+    pub(crate) fn contains_var(&self, name: &str) -> bool {
+        self.scopes
+            .iter()
+            .any(|scope| scope.locals.contains_key(name))
+    }
+
     /// Try to take the local with the given name. Returns `None` if it's
     /// missing.
     pub(crate) fn try_take_var(
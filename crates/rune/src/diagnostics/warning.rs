@@ -4,7 +4,7 @@ use std::fmt;
 use thiserror::Error;
 
 /// Compilation warning.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Warning {
     /// The last warning reported in the chain.
     pub(super) last: Option<usize>,
@@ -38,6 +38,7 @@ impl Warning {
             WarningKind::TemplateWithoutExpansions { span, .. } => *span,
             WarningKind::RemoveTupleCallParams { span, .. } => *span,
             WarningKind::UnecessarySemiColon { span, .. } => *span,
+            WarningKind::MacroWarning { span, .. } => *span,
         }
     }
 }
@@ -55,7 +56,7 @@ impl error::Error for Warning {
 }
 
 /// Compilation warning kind.
-#[derive(Debug, Clone, Copy, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum WarningKind {
     /// Item identified by the span is not used.
     #[error("not used")]
@@ -98,4 +99,12 @@ pub enum WarningKind {
         /// Span where the semi-colon is.
         span: Span,
     },
+    /// A warning reported by a macro while it was being expanded.
+    #[error("{message}")]
+    MacroWarning {
+        /// Span the warning is associated with.
+        span: Span,
+        /// The message of the warning.
+        message: Box<str>,
+    },
 }
@@ -154,19 +154,26 @@ impl EmitDiagnostics for VmError {
                     ],
                 )
             }
-            VmErrorKind::BadArgumentCount { actual, expected } => {
+            VmErrorKind::BadArgumentCount {
+                actual,
+                expected,
+                function,
+            } => {
                 labels.push(
                     Label::primary(source_id, span.range())
                         .with_message("in this function call".to_string()),
                 );
 
-                (
-                    format!("wrong number of arguments"),
-                    vec![
-                        format!("expected `{}`", expected),
-                        format!("got `{}`", actual),
-                    ],
-                )
+                let mut notes = vec![
+                    format!("expected `{}`", expected),
+                    format!("got `{}`", actual),
+                ];
+
+                if let Some(function) = function {
+                    notes.push(format!("calling native function `{}`", function));
+                }
+
+                (format!("wrong number of arguments"), notes)
             }
             e => {
                 labels.push(
@@ -299,6 +306,10 @@ where
 
             None
         }
+        WarningKind::MacroWarning { span, message } => {
+            labels.push(Label::primary(this.source_id(), span.range()).with_message(&**message));
+            None
+        }
     };
 
     if let Some(context) = context {
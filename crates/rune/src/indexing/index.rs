@@ -365,6 +365,8 @@ impl<'a> Indexer<'a> {
             source: self.source.clone(),
             query: self.query.clone(),
             consts: self.consts.clone(),
+            source_id: self.source_id,
+            diagnostics: self.diagnostics,
         };
 
         let expanded = compiler.eval_macro::<T>(ast)?;
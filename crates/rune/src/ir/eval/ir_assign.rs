@@ -10,10 +10,11 @@ impl IrEval for ir::IrAssign {
     ) -> Result<Self::Output, IrEvalOutcome> {
         interp.budget.take(self)?;
         let value = self.value.eval(interp, used)?;
+        let overflow_policy = interp.query.overflow_policy();
 
-        interp
-            .scopes
-            .mut_target(&self.target, move |t| self.op.assign(self, t, value))?;
+        interp.scopes.mut_target(&self.target, move |t| {
+            self.op.assign(self, overflow_policy, t, value)
+        })?;
 
         Ok(IrValue::Unit)
     }
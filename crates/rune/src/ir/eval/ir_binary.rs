@@ -8,24 +8,28 @@ impl IrEval for ir::IrBinary {
         interp: &mut IrInterpreter<'_>,
         used: Used,
     ) -> Result<Self::Output, IrEvalOutcome> {
-        use std::ops::{Add, Mul, Shl, Shr, Sub};
+        use std::ops::{Shl, Shr};
 
         let span = self.span();
         interp.budget.take(span)?;
 
         let a = self.lhs.eval(interp, used)?;
         let b = self.rhs.eval(interp, used)?;
+        let overflow_policy = interp.query.overflow_policy();
 
         match (a, b) {
             (IrValue::Integer(a), IrValue::Integer(b)) => match self.op {
                 ir::IrBinaryOp::Add => {
-                    return Ok(IrValue::Integer(a.add(&b)));
+                    let n = ir::IrIntegerOp::Add.eval(span, overflow_policy, &a, &b)?;
+                    return Ok(IrValue::Integer(n));
                 }
                 ir::IrBinaryOp::Sub => {
-                    return Ok(IrValue::Integer(a.sub(&b)));
+                    let n = ir::IrIntegerOp::Sub.eval(span, overflow_policy, &a, &b)?;
+                    return Ok(IrValue::Integer(n));
                 }
                 ir::IrBinaryOp::Mul => {
-                    return Ok(IrValue::Integer(a.mul(&b)));
+                    let n = ir::IrIntegerOp::Mul.eval(span, overflow_policy, &a, &b)?;
+                    return Ok(IrValue::Integer(n));
                 }
                 ir::IrBinaryOp::Div => {
                     let number = a
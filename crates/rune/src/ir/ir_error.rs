@@ -107,6 +107,9 @@ pub enum IrErrorKind {
     /// Exceeded evaluation budget.
     #[error("evaluation budget exceeded")]
     BudgetExceeded,
+    /// Integer overflow.
+    #[error("integer overflow")]
+    IntegerOverflow,
     /// Integer underflow.
     #[error("integer underflow")]
     IntegerUnderflow,
@@ -1,6 +1,6 @@
 use crate::parsing::Id;
 use crate::query::{BuiltInMacro, QueryConstFn, QueryError, Used};
-use runestick::{CompileMeta, Item, Span};
+use runestick::{CompileMeta, Item, OverflowPolicy, Span};
 use std::sync::Arc;
 
 /// Query interface for the interpreter.
@@ -22,4 +22,7 @@ pub(crate) trait IrQuery {
 
     /// Query for the constant function related to the given id.
     fn const_fn_for(&self, span: Span, id: Option<Id>) -> Result<Arc<QueryConstFn>, QueryError>;
+
+    /// How integer arithmetic that overflows is handled.
+    fn overflow_policy(&self) -> OverflowPolicy;
 }
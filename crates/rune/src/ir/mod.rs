@@ -20,7 +20,8 @@ pub(crate) use self::ir_interpreter::IrBudget;
 pub(crate) use self::ir_query::IrQuery;
 
 use crate::Spanned;
-use runestick::{ConstValue, Span};
+use num::ToPrimitive as _;
+use runestick::{ConstValue, OverflowPolicy, Span};
 
 macro_rules! decl_kind {
     (
@@ -64,6 +65,44 @@ impl Ir {
             kind: IrKind::from(kind),
         }
     }
+
+    /// Test if any variable referenced by this instruction, or by any
+    /// instruction nested in it, matches the given predicate.
+    pub(crate) fn any_name(&self, f: &mut dyn FnMut(&str) -> bool) -> bool {
+        match &self.kind {
+            IrKind::Scope(scope) => scope.any_name(f),
+            IrKind::Binary(binary) => binary.lhs.any_name(f) || binary.rhs.any_name(f),
+            IrKind::Decl(decl) => decl.value.any_name(f),
+            IrKind::Set(set) => set.target.any_name(f) || set.value.any_name(f),
+            IrKind::Assign(assign) => assign.target.any_name(f) || assign.value.any_name(f),
+            IrKind::Template(template) => template.components.iter().any(|c| match c {
+                IrTemplateComponent::Ir(ir) => ir.any_name(f),
+                IrTemplateComponent::String(..) => false,
+            }),
+            IrKind::Name(name) => f(name),
+            IrKind::Target(target) => target.any_name(f),
+            IrKind::Value(..) => false,
+            IrKind::Branches(branches) => {
+                branches
+                    .branches
+                    .iter()
+                    .any(|(condition, scope)| condition.any_name(f) || scope.any_name(f))
+                    || matches!(&branches.default_branch, Some(scope) if scope.any_name(f))
+            }
+            IrKind::Loop(ir_loop) => {
+                matches!(&ir_loop.condition, Some(condition) if condition.any_name(f))
+                    || ir_loop.body.any_name(f)
+            }
+            IrKind::Break(ir_break) => match &ir_break.kind {
+                IrBreakKind::Ir(ir) => ir.any_name(f),
+                _ => false,
+            },
+            IrKind::Vec(vec) => vec.items.iter().any(|ir| ir.any_name(f)),
+            IrKind::Tuple(tuple) => tuple.items.iter().any(|ir| ir.any_name(f)),
+            IrKind::Object(object) => object.assignments.iter().any(|(_, ir)| ir.any_name(f)),
+            IrKind::Call(call) => call.args.iter().any(|ir| ir.any_name(f)),
+        }
+    }
 }
 
 /// The target of a set operation.
@@ -76,6 +115,18 @@ pub struct IrTarget {
     pub(crate) kind: IrTargetKind,
 }
 
+impl IrTarget {
+    /// Test if the variable at the root of this target matches the given
+    /// predicate.
+    fn any_name(&self, f: &mut dyn FnMut(&str) -> bool) -> bool {
+        match &self.kind {
+            IrTargetKind::Name(name) => f(name),
+            IrTargetKind::Field(target, _) => target.any_name(f),
+            IrTargetKind::Index(target, _) => target.any_name(f),
+        }
+    }
+}
+
 /// The kind of the target.
 #[derive(Debug, Clone)]
 pub enum IrTargetKind {
@@ -151,6 +202,13 @@ pub struct IrScope {
     pub(crate) last: Option<Box<Ir>>,
 }
 
+impl IrScope {
+    fn any_name(&self, f: &mut dyn FnMut(&str) -> bool) -> bool {
+        self.instructions.iter().any(|ir| ir.any_name(f))
+            || matches!(&self.last, Some(ir) if ir.any_name(f))
+    }
+}
+
 /// A binary operation.
 #[derive(Debug, Clone, Spanned)]
 pub struct IrBinary {
@@ -240,6 +298,15 @@ pub enum IrCondition {
     Let(IrLet),
 }
 
+impl IrCondition {
+    fn any_name(&self, f: &mut dyn FnMut(&str) -> bool) -> bool {
+        match self {
+            IrCondition::Ir(ir) => ir.any_name(f),
+            IrCondition::Let(ir_let) => ir_let.ir.any_name(f),
+        }
+    }
+}
+
 /// A pattern match.
 #[derive(Debug, Clone, Spanned)]
 pub struct IrLet {
@@ -365,6 +432,87 @@ pub enum IrBinaryOp {
     Gte,
 }
 
+/// An integer operation which might overflow.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum IrIntegerOp {
+    /// `+`.
+    Add,
+    /// `-`.
+    Sub,
+    /// `*`.
+    Mul,
+}
+
+impl IrIntegerOp {
+    /// The message a panicking overflow of this operation raises.
+    fn overflow_message(self) -> &'static str {
+        match self {
+            Self::Add => "attempt to add with overflow",
+            Self::Sub => "attempt to subtract with overflow",
+            Self::Mul => "attempt to multiply with overflow",
+        }
+    }
+
+    /// Perform the operation according to the given overflow policy, like the
+    /// virtual machine does.
+    ///
+    /// Operands outside of the range of integers supported by the virtual
+    /// machine are computed without bounds, and are checked once they're
+    /// converted into a constant.
+    pub(crate) fn eval<S>(
+        self,
+        spanned: S,
+        overflow_policy: OverflowPolicy,
+        a: &num::BigInt,
+        b: &num::BigInt,
+    ) -> Result<num::BigInt, IrError>
+    where
+        S: Spanned,
+    {
+        use std::convert::TryFrom as _;
+        use std::ops::{Add, Mul, Sub};
+
+        let (a, b) = match (a.to_i64(), b.to_i64()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => {
+                return Ok(match self {
+                    Self::Add => a.add(b),
+                    Self::Sub => a.sub(b),
+                    Self::Mul => a.mul(b),
+                })
+            }
+        };
+
+        // NB: the exact result of any of these operations on two 64-bit
+        // integers fits in 128 bits.
+        let (a, b) = (i128::from(a), i128::from(b));
+
+        let exact = match self {
+            Self::Add => a + b,
+            Self::Sub => a - b,
+            Self::Mul => a * b,
+        };
+
+        let n = match i64::try_from(exact) {
+            Ok(n) => n,
+            Err(..) => match overflow_policy {
+                OverflowPolicy::Checked if exact < 0 => {
+                    return Err(IrError::new(spanned, IrErrorKind::IntegerUnderflow));
+                }
+                OverflowPolicy::Checked => {
+                    return Err(IrError::new(spanned, IrErrorKind::IntegerOverflow));
+                }
+                OverflowPolicy::Wrapping => exact as i64,
+                OverflowPolicy::Panicking => {
+                    return Err(IrError::msg(spanned, self.overflow_message()));
+                }
+            },
+        };
+
+        Ok(n.into())
+    }
+}
+
 /// An assign operation.
 #[derive(Debug, Clone, Copy)]
 pub enum IrAssignOp {
@@ -387,6 +535,7 @@ impl IrAssignOp {
     pub(crate) fn assign<S>(
         self,
         spanned: S,
+        overflow_policy: OverflowPolicy,
         target: &mut IrValue,
         operand: IrValue,
     ) -> Result<(), IrError>
@@ -395,7 +544,7 @@ impl IrAssignOp {
     {
         if let IrValue::Integer(target) = target {
             if let IrValue::Integer(operand) = operand {
-                return Ok(self.assign_int(spanned, target, operand)?);
+                return Ok(self.assign_int(spanned, overflow_policy, target, operand)?);
             }
         }
 
@@ -406,6 +555,7 @@ impl IrAssignOp {
     fn assign_int<S>(
         self,
         spanned: S,
+        overflow_policy: OverflowPolicy,
         target: &mut num::BigInt,
         operand: num::BigInt,
     ) -> Result<(), IrError>
@@ -413,17 +563,17 @@ impl IrAssignOp {
         S: Copy + Spanned,
     {
         use std::convert::TryFrom;
-        use std::ops::{AddAssign, MulAssign, ShlAssign, ShrAssign, SubAssign};
+        use std::ops::{ShlAssign, ShrAssign};
 
         match self {
             IrAssignOp::Add => {
-                target.add_assign(operand);
+                *target = IrIntegerOp::Add.eval(spanned, overflow_policy, target, &operand)?;
             }
             IrAssignOp::Sub => {
-                target.sub_assign(operand);
+                *target = IrIntegerOp::Sub.eval(spanned, overflow_policy, target, &operand)?;
             }
             IrAssignOp::Mul => {
-                target.mul_assign(operand);
+                *target = IrIntegerOp::Mul.eval(spanned, overflow_policy, target, &operand)?;
             }
             IrAssignOp::Div => {
                 *target = target
@@ -70,3 +70,16 @@ where
 {
     current_context(|ctx| ctx.stringify(stream).to_string())
 }
+
+/// Report a warning associated with the given span from the current macro.
+///
+/// # Panics
+///
+/// This will panic if it's called outside of a macro context.
+pub fn warning<S, M>(spanned: S, message: M)
+where
+    S: Spanned,
+    M: Into<Box<str>>,
+{
+    current_context(|ctx| ctx.warning(spanned, message))
+}
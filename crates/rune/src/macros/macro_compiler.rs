@@ -5,9 +5,10 @@ use crate::query::Query;
 use crate::shared::Consts;
 use crate::CompileResult;
 use crate::{
-    ast, CompileError, CompileErrorKind, IrError, Options, Parse, ParseError, Parser, Spanned as _,
+    ast, CompileError, CompileErrorKind, Diagnostics, IrError, Options, Parse, ParseError, Parser,
+    Spanned as _, WarningKind,
 };
-use runestick::{CompileItem, Context, Hash, Source};
+use runestick::{CompileItem, Context, Hash, Source, SourceId};
 use std::sync::Arc;
 
 pub(crate) struct MacroCompiler<'a> {
@@ -18,6 +19,8 @@ pub(crate) struct MacroCompiler<'a> {
     pub(crate) source: Arc<Source>,
     pub(crate) query: Query,
     pub(crate) consts: Consts,
+    pub(crate) source_id: SourceId,
+    pub(crate) diagnostics: &'a mut Diagnostics,
 }
 
 impl MacroCompiler<'_> {
@@ -66,10 +69,17 @@ impl MacroCompiler<'_> {
             item: self.item.clone(),
             query: self.query.clone(),
             consts: self.consts.clone(),
+            warnings: Default::default(),
         };
 
+        let warnings = macro_context.warnings.clone();
         let result = crate::macros::with_context(macro_context, || handler(input_stream));
 
+        for (span, message) in warnings.take() {
+            self.diagnostics
+                .warning(self.source_id, WarningKind::MacroWarning { span, message });
+        }
+
         let output = match result {
             Ok(output) => output,
             Err(error) => {
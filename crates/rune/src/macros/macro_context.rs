@@ -15,8 +15,13 @@ use query::Query;
 use runestick::{CompileItem, Source, Span};
 use std::cell::RefCell;
 use std::fmt;
+use std::rc::Rc;
 use std::sync::Arc;
 
+/// Warnings reported by a macro, shared with the macro compiler so that they
+/// can be collected after the macro has been expanded.
+type Warnings = Rc<RefCell<Vec<(Span, Box<str>)>>>;
+
 thread_local! {
     static MACRO_CONTEXT: RefCell<Option<MacroContext>> = RefCell::new(None);
 }
@@ -110,6 +115,8 @@ pub struct MacroContext {
     pub(crate) item: Arc<CompileItem>,
     /// Constants storage.
     pub(crate) consts: Consts,
+    /// Warnings reported by the macro.
+    pub(crate) warnings: Warnings,
 }
 
 impl MacroContext {
@@ -123,6 +130,7 @@ impl MacroContext {
             query: Default::default(),
             item: Default::default(),
             consts: Default::default(),
+            warnings: Default::default(),
         }
     }
 
@@ -171,6 +179,20 @@ impl MacroContext {
         }
     }
 
+    /// Report a warning associated with the given span.
+    ///
+    /// Warnings don't cause the macro expansion to fail, and are reported
+    /// together with other compiler warnings once the macro has been expanded.
+    pub fn warning<S, M>(&self, spanned: S, message: M)
+    where
+        S: Spanned,
+        M: Into<Box<str>>,
+    {
+        self.warnings
+            .borrow_mut()
+            .push((spanned.span(), message.into()));
+    }
+
     /// Stringify the given token stream.
     pub fn stringify<'a, T>(&'a self, tokens: &T) -> Stringify<'_>
     where
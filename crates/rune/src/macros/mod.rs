@@ -9,7 +9,7 @@ mod storage;
 mod token_stream;
 
 pub use self::format_args::FormatArgs;
-pub use self::functions::{eval, resolve, stringify, to_tokens};
+pub use self::functions::{eval, resolve, stringify, to_tokens, warning};
 pub use self::macro_context::{with_context, IntoLit, MacroContext};
 pub use self::quote_fn::{quote_fn, Quote};
 pub use self::storage::Storage;
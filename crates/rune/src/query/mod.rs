@@ -14,7 +14,8 @@ use runestick::format;
 use runestick::{
     Call, CompileItem, CompileMeta, CompileMetaCapture, CompileMetaEmpty, CompileMetaKind,
     CompileMetaStruct, CompileMetaTuple, CompileMod, CompileSource, Component, ComponentRef,
-    Context, Hash, IntoComponent, Item, Location, Names, Source, SourceId, Span, Visibility,
+    Context, Hash, IntoComponent, Item, Location, Names, OverflowPolicy, RuntimeContext, Source,
+    SourceId, Span, Visibility,
 };
 use std::cell::{RefCell, RefMut};
 use std::collections::VecDeque;
@@ -101,6 +102,10 @@ impl IrQuery for QueryInner {
     fn const_fn_for(&self, span: Span, id: Option<Id>) -> Result<Arc<QueryConstFn>, QueryError> {
         QueryInner::const_fn_for(self, span, id)
     }
+
+    fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
 }
 
 #[derive(Clone, Default)]
@@ -116,6 +121,7 @@ impl Query {
         unit: UnitBuilder,
         consts: Consts,
         gen: Gen,
+        overflow_policy: OverflowPolicy,
     ) -> Self {
         Self {
             inner: Rc::new(RefCell::new(QueryInner {
@@ -134,6 +140,8 @@ impl Query {
                 items: HashMap::new(),
                 names: Names::default(),
                 modules: HashMap::new(),
+                overflow_policy,
+                const_fn_runtime: None,
             })),
         }
     }
@@ -145,6 +153,18 @@ impl Query {
         RefMut::map(inner, |inner| inner)
     }
 
+    /// Get the runtime context used to call native constant functions.
+    ///
+    /// It's constructed from the given context the first time it's needed, and
+    /// then reused for the rest of the compilation.
+    pub(crate) fn const_fn_runtime(&self, context: &Context) -> Arc<RuntimeContext> {
+        self.inner
+            .borrow_mut()
+            .const_fn_runtime
+            .get_or_insert_with(|| Arc::new(context.runtime()))
+            .clone()
+    }
+
     /// Insert the given compile meta.
     pub(crate) fn insert_meta(&self, spanned: Span, meta: CompileMeta) -> Result<(), QueryError> {
         let mut inner = self.inner.borrow_mut();
@@ -652,6 +672,12 @@ struct QueryInner {
     names: Names,
     /// Modules and associated metadata.
     modules: HashMap<Item, Arc<CompileMod>>,
+    /// How integer arithmetic that overflows is handled in constant
+    /// expressions.
+    overflow_policy: OverflowPolicy,
+    /// Runtime context used to call native constant functions, constructed
+    /// the first time one is called.
+    const_fn_runtime: Option<Arc<RuntimeContext>>,
 }
 
 impl Default for QueryInner {
@@ -672,6 +698,8 @@ impl Default for QueryInner {
             items: Default::default(),
             names: Default::default(),
             modules: Default::default(),
+            overflow_policy: Default::default(),
+            const_fn_runtime: None,
         }
     }
 }
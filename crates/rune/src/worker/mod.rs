@@ -62,7 +62,14 @@ impl<'a> Worker<'a> {
             source_loader,
             consts: consts.clone(),
             queue: VecDeque::new(),
-            query: Query::new(visitor, storage.clone(), unit, consts, gen.clone()),
+            query: Query::new(
+                visitor,
+                storage.clone(),
+                unit,
+                consts,
+                gen.clone(),
+                context.overflow_policy(),
+            ),
             storage,
             gen,
             loaded: HashMap::new(),
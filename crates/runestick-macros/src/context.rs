@@ -30,8 +30,12 @@ pub(crate) struct FieldAttrs {
     /// `#[rune(..)]` to generate a protocol function.
     pub(crate) protocols: Vec<FieldProtocol>,
     /// `#[rune(copy)]` to indicate that a field is copy and does not need to be
-    /// cloned.
+    /// cloned. This is taken on trust for any field type, so getters read the
+    /// field by value instead of calling `Clone::clone`.
     pub(crate) copy: bool,
+    /// `#[rune(ref)]` to indicate that a getter should return a reference to
+    /// the field instead of a clone of it.
+    pub(crate) by_ref: bool,
 }
 
 /// Parsed field attributes.
@@ -41,6 +45,9 @@ pub(crate) struct DeriveAttrs {
     pub(crate) name: Option<syn::LitStr>,
     /// `#[rune(module = "...")]`.
     pub(crate) module: Option<syn::Path>,
+    /// `#[rune(crate = "...")]` to use a re-export of runestick under a
+    /// different name.
+    pub(crate) krate: Option<syn::Path>,
     /// `#[rune(install_with = "...")]`.
     pub(crate) install_with: Option<syn::Path>,
 }
@@ -48,6 +55,7 @@ pub(crate) struct DeriveAttrs {
 pub(crate) struct Tokens {
     pub(crate) protocol: TokenStream,
     pub(crate) any: TokenStream,
+    pub(crate) any_obj: TokenStream,
     pub(crate) context_error: TokenStream,
     pub(crate) from_value: TokenStream,
     pub(crate) variant_data: TokenStream,
@@ -59,6 +67,7 @@ pub(crate) struct Tokens {
     pub(crate) raw_into_mut: TokenStream,
     pub(crate) raw_into_ref: TokenStream,
     pub(crate) raw_str: TokenStream,
+    pub(crate) ref_: TokenStream,
     pub(crate) shared: TokenStream,
     pub(crate) to_value: TokenStream,
     pub(crate) tuple: TokenStream,
@@ -104,7 +113,7 @@ impl Context {
     }
 
     pub(crate) fn tokens_with_module(&self, attrs: &DeriveAttrs) -> Tokens {
-        let module = &match &attrs.module {
+        let module = &match attrs.module.as_ref().or(attrs.krate.as_ref()) {
             Some(module) => quote!(#module),
             None => match &self.module {
                 Some(module) => module.clone(),
@@ -118,6 +127,7 @@ impl Context {
         Tokens {
             protocol: quote!(#module::Protocol),
             any: quote!(#module::Any),
+            any_obj: quote!(#module::AnyObj),
             context_error: quote!(#module::ContextError),
             from_value: quote!(#module::FromValue),
             variant_data: quote!(#module::VariantData),
@@ -129,6 +139,7 @@ impl Context {
             raw_into_mut: quote!(#module::RawMut),
             raw_into_ref: quote!(#module::RawRef),
             raw_str: quote!(#module::RawStr),
+            ref_: quote!(#module::Ref),
             shared: quote!(#module::Shared),
             to_value: quote!(#module::ToValue),
             tuple: quote!(#module::Tuple),
@@ -202,6 +213,9 @@ impl Context {
                     Meta(Path(path)) if path == COPY => {
                         output.copy = true;
                     }
+                    Meta(Path(path)) if path == REF => {
+                        output.by_ref = true;
+                    }
                     Meta(meta) if meta.path() == GET => {
                         output.protocols.push(FieldProtocol {
                             custom: self.parse_field_custom(meta)?,
@@ -213,14 +227,30 @@ impl Context {
                                     ..
                                 } = g;
 
+                                let protocol = g.tokens.protocol(PROTOCOL_GET);
+
+                                if g.attrs.by_ref {
+                                    let Tokens {
+                                        any_obj,
+                                        ref_,
+                                        value,
+                                        ..
+                                    } = g.tokens;
+
+                                    return quote_spanned! { g.field.span() =>
+                                        module.field_fn(#protocol, #name, |s: #ref_<#ident>| {
+                                            let field = #ref_::map(s, |s| &s.#field_ident);
+                                            #value::from(#any_obj::from_managed_ref(field))
+                                        })?;
+                                    };
+                                }
+
                                 let access = if g.attrs.copy {
                                     quote!(s.#field_ident)
                                 } else {
                                     quote!(Clone::clone(&s.#field_ident))
                                 };
 
-                                let protocol = g.tokens.protocol(PROTOCOL_GET);
-
                                 quote_spanned! { g.field.span() =>
                                     module.field_fn(#protocol, #name, |s: &#ident| #access)?;
                                 }
@@ -241,6 +271,15 @@ impl Context {
 
                                 let protocol = g.tokens.protocol(PROTOCOL_SET);
 
+                                // NB: a custom setter is registered as-is, so
+                                // it can return `Result<(), VmError>` to
+                                // reject the value being assigned.
+                                if let Some(custom) = &g.protocol.custom {
+                                    return quote_spanned! { g.field.span() =>
+                                        module.field_fn(#protocol, #name, #custom)?;
+                                    };
+                                }
+
                                 quote_spanned! { g.field.span() =>
                                     module.field_fn(#protocol, #name, |s: &mut #ident, value: #ty| {
                                         s.#field_ident = value;
@@ -377,6 +416,22 @@ impl Context {
 
                         output.module = Some(module);
                     }
+                    // Parse `#[rune(crate = "..")]`.
+                    Meta(NameValue(syn::MetaNameValue {
+                        path,
+                        lit: Lit::Str(s),
+                        ..
+                    })) if path == CRATE => {
+                        let krate = match s.parse_with(syn::Path::parse_mod_style) {
+                            Ok(krate) => krate,
+                            Err(e) => {
+                                self.errors.push(e);
+                                return None;
+                            }
+                        };
+
+                        output.krate = Some(krate);
+                    }
                     // Parse `#[rune(install_with = "..")]`.
                     Meta(NameValue(syn::MetaNameValue {
                         path,
@@ -403,6 +458,15 @@ impl Context {
             }
         }
 
+        if let (Some(module), Some(..)) = (&output.module, &output.krate) {
+            self.errors.push(syn::Error::new_spanned(
+                module,
+                "`module` and `crate` can't be used at the same time",
+            ));
+
+            return None;
+        }
+
         Some(output)
     }
 
@@ -553,6 +617,10 @@ impl Context {
                 unsafe fn unsafe_coerce(output: Self::Output) -> Self {
                     &*output
                 }
+
+                fn type_hint() -> Option<#hash> {
+                    Some(<#ident as #any>::type_hash())
+                }
             }
 
             impl #unsafe_from_value for &mut #ident {
@@ -568,6 +636,10 @@ impl Context {
                 unsafe fn unsafe_coerce(output: Self::Output) -> Self {
                     &mut *output
                 }
+
+                fn type_hint() -> Option<#hash> {
+                    Some(<#ident as #any>::type_hash())
+                }
             }
 
             impl #unsafe_to_value for &#ident {
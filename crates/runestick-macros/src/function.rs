@@ -0,0 +1,73 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+
+/// A call to the `rune_function!` macro.
+pub struct FunctionCall {
+    module: syn::Expr,
+    item: syn::Path,
+    function: syn::Path,
+    args: Punctuated<syn::Ident, syn::Token![,]>,
+}
+
+impl syn::parse::Parse for FunctionCall {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let module = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let item = input.call(syn::Path::parse_mod_style)?;
+        input.parse::<syn::Token![=>]>()?;
+        let function = input.call(syn::Path::parse_mod_style)?;
+
+        let content;
+        syn::parenthesized!(content in input);
+        let args = content.parse_terminated(syn::Ident::parse)?;
+
+        Ok(Self {
+            module,
+            item,
+            function,
+            args,
+        })
+    }
+}
+
+impl FunctionCall {
+    pub fn expand(self) -> Result<TokenStream, Vec<syn::Error>> {
+        if let Some(colon) = &self.item.leading_colon {
+            return Err(vec![syn::Error::new_spanned(
+                colon,
+                "item paths can't have a leading `::`",
+            )]);
+        }
+
+        let item = self
+            .item
+            .segments
+            .iter()
+            .map(|s| syn::LitStr::new(&s.ident.to_string(), s.ident.span()))
+            .collect::<Vec<_>>();
+
+        let module = &self.module;
+        let function = &self.function;
+
+        let args = self
+            .args
+            .iter()
+            .enumerate()
+            .map(|(n, _)| format_ident!("A{}", n))
+            .collect::<Vec<_>>();
+
+        // NB: forces the function to have the declared number of arguments.
+        let check = quote_spanned! { function.span() =>
+            fn check<F, R, #(#args,)*>(f: F) -> F where F: Fn(#(#args,)*) -> R {
+                f
+            }
+        };
+
+        Ok(quote! {{
+            #check
+            #module.function(&[#(#item),*], check(#function))
+        }})
+    }
+}
@@ -52,6 +52,7 @@ use quote::quote;
 mod any;
 mod context;
 mod from_value;
+mod function;
 mod internals;
 mod to_value;
 
@@ -100,12 +101,75 @@ pub fn to_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///     Ok(module)
 /// }
 /// ```
+///
+/// ## `#[rune(crate = "..")]` attribute
+///
+/// Generated implementations refer to items in the `runestick` crate. If
+/// runestick is only available through a re-export, the path to use can be
+/// specified with the `#[rune(crate = "...")]` attribute:
+///
+/// ```rust
+/// mod wrapper {
+///     pub use runestick as vm;
+/// }
+///
+/// #[derive(runestick::Any)]
+/// #[rune(crate = "wrapper::vm")]
+/// struct Foo {
+/// }
+/// ```
 #[proc_macro_derive(Any, attributes(rune))]
 pub fn any(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let derive = syn::parse_macro_input!(input as any::Derive);
     derive.expand().unwrap_or_else(to_compile_errors).into()
 }
 
+/// Macro to register a native function under the given item.
+///
+/// The item is specified as a Rust path instead of a list of strings, and the
+/// arguments of the function have to be declared. Declaring the wrong number
+/// of arguments is a compile error.
+///
+/// ```rust
+/// fn add(a: i64, b: i64) -> i64 {
+///     a + b
+/// }
+///
+/// # fn main() -> runestick::Result<()> {
+/// let mut module = runestick::Module::new();
+/// runestick::rune_function!(module, math::add => add(a, b))?;
+/// # Ok(()) }
+/// ```
+///
+/// This is equivalent to:
+///
+/// ```rust
+/// # fn add(a: i64, b: i64) -> i64 {
+/// #     a + b
+/// # }
+/// # fn main() -> runestick::Result<()> {
+/// let mut module = runestick::Module::new();
+/// module.function(&["math", "add"], add)?;
+/// # Ok(()) }
+/// ```
+///
+/// Declaring the wrong number of arguments fails to compile:
+///
+/// ```compile_fail
+/// # fn add(a: i64, b: i64) -> i64 {
+/// #     a + b
+/// # }
+/// # fn main() -> runestick::Result<()> {
+/// let mut module = runestick::Module::new();
+/// runestick::rune_function!(module, math::add => add(a))?;
+/// # Ok(()) }
+/// ```
+#[proc_macro]
+pub fn rune_function(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let call = syn::parse_macro_input!(input as function::FunctionCall);
+    call.expand().unwrap_or_else(to_compile_errors).into()
+}
+
 /// Internal macro to implement external.
 #[proc_macro]
 #[doc(hidden)]
@@ -0,0 +1,187 @@
+//! Allocation budgeting module for Runestick.
+//!
+//! This module contains methods which allows for limiting how much memory the
+//! virtual machine allocates for collections, like vectors, tuples, objects,
+//! strings, and bytes. Collections returned by native functions count towards
+//! the budget as well.
+//!
+//! The size of a collection is estimated from the number of elements it holds
+//! when it's allocated, and memory is never given back to the budget when a
+//! collection is freed. Growing vectors, strings, bytes, and objects through
+//! the standard library, like with `push`, `extend`, `reserve`, or
+//! `with_capacity`, is taken from the budget before any memory is allocated.
+//!
+//! Collections returned by native functions can only be counted once the
+//! function has returned, so the budget doesn't stop a native function from
+//! allocating more than it allows. It only stops the virtual machine from
+//! continuing afterwards.
+//!
+//! By default allocations are unlimited, but they can be limited by wrapping
+//! your function call in [with].
+
+use crate::{Value, VmError, VmErrorKind};
+use pin_project::pin_project;
+use std::cell::Cell;
+use std::cmp;
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+thread_local!(static BUDGET: Cell<usize> = const { Cell::new(usize::MAX) });
+
+/// Wrap the given value with an allocation budget of `limit` bytes.
+///
+/// The value can either be a function, after which you can use
+/// [AllocationBudget::call], or it can be a [Future] which can be polled.
+pub fn with<T>(limit: usize, value: T) -> AllocationBudget<T> {
+    AllocationBudget {
+        budget: limit,
+        value,
+    }
+}
+
+/// Take the given number of bytes from the budget, indicating with `true` if
+/// the budget is maintained.
+///
+/// Nothing is taken if the budget would be exceeded.
+pub fn take(bytes: usize) -> bool {
+    BUDGET.with(|tls| {
+        let v = tls.get();
+
+        if v == usize::MAX {
+            true
+        } else if v < bytes {
+            false
+        } else {
+            tls.set(v - bytes);
+            true
+        }
+    })
+}
+
+/// Test if the budget is unlimited, which it is unless it's been set with
+/// [with].
+#[inline]
+fn is_unlimited() -> bool {
+    BUDGET.with(|tls| tls.get() == usize::MAX)
+}
+
+/// Take the given number of bytes from the budget, erroring if it's exceeded.
+pub(crate) fn take_bytes(bytes: usize) -> Result<(), VmError> {
+    if take(bytes) {
+        Ok(())
+    } else {
+        Err(VmError::from(VmErrorKind::AllocationLimitExceeded {
+            requested: bytes,
+        }))
+    }
+}
+
+/// Take the given number of elements of type `T` from the budget.
+pub(crate) fn take_elements<T>(len: usize) -> Result<(), VmError> {
+    take_bytes(len.saturating_mul(mem::size_of::<T>()))
+}
+
+/// Take the memory needed to fit `additional` more elements of type `T` into
+/// a collection with the given length and capacity from the budget.
+///
+/// Returns how many elements to reserve space for with `try_reserve_exact`,
+/// which is zero if the elements already fit. Capacity grows amortized like it
+/// does in the standard library, and the whole growth is taken before the
+/// collection reallocates.
+pub(crate) fn take_growth<T>(
+    len: usize,
+    capacity: usize,
+    additional: usize,
+) -> Result<usize, VmError> {
+    let required = len.saturating_add(additional);
+
+    if required <= capacity {
+        return Ok(0);
+    }
+
+    let new_capacity = cmp::max(required, capacity.saturating_mul(2));
+    take_elements::<T>(new_capacity - capacity)?;
+    Ok(new_capacity - len)
+}
+
+/// Take the size of a value returned by a native function from the budget.
+///
+/// Only collections which aren't referenced from anywhere else are counted,
+/// since those are the ones which were allocated by the function.
+#[inline]
+pub(crate) fn take_returned(value: &Value) -> Result<(), VmError> {
+    // NB: this is called after every native function, so avoid inspecting the
+    // returned value unless there's a budget to take from.
+    if is_unlimited() {
+        return Ok(());
+    }
+
+    match value {
+        Value::String(string) if string.is_unique() => take_bytes(string.borrow_ref()?.len()),
+        Value::Bytes(bytes) if bytes.is_unique() => take_bytes(bytes.borrow_ref()?.len()),
+        Value::Vec(vec) if vec.is_unique() => take_elements::<Value>(vec.borrow_ref()?.len()),
+        Value::Tuple(tuple) if tuple.is_unique() => {
+            take_elements::<Value>(tuple.borrow_ref()?.len())
+        }
+        Value::Object(object) if object.is_unique() => {
+            take_elements::<(String, Value)>(object.borrow_ref()?.len())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[repr(transparent)]
+struct BudgetGuard(usize);
+
+impl Drop for BudgetGuard {
+    fn drop(&mut self) {
+        BUDGET.with(|tls| {
+            tls.set(self.0);
+        });
+    }
+}
+
+/// A value with an allocation budget.
+#[pin_project]
+pub struct AllocationBudget<T> {
+    /// The remaining budget in bytes.
+    budget: usize,
+    /// The value being budgeted.
+    #[pin]
+    value: T,
+}
+
+impl<T, O> AllocationBudget<T>
+where
+    T: FnOnce() -> O,
+{
+    /// Call the wrapped function.
+    pub fn call(self) -> O {
+        BUDGET.with(|tls| {
+            let _guard = BudgetGuard(tls.get());
+            tls.set(self.budget);
+            (self.value)()
+        })
+    }
+}
+
+impl<T> Future for AllocationBudget<T>
+where
+    T: Future,
+{
+    type Output = T::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        BUDGET.with(|tls| {
+            let _guard = BudgetGuard(tls.get());
+            tls.set(*this.budget);
+            let poll = this.value.poll(cx);
+            *this.budget = tls.get();
+            poll
+        })
+    }
+}
@@ -1,6 +1,6 @@
 //! Helper types for a holder of data.
 
-use crate::{Any, Hash, RawStr};
+use crate::{Any, Hash, RawStr, Ref};
 use std::any;
 use std::fmt;
 use std::mem::ManuallyDrop;
@@ -172,6 +172,51 @@ impl AnyObj {
         }
     }
 
+    /// Construct an Any that wraps a managed reference.
+    ///
+    /// In contrast to [from_ref][AnyObj::from_ref] this is safe, since the
+    /// reference keeps the value it was borrowed from alive and prevents it from
+    /// being accessed mutably for as long as the returned `AnyObj` is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use runestick::{Any, AnyObj, Shared};
+    ///
+    /// #[derive(Any)]
+    /// struct Foo(u32);
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let v = Shared::new(Foo(1u32));
+    ///
+    /// let mut any = AnyObj::from_managed_ref(v.clone().into_ref()?);
+    /// assert_eq!(any.downcast_borrow_ref::<Foo>().unwrap().0, 1u32);
+    /// assert!(any.downcast_borrow_mut::<Foo>().is_none());
+    /// assert!(v.borrow_mut().is_err());
+    ///
+    /// drop(any);
+    /// assert!(v.borrow_mut().is_ok());
+    /// # Ok(()) }
+    /// ```
+    pub fn from_managed_ref<T>(data: Ref<T>) -> Self
+    where
+        T: Any,
+    {
+        let data = Box::into_raw(Box::new(data));
+
+        Self {
+            vtable: &AnyObjVtable {
+                kind: AnyObjKind::RefPtr,
+                drop: drop_impl::<Ref<T>>,
+                as_ptr: as_managed_ref_ptr_impl::<T>,
+                debug: debug_ref_impl::<T>,
+                type_name: type_name_impl::<T>,
+                type_hash: type_hash_impl::<T>,
+            },
+            data: data as *mut (),
+        }
+    }
+
     /// Construct a new any with the specified raw components.
     ///
     /// ### Safety
@@ -231,15 +276,13 @@ impl AnyObj {
     where
         T: Any,
     {
-        if self.is::<T>() {
-            unsafe { Some(&*(self.data as *const T)) }
-        } else {
-            None
-        }
+        // Safety: invariants are checked at construction time.
+        let ptr = unsafe { (self.vtable.as_ptr)(self.data, Hash::from_any::<T>())? };
+        unsafe { Some(&*(ptr as *const T)) }
     }
 
     /// Returns some mutable reference to the boxed value if it is of type `T`, or
-    /// `None` if it isn't.
+    /// `None` if it isn't or if the value is only available by reference.
     ///
     /// # Examples
     ///
@@ -258,11 +301,8 @@ impl AnyObj {
     where
         T: Any,
     {
-        if self.is::<T>() {
-            unsafe { Some(&mut *(self.data as *mut () as *mut T)) }
-        } else {
-            None
-        }
+        let ptr = self.raw_as_mut(Hash::from_any::<T>()).ok()?;
+        unsafe { Some(&mut *(ptr as *mut T)) }
     }
 
     /// Attempt to perform a conversion to a raw pointer.
@@ -425,6 +465,18 @@ where
     }
 }
 
+unsafe fn as_managed_ref_ptr_impl<T>(this: *const (), expected: Hash) -> Option<*const ()>
+where
+    T: Any,
+{
+    if expected == Hash::from_type_id(any::TypeId::of::<T>()) {
+        let data: &T = &*(this as *const Ref<T>);
+        Some(data as *const T as *const ())
+    } else {
+        None
+    }
+}
+
 fn noop_drop_impl<T>(_: *const ()) {}
 
 fn debug_impl<T>(f: &mut fmt::Formatter<'_>) -> fmt::Result
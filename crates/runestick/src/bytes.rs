@@ -3,7 +3,7 @@
 //! [Value::Bytes]: crate::Value::Bytes.
 
 use crate::{
-    FromValue, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, UnsafeFromValue, Value,
+    FromValue, Hash, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, UnsafeFromValue, Value,
     VmError,
 };
 
@@ -128,6 +128,10 @@ impl FromValue for Bytes {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_bytes()?.borrow_ref()?.clone())
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::BYTES_TYPE.hash)
+    }
 }
 
 impl<'a> UnsafeFromValue for &'a Bytes {
@@ -143,6 +147,10 @@ impl<'a> UnsafeFromValue for &'a Bytes {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::BYTES_TYPE.hash)
+    }
 }
 
 impl<'a> UnsafeFromValue for &'a mut Bytes {
@@ -158,6 +166,10 @@ impl<'a> UnsafeFromValue for &'a mut Bytes {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::BYTES_TYPE.hash)
+    }
 }
 
 impl<'a> UnsafeFromValue for &'a [u8] {
@@ -176,6 +188,10 @@ impl<'a> UnsafeFromValue for &'a [u8] {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::BYTES_TYPE.hash)
+    }
 }
 
 impl Named for Bytes {
@@ -53,6 +53,7 @@ impl CompileMeta {
             CompileMetaKind::StructVariant { .. } => None,
             CompileMetaKind::Const { .. } => None,
             CompileMetaKind::ConstFn { .. } => None,
+            CompileMetaKind::Global { .. } => None,
             CompileMetaKind::Import { .. } => None,
         }
     }
@@ -97,6 +98,9 @@ impl fmt::Display for CompileMeta {
             CompileMetaKind::ConstFn { .. } => {
                 write!(fmt, "const fn {}", self.item.item)?;
             }
+            CompileMetaKind::Global { .. } => {
+                write!(fmt, "global {}", self.item.item)?;
+            }
             CompileMetaKind::Import { .. } => {
                 write!(fmt, "import {}", self.item.item)?;
             }
@@ -201,6 +205,14 @@ pub enum CompileMetaKind {
         /// Whether this function has a test annotation
         is_test: bool,
     },
+    /// A global value registered through [Context::set_global], which is
+    /// loaded by calling the function with the given hash.
+    ///
+    /// [Context::set_global]: crate::Context::set_global
+    Global {
+        /// The hash of the function producing the value of the global.
+        hash: Hash,
+    },
     /// Purely an import.
     Import {
         /// The module of the target.
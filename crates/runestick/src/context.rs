@@ -1,13 +1,15 @@
 use crate::{
     collections::{HashMap, HashSet},
     module::{
-        ModuleAssociatedFn, ModuleFn, ModuleInternalEnum, ModuleMacro, ModuleType, ModuleUnitType,
+        ModuleAssocKey, ModuleAssociatedFn, ModuleConst, ModuleFn, ModuleInternalEnum, ModuleMacro,
+        ModuleType, ModuleUnitType,
     },
     CompileMeta, CompileMetaKind, CompileMetaStruct, CompileMetaTuple, ComponentRef, ConstValue,
-    Hash, IntoComponent, Item, Module, Names, Protocol, RuntimeContext, Stack, StaticType,
-    TypeCheck, TypeInfo, TypeOf, VmError,
+    FromValue as _, Hash, InstFnNameHash, IntoComponent, Item, Module, Names, OverflowPolicy,
+    Protocol, RuntimeContext, Stack, StaticType, ToValue, TypeCheck, TypeInfo, TypeOf, Unit,
+    VmError, VmErrorKind,
 };
-use std::{any, fmt, sync::Arc};
+use std::{any, fmt, panic, sync::Arc};
 
 use thiserror::Error;
 
@@ -23,21 +25,44 @@ pub enum ContextError {
         /// The name of the internal type already present.
         name: &'static str,
     },
+    /// Error raised when attempting to register something with the same name
+    /// as a global registered through [Context::set_global].
+    #[error("global with name `{name}` already exists")]
+    ConflictingGlobal {
+        /// The name of the global.
+        name: Item,
+    },
     /// A conflicting name.
-    #[error("conflicting meta {existing} while trying to insert {current}")]
+    #[error(
+        "conflicting meta {existing} installed by {existing_module} while trying to insert {current} from {module}",
+        module = ModuleName(.module),
+        existing_module = ModuleName(.existing_module),
+    )]
     ConflictingMeta {
         /// The current meta we tried to insert.
         current: Box<CompileMeta>,
         /// The existing meta item.
         existing: Box<CompileMeta>,
+        /// The item of the module which installed the existing meta.
+        existing_module: Item,
+        /// The item of the module which tried to install the meta.
+        module: Item,
     },
     /// Error raised when attempting to register a conflicting function.
-    #[error("function `{signature}` ({hash}) already exists")]
+    #[error(
+        "function `{signature}` ({hash}) from {module} conflicts with the one installed by {existing_module}",
+        module = ModuleName(.module),
+        existing_module = ModuleName(.existing_module),
+    )]
     ConflictingFunction {
         /// The signature of the conflicting function.
-        signature: ContextSignature,
+        signature: Box<ContextSignature>,
         /// The hash of the conflicting function.
         hash: Hash,
+        /// The item of the module which installed the existing function.
+        existing_module: Item,
+        /// The item of the module which tried to install the function.
+        module: Item,
     },
     /// Error raised when attempting to register a conflicting function.
     #[error("function with name `{name}` already exists")]
@@ -46,18 +71,54 @@ pub enum ContextError {
         name: Item,
     },
     /// Error raised when attempting to register a conflicting constant.
-    #[error("constant with name `{name}` already exists")]
+    #[error(
+        "constant with name `{name}` from {module} conflicts with the one installed by {existing_module}",
+        module = ModuleName(.module),
+        existing_module = ModuleName(.existing_module),
+    )]
     ConflictingConstantName {
         /// The name of the conflicting constant.
         name: Item,
+        /// The item of the module which installed the existing constant.
+        existing_module: Item,
+        /// The item of the module which tried to install the constant.
+        module: Item,
+    },
+    /// Error raised when the number of argument names given for a function
+    /// doesn't match the number of arguments it takes.
+    #[error(
+        "function `{name}` takes {expected} arguments, but {actual} argument names were given"
+    )]
+    ArgumentNamesMismatch {
+        /// The name of the function.
+        name: Item,
+        /// The number of argument names given.
+        actual: usize,
+        /// The number of arguments the function takes.
+        expected: usize,
+    },
+    /// Error raised when attempting to register a constant and a function with
+    /// the same name.
+    #[error("constant and function with name `{name}` can't both exist")]
+    ConflictingConstantFunction {
+        /// The name shared by the constant and the function.
+        name: Item,
     },
     /// Error raised when attempting to register a conflicting instance function.
-    #[error("instance function `{name}` for type `{type_info}` already exists")]
+    #[error(
+        "instance function `{name}` for type `{type_info}` from {module} conflicts with the one installed by {existing_module}",
+        module = ModuleName(.module),
+        existing_module = ModuleName(.existing_module),
+    )]
     ConflictingInstanceFunction {
         /// Type that we register the instance function for.
         type_info: TypeInfo,
         /// The name of the conflicting function.
         name: String,
+        /// The item of the module which installed the existing function.
+        existing_module: Item,
+        /// The item of the module which tried to install the function.
+        module: Item,
     },
     /// Tried to insert a module that conflicted with an already existing one.
     #[error("module `{item}` with hash `{hash}` already exists")]
@@ -68,20 +129,36 @@ pub enum ContextError {
         hash: Hash,
     },
     /// Raised when we try to register a conflicting type.
-    #[error("type `{item}` already exists `{existing}`")]
+    #[error(
+        "type `{item}` from {module} conflicts with `{existing}` installed by {existing_module}",
+        module = ModuleName(.module),
+        existing_module = ModuleName(.existing_module),
+    )]
     ConflictingType {
         /// The name we tried to register.
         item: Item,
         /// The type information for the type that already existed.
         existing: TypeInfo,
+        /// The item of the module which installed the existing type.
+        existing_module: Item,
+        /// The item of the module which tried to install the type.
+        module: Item,
     },
     /// Raised when we try to register a conflicting type hash.
-    #[error("tried to insert conflicting hash `{hash}` for `{existing}`")]
+    #[error(
+        "tried to insert conflicting hash `{hash}` from {module} for `{existing}` installed by {existing_module}",
+        module = ModuleName(.module),
+        existing_module = ModuleName(.existing_module),
+    )]
     ConflictingTypeHash {
         /// The hash we are trying to insert.
         hash: Hash,
         /// The hash that already existed.
         existing: Hash,
+        /// The item of the module which installed the existing type.
+        existing_module: Item,
+        /// The item of the module which tried to install the type.
+        module: Item,
     },
     /// Error raised when attempting to register a conflicting function.
     #[error("variant with `{item}` already exists")]
@@ -89,13 +166,56 @@ pub enum ContextError {
         /// The name of the conflicting variant.
         item: Item,
     },
+    /// Error raised when a function is registered with more default values
+    /// than it has parameters.
+    #[error("function `{name}` has {defaults} default values, but only takes {args} arguments")]
+    TooManyDefaults {
+        /// The name of the function.
+        name: Item,
+        /// The number of default values.
+        defaults: usize,
+        /// The number of arguments the function takes.
+        args: usize,
+    },
+    /// Error raised when attempting to alias a function which doesn't exist.
+    #[error("function with name `{name}` doesn't exist")]
+    MissingFunction {
+        /// The name of the missing function.
+        name: Item,
+    },
+    /// Error raised when attempting to alias an instance function which
+    /// doesn't exist.
+    #[error("instance function `{name}` for type `{type_info}` doesn't exist")]
+    MissingInstanceFunction {
+        /// Type that we looked up the instance function for.
+        type_info: TypeInfo,
+        /// The name of the missing function.
+        name: String,
+    },
     /// Error raised when attempting to register an instance function on an
     /// instance which does not exist.
-    #[error("instance `{instance_type}` does not exist in module")]
+    #[error("instance `{instance_type}` does not exist in module or context")]
     MissingInstance {
         /// The instance type.
         instance_type: TypeInfo,
     },
+    /// Error raised when an instance function was registered on a type which
+    /// was never installed.
+    #[error("instance function `{name}` is registered on `{type_info}` which isn't installed")]
+    OrphanInstanceFunction {
+        /// The type the function was registered on.
+        type_info: TypeInfo,
+        /// The name of the function.
+        name: String,
+    },
+    /// Error raised when a function returns a type which was never installed.
+    #[error("function `{name}` returns a type which isn't installed (type hash `{type_hash}`)")]
+    UnregisteredReturnType {
+        /// The name of the function.
+        name: Item,
+        /// The type hash of the returned type.
+        type_hash: Hash,
+    },
     /// Error raised when attempting to create a constant value.
     #[error("error when converting to constant value: {error}")]
     ValueError {
@@ -208,6 +328,23 @@ impl fmt::Display for ContextSignature {
     }
 }
 
+/// An observer of native function calls, as set through
+/// [Context::set_call_observer].
+pub type CallObserver = dyn Fn(&Item, usize, CallPhase) + Send + Sync;
+
+/// The phase of a native function call reported to a [CallObserver].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallPhase {
+    /// The function is about to be called.
+    Before,
+    /// The function has returned.
+    After {
+        /// Whether the function returned successfully, as opposed to with an
+        /// error.
+        ok: bool,
+    },
+}
+
 /// Static run context visible to the virtual machine.
 ///
 /// This contains:
@@ -224,12 +361,29 @@ pub struct Context {
     functions: HashMap<Hash, Arc<Handler>>,
     /// Registered native macro handlers.
     macros: HashMap<Hash, Arc<Macro>>,
+    /// The items of registered native macros.
+    macro_items: HashMap<Hash, Item>,
     /// Information on functions.
     functions_info: HashMap<Hash, ContextSignature>,
+    /// The item of the module which installed each function.
+    function_modules: HashMap<Hash, Item>,
+    /// The item of the module which installed each named item, by its name.
+    /// Globals are recorded as installed by the root module.
+    item_modules: HashMap<Item, Item>,
+    /// Functions which can be evaluated at compile time.
+    const_functions: HashSet<Hash>,
+    /// Type hints for the arguments of functions, where available.
+    functions_arg_types: HashMap<Hash, Box<[Option<Hash>]>>,
+    /// Names of the arguments of functions, where provided.
+    functions_arg_names: HashMap<Hash, Box<[Box<str>]>>,
+    /// Static functions associated with types, by the hash of the type.
+    static_functions: HashMap<Hash, Vec<Hash>>,
     /// Registered types.
     types: HashMap<Hash, ContextTypeInfo>,
     /// Reverse lookup for types.
     types_rev: HashMap<Hash, Hash>,
+    /// Types which are hidden from the item namespace.
+    hidden_types: HashSet<Hash>,
     /// Specialized information on unit types, if available.
     unit_type: Option<Hash>,
     /// Registered internal enums.
@@ -239,7 +393,20 @@ pub struct Context {
     /// Registered crates.
     crates: HashSet<Box<str>>,
     /// Constants visible in this context
-    constants: HashMap<Hash, ConstValue>,
+    constants: HashMap<Hash, ModuleConst>,
+    /// Identities of the modules installed in this context.
+    modules: HashSet<Hash>,
+    /// Associated functions registered on types which haven't been installed
+    /// yet.
+    orphans: Vec<(ModuleAssocKey, ModuleAssociatedFn, Item)>,
+    /// The statically known return types of installed functions.
+    return_types: Vec<(Item, Hash)>,
+    /// Whether or not panics in native functions should be caught.
+    catch_unwind: bool,
+    /// How integer arithmetic that overflows is handled.
+    overflow_policy: OverflowPolicy,
+    /// Observer called whenever a native function is called.
+    call_observer: Option<Arc<CallObserver>>,
 }
 
 impl Context {
@@ -274,6 +441,7 @@ impl Context {
         this.install(&crate::modules::result::module()?)?;
         this.install(&crate::modules::stream::module()?)?;
         this.install(&crate::modules::string::module()?)?;
+        this.install(&crate::modules::time::module()?)?;
         this.install(&crate::modules::vec::module()?)?;
         this.has_default_modules = true;
         Ok(this)
@@ -295,13 +463,141 @@ impl Context {
     /// # Ok(()) }
     /// ```
     pub fn runtime(&self) -> RuntimeContext {
+        let mut functions = HashMap::with_capacity(self.functions.len());
+
+        // NB: globals are the only functions without a signature.
+        let globals = match &self.call_observer {
+            Some(..) => self
+                .meta
+                .values()
+                .filter_map(|meta| match meta.kind {
+                    CompileMetaKind::Global { hash } => Some((hash, &meta.item.item)),
+                    _ => None,
+                })
+                .collect(),
+            None => HashMap::new(),
+        };
+
+        for (hash, handler) in &self.functions {
+            let mut handler = handler.clone();
+
+            if self.catch_unwind {
+                handler = catch_unwind_handler(handler);
+            }
+
+            if let Some(observer) = &self.call_observer {
+                let item = match self.functions_info.get(hash) {
+                    Some(signature) => Some(signature_item(signature)),
+                    None => globals.get(hash).map(|item| (*item).clone()),
+                };
+
+                if let Some(item) = item {
+                    handler = observed_handler(handler, observer.clone(), item);
+                }
+            }
+
+            functions.insert(*hash, handler);
+        }
+
         RuntimeContext {
-            functions: self.functions.clone(),
+            functions,
             types: self.types.iter().map(|(k, t)| (*k, t.type_check)).collect(),
+            type_infos: self
+                .types
+                .values()
+                .map(|t| (t.type_hash, t.type_info.clone()))
+                .collect(),
             constants: self.constants.clone(),
+            overflow_policy: self.overflow_policy,
         }
     }
 
+    /// Set whether panics in native functions should be caught and converted
+    /// into a [Panic][crate::VmErrorKind::Panic] error, instead of unwinding
+    /// through the virtual machine.
+    ///
+    /// This is disabled by default, since it adds overhead to every native
+    /// function call. It applies to runtime contexts constructed through
+    /// [runtime][Context::runtime] after it has been set.
+    ///
+    /// Note that only panics raised while calling the function are caught. A
+    /// panic raised while polling the future returned by an async function
+    /// still unwinds.
+    pub fn set_catch_unwind(&mut self, catch_unwind: bool) {
+        self.catch_unwind = catch_unwind;
+    }
+
+    /// Set how integer arithmetic that overflows is handled by the virtual
+    /// machine. Defaults to [OverflowPolicy::Checked].
+    ///
+    /// The compiler uses the same policy when evaluating constant
+    /// expressions, where an overflow which would raise an error at runtime
+    /// is a compile error instead.
+    ///
+    /// Like [set_catch_unwind][Context::set_catch_unwind], it applies to
+    /// runtime contexts constructed through [runtime][Context::runtime] after
+    /// it has been set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Context, OverflowPolicy};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut context = Context::with_default_modules()?;
+    /// context.set_overflow_policy(OverflowPolicy::Wrapping);
+    ///
+    /// let runtime = context.runtime();
+    /// assert_eq!(runtime.overflow_policy(), OverflowPolicy::Wrapping);
+    /// # Ok(()) }
+    /// ```
+    pub fn set_overflow_policy(&mut self, overflow_policy: OverflowPolicy) {
+        self.overflow_policy = overflow_policy;
+    }
+
+    /// Get how integer arithmetic that overflows is handled.
+    ///
+    /// This is also used when evaluating constant expressions at compile time.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    /// Set an observer which is called with the item of a native function and
+    /// the number of arguments it's called with, right before it's called and
+    /// right after it returns.
+    ///
+    /// The [CallPhase] tells the two apart, and whether the function returned
+    /// an error. Every native function is observed, including globals set
+    /// through [set_global][Context::set_global], which are reported under
+    /// their name. For async functions, the function returns once it has
+    /// constructed its future, before the future is polled.
+    ///
+    /// This is intended for tracing and debugging. Like
+    /// [set_catch_unwind][Context::set_catch_unwind], it applies to runtime
+    /// contexts constructed through [runtime][Context::runtime] after it has
+    /// been set, and native functions are not wrapped at all when no observer
+    /// is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{CallPhase, Context};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut context = Context::with_default_modules()?;
+    ///
+    /// context.set_call_observer(Box::new(|item, args, phase| match phase {
+    ///     CallPhase::Before => println!("calling {} with {} arguments", item, args),
+    ///     CallPhase::After { ok } => println!("{} returned (ok: {})", item, ok),
+    /// }));
+    ///
+    /// let runtime = context.runtime();
+    /// # Ok(()) }
+    /// ```
+    pub fn set_call_observer(&mut self, observer: Box<CallObserver>) {
+        self.call_observer = Some(Arc::from(observer));
+    }
+
     /// Use the specified type check.
     pub fn type_check_for(&self, item: &Item) -> Option<TypeCheck> {
         let ty = self.types.get(&Hash::type_hash(item))?;
@@ -309,6 +605,31 @@ impl Context {
     }
 
     /// Construct a new collection of functions with default packages installed.
+    ///
+    /// This installs every module in the standard library, including the
+    /// internal `Option`, `Result` and `GeneratorState` enums, and makes the
+    /// prelude available to units compiled against the context. I/O functions
+    /// which interact with stdout and stderr are included, see
+    /// [with_config][Context::with_config] to leave them out.
+    ///
+    /// Additional modules can be installed on top of the returned context.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Context, Item, Module};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = Module::with_item(&["math"]);
+    /// module.function(&["double"], |n: i64| n * 2)?;
+    ///
+    /// let mut context = Context::with_default_modules()?;
+    /// context.install(&module)?;
+    ///
+    /// assert!(context.contains_name(&Item::with_crate_item("std", &["vec", "Vec"])));
+    /// assert!(context.contains_name(&Item::with_item(&["math", "double"])));
+    /// # Ok(()) }
+    /// ```
     pub fn with_default_modules() -> Result<Self, ContextError> {
         Self::with_config(true)
     }
@@ -358,14 +679,129 @@ impl Context {
         self.functions.get(&hash)
     }
 
+    /// Lookup the handler of the instance function `name` registered on the
+    /// type with the given hash.
+    ///
+    /// This is intended for host-driven method calls. The name can either be
+    /// a string or its precomputed hash, as produced by
+    /// [Hash::instance_fn_name]. Combining it with the type hash is cheap, so
+    /// callers which perform many lookups should hash the name once up front.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Context, Hash, Stack, ToValue as _, TypeOf as _};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let context = Context::with_default_modules()?;
+    /// let len = Hash::instance_fn_name("len");
+    ///
+    /// let handler = context
+    ///     .lookup_inst_fn(runestick::Vec::type_hash(), len)
+    ///     .expect("`len` is registered on vectors");
+    ///
+    /// let mut stack = Stack::new();
+    /// stack.push(vec![1i64, 2, 3].to_value()?);
+    /// handler(&mut stack, 1)?;
+    ///
+    /// assert_eq!(stack.pop()?.into_integer()?, 3);
+    /// # Ok(()) }
+    /// ```
+    pub fn lookup_inst_fn<N>(&self, type_hash: Hash, name: N) -> Option<&Arc<Handler>>
+    where
+        N: InstFnNameHash,
+    {
+        self.functions
+            .get(&Hash::instance_function(type_hash, name))
+    }
+
+    /// Lookup the type hashes of the arguments of the given native function.
+    ///
+    /// This is only available for functions whose argument types are
+    /// statically known, and is intended as a hint for tooling. Arguments that
+    /// accept any value are reported as `None`.
+    pub fn lookup_arg_types(&self, hash: Hash) -> Option<&[Option<Hash>]> {
+        Some(self.functions_arg_types.get(&hash)?)
+    }
+
+    /// Lookup the names of the arguments of the given native function.
+    ///
+    /// This is only available for functions registered through
+    /// [Module::function_named_args], and is intended for tooling.
+    pub fn lookup_arg_names(&self, hash: Hash) -> Option<&[Box<str>]> {
+        Some(self.functions_arg_names.get(&hash)?)
+    }
+
+    /// Test if the function with the given hash was registered through
+    /// [Module::const_function], and can be evaluated at compile time.
+    pub fn is_const_fn(&self, hash: Hash) -> bool {
+        self.const_functions.contains(&hash)
+    }
+
+    /// Evaluate a function registered through [Module::const_function] with
+    /// the given constant arguments.
+    ///
+    /// The function is looked up in the given runtime context, which must have
+    /// been constructed from this context through [runtime][Context::runtime],
+    /// so it's called like it would be at runtime with respect to
+    /// [set_catch_unwind][Context::set_catch_unwind] and
+    /// [set_call_observer][Context::set_call_observer]. Since constructing a
+    /// runtime context is expensive, it should be reused across calls.
+    ///
+    /// The function is called on a scratch stack outside of any virtual
+    /// machine, and its return value must be representable as a constant.
+    /// Like at runtime, the function has access to the runtime context, but
+    /// since the unit is still being compiled it sees an empty unit.
+    pub fn call_const_fn(
+        &self,
+        runtime: &Arc<RuntimeContext>,
+        hash: Hash,
+        args: Vec<ConstValue>,
+    ) -> Result<ConstValue, VmError> {
+        let handler = match runtime.lookup(hash) {
+            Some(handler) if self.const_functions.contains(&hash) => handler,
+            _ => return Err(VmError::from(VmErrorKind::MissingFunction { hash })),
+        };
+
+        let count = args.len();
+        let mut stack = Stack::with_capacity(count);
+
+        for arg in args {
+            stack.push(arg.into_value());
+        }
+
+        let unit = Arc::new(Unit::default());
+
+        // NB: set up the environment like the virtual machine does, so that
+        // the function can use protocols and the overflow policy.
+        let _guard = crate::env::Guard::new(runtime, &unit);
+        handler(&mut stack, count)?;
+        ConstValue::from_value(stack.pop()?)
+    }
+
     /// Lookup the given macro handler.
     pub fn lookup_macro(&self, hash: Hash) -> Option<&Arc<Macro>> {
         self.macros.get(&hash)
     }
 
     /// Access the meta for the given language item.
+    ///
+    /// Lazy constants are evaluated the first time their meta is looked up.
     pub fn lookup_meta(&self, name: &Item) -> Option<CompileMeta> {
-        self.meta.get(name).cloned()
+        if let Some(meta) = self.meta.get(name) {
+            return Some(meta.clone());
+        }
+
+        match self.constants.get(&Hash::type_hash(name))? {
+            ModuleConst::Lazy(lazy) => Some(CompileMeta {
+                item: Arc::new(name.clone().into()),
+                kind: CompileMetaKind::Const {
+                    const_value: lazy.get().clone(),
+                },
+                source: None,
+            }),
+            ModuleConst::Value(..) => None,
+        }
     }
 
     /// Iterate over all available functions
@@ -388,8 +824,122 @@ impl Context {
         })
     }
 
+    /// Iterate over the static functions associated with the given type, as
+    /// registered through [Module::static_fn].
+    pub fn iter_static_functions(
+        &self,
+        type_hash: Hash,
+    ) -> impl Iterator<Item = (Hash, &ContextSignature)> {
+        self.static_functions
+            .get(&type_hash)
+            .into_iter()
+            .flatten()
+            .filter_map(move |hash| Some((*hash, self.functions_info.get(hash)?)))
+    }
+
+    /// Iterate over the names of all installed types.
+    ///
+    /// This includes internal enums like `Option` and `Result` together with
+    /// their variants, but not types which were installed through
+    /// [Module::ty_hidden] since they can't be named.
+    pub fn type_names(&self) -> impl Iterator<Item = &Item> {
+        self.types
+            .values()
+            .filter(move |ty| !self.hidden_types.contains(&ty.type_hash))
+            .map(|ty| &ty.item)
+    }
+
+    /// Iterate over the names of all installed native macros.
+    ///
+    /// The crate a macro belongs to can be checked through
+    /// [Item::as_crate] on its name.
+    pub fn macro_names(&self) -> impl Iterator<Item = &Item> {
+        self.macro_items.values()
+    }
+
+    /// Register a global value with the given name, which scripts can refer
+    /// to like any other item.
+    ///
+    /// Unlike a constant, a global can hold any value which can be converted
+    /// into a [Value][crate::Value], like instances of types registered
+    /// through [Module::ty]. Every use of the global in a script produces a new
+    /// copy of the value, so scripts can't modify the global itself, and
+    /// assigning to it is a compile error.
+    ///
+    /// Setting a global which already exists replaces its value. Errors if the
+    /// name is already used by something else, like a function or a type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut context = runestick::Context::new();
+    /// context.set_global(&["VERSION"], String::from("1.2.3"))?;
+    /// # Ok(()) }
+    /// ```
+    pub fn set_global<N, T>(&mut self, name: N, value: T) -> Result<(), ContextError>
+    where
+        N: IntoIterator,
+        N::Item: IntoComponent,
+        T: 'static + ToValue + Clone + Send + Sync,
+    {
+        let item = Item::with_item(name);
+        let hash = Hash::type_hash(&item);
+
+        match self.meta.get(&item) {
+            Some(CompileMeta {
+                kind: CompileMetaKind::Global { .. },
+                ..
+            }) => (),
+            Some(..) => {
+                return Err(ContextError::ConflictingMeta {
+                    existing: Box::new(self.meta[&item].clone()),
+                    current: Box::new(global_meta(&item, hash)),
+                    existing_module: self.item_module(&item),
+                    module: Item::new(),
+                });
+            }
+            // NB: lazy constants don't have any meta until they're used.
+            None if self.constants.contains_key(&hash) => {
+                return Err(ContextError::ConflictingConstantName {
+                    existing_module: self.item_module(&item),
+                    module: Item::new(),
+                    name: item,
+                });
+            }
+            None => {
+                self.names.insert(&item);
+                self.meta.insert(item.clone(), global_meta(&item, hash));
+                self.item_modules.insert(item, Item::new());
+            }
+        }
+
+        let handler: Arc<Handler> = Arc::new(move |stack, _| {
+            stack.push(value.clone().to_value()?);
+            Ok(())
+        });
+
+        self.functions.insert(hash, handler);
+        Ok(())
+    }
+
     /// Install the specified module.
+    ///
+    /// Installing a module with the same [identity][Module::identity] as one
+    /// which is already installed does nothing.
+    ///
+    /// Associated functions can be registered on types declared by any module
+    /// installed into the context, regardless of the order in which they're
+    /// installed. Functions whose type hasn't been installed yet are held onto
+    /// until it is, use [validate][Context::validate] once all modules have
+    /// been installed to check that none of them were left behind.
     pub fn install(&mut self, module: &Module) -> Result<(), ContextError> {
+        let identity = module.identity();
+
+        if self.modules.contains(&identity) {
+            return Ok(());
+        }
+
         if let Some(ComponentRef::Crate(name)) = module.item.first() {
             self.crates.insert(name.into());
         }
@@ -419,7 +969,13 @@ impl Context {
         }
 
         for (key, inst) in &module.associated_functions {
+            if !self.types_rev.contains_key(&key.type_hash) {
+                self.orphans.push((*key, inst.clone(), module.item.clone()));
+                continue;
+            }
+
             self.install_associated_function(
+                &module.item,
                 key.type_hash,
                 key.hash,
                 inst,
@@ -427,18 +983,105 @@ impl Context {
             )?;
         }
 
+        self.install_orphans()?;
+        self.modules.insert(identity);
+        Ok(())
+    }
+
+    /// Check that all installed associated functions have been registered on
+    /// types which are installed, and that the types returned by native
+    /// functions are installed.
+    ///
+    /// This should be called once all modules have been installed, since
+    /// associated functions can be registered before their type is.
+    ///
+    /// Returning a type which isn't installed is usually a mistake, since
+    /// scripts can't do anything with values of that type. Note that return
+    /// types are only checked when they're statically known, like for
+    /// functions returning an [Any][crate::Any] type directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Any, Context, ContextError, Module};
+    ///
+    /// #[derive(Any)]
+    /// struct Point {
+    ///     x: i64,
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = Module::new();
+    /// module.inst_fn("x", |p: &Point| p.x)?;
+    ///
+    /// let mut context = Context::new();
+    /// context.install(&module)?;
+    ///
+    /// assert!(matches!(
+    ///     context.validate(),
+    ///     Err(ContextError::OrphanInstanceFunction { .. })
+    /// ));
+    /// # Ok(()) }
+    /// ```
+    pub fn validate(&self) -> Result<(), ContextError> {
+        if let Some((_, assoc, _)) = self.orphans.first() {
+            return Err(ContextError::OrphanInstanceFunction {
+                type_info: assoc.type_info.clone(),
+                name: assoc.name.clone(),
+            });
+        }
+
+        for (name, type_hash) in &self.return_types {
+            if !self.types_rev.contains_key(type_hash) {
+                return Err(ContextError::UnregisteredReturnType {
+                    name: name.clone(),
+                    type_hash: *type_hash,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Install associated functions whose type has been installed since they
+    /// were registered.
+    fn install_orphans(&mut self) -> Result<(), ContextError> {
+        for (key, inst, module) in std::mem::take(&mut self.orphans) {
+            if !self.types_rev.contains_key(&key.type_hash) {
+                self.orphans.push((key, inst, module));
+                continue;
+            }
+
+            self.install_associated_function(
+                &module,
+                key.type_hash,
+                key.hash,
+                &inst,
+                |instance_type, field| key.kind.hash(instance_type, field),
+            )?;
+        }
+
         Ok(())
     }
 
+    /// Get the item of the module which installed the given item.
+    fn item_module(&self, item: &Item) -> Item {
+        self.item_modules.get(item).cloned().unwrap_or_default()
+    }
+
     /// Install the given meta.
-    fn install_meta(&mut self, meta: CompileMeta) -> Result<(), ContextError> {
+    fn install_meta(&mut self, module: &Item, meta: CompileMeta) -> Result<(), ContextError> {
         if let Some(existing) = self.meta.insert(meta.item.item.clone(), meta.clone()) {
             return Err(ContextError::ConflictingMeta {
+                existing_module: self.item_module(&meta.item.item),
                 existing: Box::new(existing),
                 current: Box::new(meta),
+                module: module.clone(),
             });
         }
 
+        self.item_modules
+            .insert(meta.item.item.clone(), module.clone());
         Ok(())
     }
 
@@ -452,7 +1095,12 @@ impl Context {
         let item = module.item.extended(&*ty.name);
         let hash = Hash::type_hash(&item);
 
+        if ty.hidden {
+            self.hidden_types.insert(type_hash);
+        }
+
         self.install_type_info(
+            &module.item,
             hash,
             ContextTypeInfo {
                 type_check: TypeCheck::Type(type_hash),
@@ -462,40 +1110,89 @@ impl Context {
             },
         )?;
 
-        self.install_meta(CompileMeta {
-            item: Arc::new(item.into()),
-            kind: CompileMetaKind::Struct {
-                type_hash,
-                object: CompileMetaStruct {
-                    fields: Default::default(),
+        if ty.hidden {
+            return Ok(());
+        }
+
+        self.install_meta(
+            &module.item,
+            CompileMeta {
+                item: Arc::new(item.into()),
+                kind: CompileMetaKind::Struct {
+                    type_hash,
+                    object: CompileMetaStruct {
+                        fields: Default::default(),
+                    },
                 },
+                source: None,
             },
-            source: None,
-        })?;
+        )?;
 
         Ok(())
     }
 
-    fn install_type_info(&mut self, hash: Hash, info: ContextTypeInfo) -> Result<(), ContextError> {
-        self.names.insert(&info.item);
+    fn install_type_info(
+        &mut self,
+        module: &Item,
+        hash: Hash,
+        info: ContextTypeInfo,
+    ) -> Result<(), ContextError> {
+        if !self.hidden_types.contains(&info.type_hash) {
+            self.names.insert(&info.item);
+        }
 
         // reverse lookup for types.
         if let Some(existing) = self.types_rev.insert(info.type_hash, hash) {
-            return Err(ContextError::ConflictingTypeHash { hash, existing });
+            let existing_module = match self.types.get(&existing) {
+                Some(ty) => self.item_module(&ty.item),
+                None => Item::new(),
+            };
+
+            return Err(ContextError::ConflictingTypeHash {
+                hash,
+                existing,
+                existing_module,
+                module: module.clone(),
+            });
         }
 
         self.constants.insert(
             Hash::instance_function(info.type_hash, Protocol::INTO_TYPE_NAME),
-            ConstValue::String(info.item.to_string()),
+            ConstValue::String(info.item.to_string()).into(),
         );
 
+        let item = info.item.clone();
+
         if let Some(existing) = self.types.insert(hash, info) {
             return Err(ContextError::ConflictingType {
+                existing_module: self.item_module(&existing.item),
                 item: existing.item,
                 existing: existing.type_info,
+                module: module.clone(),
             });
         }
 
+        // NB: the same name might already be used by something other than a
+        // type, in which case installing its meta reports the conflict.
+        self.item_modules
+            .entry(item)
+            .or_insert_with(|| module.clone());
+        Ok(())
+    }
+
+    /// Check that the given item isn't the name of a global.
+    ///
+    /// Globals aren't installed through modules, so they would otherwise be
+    /// silently replaced by a module installed after them.
+    fn check_global(&self, item: &Item) -> Result<(), ContextError> {
+        if let Some(CompileMeta {
+            kind: CompileMetaKind::Global { .. },
+            ..
+        }) = self.meta.get(item)
+        {
+            return Err(ContextError::ConflictingGlobal { name: item.clone() });
+        }
+
         Ok(())
     }
 
@@ -507,6 +1204,7 @@ impl Context {
         f: &ModuleFn,
     ) -> Result<(), ContextError> {
         let item = module.item.join(item);
+        self.check_global(&item)?;
         self.names.insert(&item);
 
         let hash = Hash::type_hash(&item);
@@ -517,19 +1215,40 @@ impl Context {
             args: f.args,
         };
 
-        if let Some(old) = self.functions_info.insert(hash, signature) {
-            return Err(ContextError::ConflictingFunction {
-                signature: old,
-                hash,
-            });
-        }
+        self.install_function_info(&module.item, hash, signature)?;
+        self.item_modules.insert(item.clone(), module.item.clone());
 
         self.constants.insert(
             Hash::instance_function(hash, Protocol::INTO_TYPE_NAME),
-            ConstValue::String(item.to_string()),
+            ConstValue::String(item.to_string()).into(),
         );
 
-        self.functions.insert(hash, f.handler.clone());
+        self.functions
+            .insert(hash, function_handler(f.handler.clone(), item.clone()));
+
+        if let Some(arg_types) = &f.arg_types {
+            self.functions_arg_types.insert(hash, arg_types.clone());
+        }
+
+        if let Some(arg_names) = &f.arg_names {
+            self.functions_arg_names.insert(hash, arg_names.clone());
+        }
+
+        if let Some(return_type) = f.return_type {
+            self.return_types.push((item.clone(), return_type));
+        }
+
+        if f.constant {
+            self.const_functions.insert(hash);
+        }
+
+        if let Some(type_hash) = f.associated_type {
+            self.static_functions
+                .entry(type_hash)
+                .or_default()
+                .push(hash);
+        }
+
         self.meta.insert(
             item.clone(),
             CompileMeta {
@@ -559,6 +1278,7 @@ impl Context {
         let hash = Hash::type_hash(&item);
 
         self.macros.insert(hash, m.handler.clone());
+        self.macro_items.insert(hash, item);
         Ok(())
     }
 
@@ -567,9 +1287,10 @@ impl Context {
         &mut self,
         module: &Module,
         item: &Item,
-        v: &ConstValue,
+        v: &ModuleConst,
     ) -> Result<(), ContextError> {
         let item = module.item.join(item);
+        self.check_global(&item)?;
 
         self.names.insert(&item);
 
@@ -577,6 +1298,15 @@ impl Context {
 
         self.constants.insert(hash, v.clone());
 
+        self.item_modules.insert(item.clone(), module.item.clone());
+
+        // NB: the meta of lazy constants is constructed when it's looked up,
+        // since building it requires the constant to be evaluated.
+        let v = match v {
+            ModuleConst::Value(v) => v,
+            ModuleConst::Lazy(..) => return Ok(()),
+        };
+
         self.meta.insert(
             item.clone(),
             CompileMeta {
@@ -592,6 +1322,7 @@ impl Context {
 
     fn install_associated_function(
         &mut self,
+        module: &Item,
         type_hash: Hash,
         hash: Hash,
         assoc: &ModuleAssociatedFn,
@@ -620,31 +1351,53 @@ impl Context {
             self_type_info: info.type_info.clone(),
         };
         let item = info.item.extended(&assoc.name);
+        self.check_global(&item)?;
+
+        if self.functions_info.contains_key(&hash) {
+            return Err(ContextError::ConflictingInstanceFunction {
+                type_info: info.type_info.clone(),
+                name: assoc.name.clone(),
+                existing_module: self
+                    .function_modules
+                    .get(&hash)
+                    .cloned()
+                    .unwrap_or_default(),
+                module: module.clone(),
+            });
+        }
 
         self.constants.insert(
             Hash::instance_function(hash, Protocol::INTO_TYPE_NAME),
-            ConstValue::String(item.to_string()),
+            ConstValue::String(item.to_string()).into(),
         );
 
-        if let Some(old) = self.functions_info.insert(hash, signature) {
-            return Err(ContextError::ConflictingFunction {
-                signature: old,
-                hash,
-            });
-        }
-        self.meta.insert(
-            item.clone(),
-            CompileMeta {
-                item: Arc::new(item.into()),
-                kind: CompileMetaKind::Function {
-                    type_hash: hash,
-                    is_test: false,
+        self.install_function_info(module, hash, signature)?;
+        self.item_modules.insert(item.clone(), module.clone());
+
+        if !self.hidden_types.contains(&type_hash) {
+            self.meta.insert(
+                item.clone(),
+                CompileMeta {
+                    item: Arc::new(item.clone().into()),
+                    kind: CompileMetaKind::Function {
+                        type_hash: hash,
+                        is_test: false,
+                    },
+                    source: None,
                 },
-                source: None,
-            },
-        );
+            );
+        }
 
-        self.functions.insert(hash, assoc.handler.clone());
+        if let Some(return_type) = assoc.return_type {
+            self.return_types.push((item.clone(), return_type));
+        }
+
+        if let Some(arg_types) = &assoc.arg_types {
+            self.functions_arg_types.insert(hash, arg_types.clone());
+        }
+
+        self.functions
+            .insert(hash, function_handler(assoc.handler.clone(), item));
         Ok(())
     }
 
@@ -661,9 +1414,10 @@ impl Context {
         let item = module.item.extended(&*unit_type.name);
         let hash = Hash::type_hash(&item);
         self.unit_type = Some(Hash::type_hash(&item));
-        self.add_internal_tuple(None, item.clone(), 0, || ())?;
+        self.add_internal_tuple(&module.item, None, item.clone(), 0, || ())?;
 
         self.install_type_info(
+            &module.item,
             hash,
             ContextTypeInfo {
                 type_check: TypeCheck::Unit,
@@ -691,15 +1445,19 @@ impl Context {
         let enum_item = module.item.join(&internal_enum.base_type);
         let enum_hash = Hash::type_hash(&enum_item);
 
-        self.install_meta(CompileMeta {
-            item: Arc::new(enum_item.clone().into()),
-            kind: CompileMetaKind::Enum {
-                type_hash: internal_enum.static_type.hash,
+        self.install_meta(
+            &module.item,
+            CompileMeta {
+                item: Arc::new(enum_item.clone().into()),
+                kind: CompileMetaKind::Enum {
+                    type_hash: internal_enum.static_type.hash,
+                },
+                source: None,
             },
-            source: None,
-        })?;
+        )?;
 
         self.install_type_info(
+            &module.item,
             enum_hash,
             ContextTypeInfo {
                 type_check: TypeCheck::Type(internal_enum.static_type.hash),
@@ -714,6 +1472,7 @@ impl Context {
             let hash = Hash::type_hash(&item);
 
             self.install_type_info(
+                &module.item,
                 hash,
                 ContextTypeInfo {
                     type_check: variant.type_check,
@@ -723,18 +1482,21 @@ impl Context {
                 },
             )?;
 
-            self.install_meta(CompileMeta {
-                item: Arc::new(item.clone().into()),
-                kind: CompileMetaKind::TupleVariant {
-                    type_hash: variant.type_hash,
-                    enum_item: enum_item.clone(),
-                    tuple: CompileMetaTuple {
-                        args: variant.args,
-                        hash,
+            self.install_meta(
+                &module.item,
+                CompileMeta {
+                    item: Arc::new(item.clone().into()),
+                    kind: CompileMetaKind::TupleVariant {
+                        type_hash: variant.type_hash,
+                        enum_item: enum_item.clone(),
+                        tuple: CompileMetaTuple {
+                            args: variant.args,
+                            hash,
+                        },
                     },
+                    source: None,
                 },
-                source: None,
-            })?;
+            )?;
 
             let signature = ContextSignature::Function {
                 type_hash: variant.type_hash,
@@ -742,12 +1504,7 @@ impl Context {
                 args: Some(variant.args),
             };
 
-            if let Some(old) = self.functions_info.insert(hash, signature) {
-                return Err(ContextError::ConflictingFunction {
-                    signature: old,
-                    hash,
-                });
-            }
+            self.install_function_info(&module.item, hash, signature)?;
             self.functions.insert(hash, variant.constructor.clone());
         }
 
@@ -757,6 +1514,7 @@ impl Context {
     /// Add a piece of internal tuple meta.
     fn add_internal_tuple<C, Args>(
         &mut self,
+        module: &Item,
         enum_item: Option<Item>,
         item: Item,
         args: usize,
@@ -788,14 +1546,14 @@ impl Context {
             },
         };
 
-        self.install_meta(meta)?;
+        self.install_meta(module, meta)?;
 
         let constructor: Arc<Handler> =
             Arc::new(move |stack, args| constructor.fn_call(stack, args));
 
         self.constants.insert(
             Hash::instance_function(type_hash, Protocol::INTO_TYPE_NAME),
-            ConstValue::String(item.to_string()),
+            ConstValue::String(item.to_string()).into(),
         );
 
         let signature = ContextSignature::Function {
@@ -804,17 +1562,110 @@ impl Context {
             args: Some(args),
         };
 
+        self.install_function_info(module, hash, signature)?;
+        self.functions.insert(hash, constructor);
+        Ok(())
+    }
+
+    /// Install information on a function, recording which module installed
+    /// it so that conflicts can report both modules involved.
+    fn install_function_info(
+        &mut self,
+        module: &Item,
+        hash: Hash,
+        signature: ContextSignature,
+    ) -> Result<(), ContextError> {
         if let Some(old) = self.functions_info.insert(hash, signature) {
             return Err(ContextError::ConflictingFunction {
-                signature: old,
+                signature: Box::new(old),
                 hash,
+                existing_module: self
+                    .function_modules
+                    .get(&hash)
+                    .cloned()
+                    .unwrap_or_default(),
+                module: module.clone(),
             });
         }
-        self.functions.insert(hash, constructor);
+
+        self.function_modules.insert(hash, module.clone());
         Ok(())
     }
 }
 
+/// Wrap the handler of the native function with the given item, so that bad
+/// argument errors it raises record which function they were raised in.
+fn function_handler(handler: Arc<Handler>, item: Item) -> Arc<Handler> {
+    Arc::new(move |stack, args| handler(stack, args).map_err(|e| e.with_function(&item)))
+}
+
+/// Construct the meta of a global with the given name.
+fn global_meta(item: &Item, hash: Hash) -> CompileMeta {
+    CompileMeta {
+        item: Arc::new(item.clone().into()),
+        kind: CompileMetaKind::Global { hash },
+        source: None,
+    }
+}
+
+/// Display the name of the module with the given item in a conflict error,
+/// where the root module is named explicitly since its item is empty.
+struct ModuleName<'a>(&'a Item);
+
+impl fmt::Display for ModuleName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "the root module")
+        } else {
+            write!(f, "module `{}`", self.0)
+        }
+    }
+}
+
+/// Get the item of the function described by the given signature.
+fn signature_item(signature: &ContextSignature) -> Item {
+    match signature {
+        ContextSignature::Function { item, .. } => item.clone(),
+        ContextSignature::Instance { item, name, .. } => item.extended(name),
+    }
+}
+
+/// Wrap the given handler so that the observer is notified every time it's
+/// called and every time it returns.
+fn observed_handler(
+    handler: Arc<Handler>,
+    observer: Arc<CallObserver>,
+    item: Item,
+) -> Arc<Handler> {
+    Arc::new(move |stack, args| {
+        observer(&item, args, CallPhase::Before);
+        let result = handler(stack, args);
+        observer(&item, args, CallPhase::After { ok: result.is_ok() });
+        result
+    })
+}
+
+/// Wrap the given handler so that any panics it raises are converted into
+/// errors.
+fn catch_unwind_handler(handler: Arc<Handler>) -> Arc<Handler> {
+    Arc::new(move |stack, args| {
+        match panic::catch_unwind(panic::AssertUnwindSafe(|| handler(stack, args))) {
+            Ok(result) => result,
+            Err(payload) => {
+                let message = if let Some(message) = payload.downcast_ref::<&'static str>() {
+                    (*message).to_owned()
+                } else if let Some(message) = payload.downcast_ref::<String>() {
+                    message.clone()
+                } else {
+                    String::from("native function panicked")
+                };
+
+                Err(VmError::panic(message))
+            }
+        }
+    })
+}
+
 impl fmt::Debug for Context {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Context")
@@ -7,7 +7,7 @@
 //!
 //! See the corresponding function for documentation.
 
-use crate::{RuntimeContext, Unit, VmError, VmErrorKind};
+use crate::{Hash, OverflowPolicy, RuntimeContext, TypeInfo, Unit, VmError, VmErrorKind};
 use std::cell::Cell;
 use std::ptr;
 use std::sync::Arc;
@@ -32,6 +32,37 @@ where
     c(unsafe { &*context }, unsafe { &*unit })
 }
 
+/// Lookup the type information registered for the given type hash in the
+/// current context.
+///
+/// Returns `None` if there's no environment, or if the type isn't registered.
+pub(crate) fn type_info(type_hash: Hash) -> Option<TypeInfo> {
+    let env = ENV.with(|env| env.get());
+
+    if env.context.is_null() {
+        return None;
+    }
+
+    // Safety: see [with].
+    let context = unsafe { &*env.context };
+    context.type_info(type_hash).cloned()
+}
+
+/// Get the overflow policy of the current context.
+///
+/// Returns the default policy if there's no environment.
+pub(crate) fn overflow_policy() -> OverflowPolicy {
+    let env = ENV.with(|env| env.get());
+
+    if env.context.is_null() {
+        return OverflowPolicy::default();
+    }
+
+    // Safety: see [with].
+    let context = unsafe { &*env.context };
+    context.overflow_policy()
+}
+
 pub(crate) struct Guard {
     old: Env,
 }
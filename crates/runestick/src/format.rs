@@ -1,7 +1,7 @@
 //! Types for dealing with formatting specifications.
 
 use crate::protocol_caller::ProtocolCaller;
-use crate::{FromValue, InstallWith, Named, RawStr, Value, VmError, VmErrorKind};
+use crate::{FromValue, Hash, InstallWith, Named, RawStr, Value, VmError, VmErrorKind};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::fmt;
@@ -45,6 +45,10 @@ impl FromValue for Format {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(*value.into_format()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FORMAT_TYPE.hash)
+    }
 }
 
 /// A format specification.
@@ -1,10 +1,19 @@
-use crate::{Any, AnyObj, Mut, RawMut, RawRef, Ref, Shared, StaticString, Value, VmError};
+use crate::{Any, AnyObj, Hash, Mut, RawMut, RawRef, Ref, Shared, StaticString, Value, VmError};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// Trait for converting from a value.
 pub trait FromValue: 'static + Sized {
     /// Try to convert to the given type, from the given value.
     fn from_value(value: Value) -> Result<Self, VmError>;
+
+    /// The type hash of the values this type can be converted from, if it's
+    /// statically known.
+    ///
+    /// This is only used as a hint for tooling.
+    fn type_hint() -> Option<Hash> {
+        None
+    }
 }
 
 /// A potentially unsafe conversion for value conversion.
@@ -44,6 +53,14 @@ pub trait UnsafeFromValue: Sized {
     /// You must also make sure that the returned value does not outlive the
     /// guard.
     unsafe fn unsafe_coerce(output: Self::Output) -> Self;
+
+    /// The type hash of the values this type can be converted from, if it's
+    /// statically known.
+    ///
+    /// This is only used as a hint for tooling.
+    fn type_hint() -> Option<Hash> {
+        None
+    }
 }
 
 impl<T> FromValue for T
@@ -53,6 +70,10 @@ where
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_any()?.take_downcast()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(T::type_hash())
+    }
 }
 
 impl<T> FromValue for Mut<T>
@@ -62,6 +83,10 @@ where
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_any()?.downcast_into_mut()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(T::type_hash())
+    }
 }
 
 impl<T> FromValue for Ref<T>
@@ -71,6 +96,10 @@ where
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_any()?.downcast_into_ref()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(T::type_hash())
+    }
 }
 
 impl FromValue for Shared<AnyObj> {
@@ -79,6 +108,10 @@ impl FromValue for Shared<AnyObj> {
     }
 }
 
+/// Owned values like `i64` and `f64` don't need a guard, so their guard is
+/// `()` and the conversion boils down to matching on the [Value] variant.
+/// Native functions taking only such arguments therefore don't pay for any
+/// borrow tracking.
 impl<T> UnsafeFromValue for T
 where
     T: FromValue,
@@ -93,6 +126,10 @@ where
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         output
     }
+
+    fn type_hint() -> Option<Hash> {
+        <T as FromValue>::type_hint()
+    }
 }
 
 impl FromValue for Value {
@@ -113,6 +150,10 @@ where
             None => None,
         })
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::OPTION_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &Option<Value> {
@@ -126,6 +167,10 @@ impl UnsafeFromValue for &Option<Value> {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::OPTION_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &mut Option<Value> {
@@ -139,6 +184,10 @@ impl UnsafeFromValue for &mut Option<Value> {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::OPTION_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &mut Result<Value, Value> {
@@ -152,6 +201,10 @@ impl UnsafeFromValue for &mut Result<Value, Value> {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::RESULT_TYPE.hash)
+    }
 }
 
 // String impls
@@ -164,6 +217,10 @@ impl FromValue for String {
             actual => Err(VmError::expected::<String>(actual.type_info()?)),
         }
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
 }
 
 impl FromValue for Mut<String> {
@@ -173,6 +230,10 @@ impl FromValue for Mut<String> {
             actual => Err(VmError::expected::<String>(actual.type_info()?)),
         }
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
 }
 
 impl FromValue for Ref<String> {
@@ -182,6 +243,10 @@ impl FromValue for Ref<String> {
             actual => Err(VmError::expected::<String>(actual.type_info()?)),
         }
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
 }
 
 impl FromValue for Box<str> {
@@ -190,6 +255,10 @@ impl FromValue for Box<str> {
         let string = string.borrow_ref()?.clone();
         Ok(string.into_boxed_str())
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
 }
 
 /// Raw guard used for `&str` references.
@@ -224,6 +293,10 @@ impl UnsafeFromValue for &str {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &mut str {
@@ -248,6 +321,10 @@ impl UnsafeFromValue for &mut str {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &String {
@@ -271,6 +348,10 @@ impl UnsafeFromValue for &String {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &mut String {
@@ -293,6 +374,42 @@ impl UnsafeFromValue for &mut String {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
+}
+
+// Path impls
+
+impl FromValue for PathBuf {
+    fn from_value(value: Value) -> Result<Self, VmError> {
+        Ok(PathBuf::from(<String as FromValue>::from_value(value)?))
+    }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
+}
+
+impl UnsafeFromValue for &Path {
+    type Output = *const Path;
+    type Guard = StrGuard;
+
+    fn from_value(value: Value) -> Result<(Self::Output, Self::Guard), VmError> {
+        let (s, guard) = <&str as UnsafeFromValue>::from_value(value)?;
+        // Safety: the guard keeps the string alive for as long as the path is
+        // being referenced.
+        Ok((Path::new(unsafe { &*s }), guard))
+    }
+
+    unsafe fn unsafe_coerce(output: Self::Output) -> Self {
+        &*output
+    }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
 }
 
 // Result impls
@@ -308,6 +425,10 @@ where
             Err(err) => Err(E::from_value(err)?),
         })
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::RESULT_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &Result<Value, Value> {
@@ -323,6 +444,10 @@ impl UnsafeFromValue for &Result<Value, Value> {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::RESULT_TYPE.hash)
+    }
 }
 
 // number impls
@@ -331,30 +456,50 @@ impl FromValue for () {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_unit()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::UNIT_TYPE.hash)
+    }
 }
 
 impl FromValue for u8 {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_byte()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::BYTE_TYPE.hash)
+    }
 }
 
 impl FromValue for bool {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_bool()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::BOOL_TYPE.hash)
+    }
 }
 
 impl FromValue for char {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_char()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::CHAR_TYPE.hash)
+    }
 }
 
 impl FromValue for i64 {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_integer()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::INTEGER_TYPE.hash)
+    }
 }
 
 macro_rules! impl_number {
@@ -366,14 +511,13 @@ macro_rules! impl_number {
 
                 match integer.try_into() {
                     Ok(number) => Ok(number),
-                    Err(..) => Err($crate::VmError::from(
-                        $crate::VmErrorKind::ValueToIntegerCoercionError {
-                            from: $crate::VmIntegerRepr::from(integer),
-                            to: std::any::type_name::<Self>(),
-                        },
-                    )),
+                    Err(..) => Err($crate::VmError::integer_coercion::<Self>(integer)),
                 }
             }
+
+            fn type_hint() -> Option<$crate::Hash> {
+                Some($crate::INTEGER_TYPE.hash)
+            }
         }
     };
 }
@@ -393,12 +537,20 @@ impl FromValue for f64 {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_float()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FLOAT_TYPE.hash)
+    }
 }
 
 impl FromValue for f32 {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_float()? as f32)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FLOAT_TYPE.hash)
+    }
 }
 
 // map impls
@@ -421,8 +573,33 @@ macro_rules! impl_map {
 
                 Ok(output)
             }
+
+            fn type_hint() -> Option<$crate::Hash> {
+                Some($crate::OBJECT_TYPE.hash)
+            }
         }
     };
 }
 
 impl_map!(std::collections::HashMap<String, T>);
+
+/// Unlike a `HashMap`, the keys of the map are kept in the same order as they
+/// are in the [Object][crate::Object] it was converted from.
+impl<T> FromValue for std::collections::BTreeMap<String, T>
+where
+    T: FromValue,
+{
+    fn from_value(value: Value) -> Result<Self, VmError> {
+        let object = value.into_object()?;
+        let object = object.take()?;
+
+        object
+            .into_iter()
+            .map(|(key, value)| Ok((key, T::from_value(value)?)))
+            .collect()
+    }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::OBJECT_TYPE.hash)
+    }
+}
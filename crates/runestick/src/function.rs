@@ -250,6 +250,7 @@ where
             return Err(VmError::from(VmErrorKind::BadArgumentCount {
                 expected,
                 actual,
+                function: None,
             }));
         }
 
@@ -483,24 +484,40 @@ impl FromValue for SyncFunction {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_function()?.take()?.into_sync()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FUNCTION_TYPE.hash)
+    }
 }
 
 impl FromValue for Function {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_function()?.take()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FUNCTION_TYPE.hash)
+    }
 }
 
 impl FromValue for Shared<Function> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_function()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FUNCTION_TYPE.hash)
+    }
 }
 
 impl FromValue for Ref<Function> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_function()?.into_ref()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FUNCTION_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &Function {
@@ -516,6 +533,10 @@ impl UnsafeFromValue for &Function {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FUNCTION_TYPE.hash)
+    }
 }
 
 #[cfg(test)]
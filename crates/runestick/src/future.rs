@@ -1,6 +1,7 @@
+use crate::timer::Deadline;
 use crate::{
-    FromValue, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared, ToValue,
-    UnsafeFromValue, Value, VmError,
+    FromValue, Hash, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared, ToValue,
+    UnsafeFromValue, Value, VmError, VmErrorKind,
 };
 use pin_project::pin_project;
 use std::fmt;
@@ -8,6 +9,7 @@ use std::fmt;
 use std::future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 
 /// dyn future alias.
 type DynFuture = dyn future::Future<Output = Result<Value, VmError>> + 'static;
@@ -102,16 +104,83 @@ where
     }
 }
 
+/// Future wrapper which fails with [VmErrorKind::HostTimeout] if the wrapped
+/// future doesn't complete within the given duration.
+///
+/// The duration is counted from when the future is first polled. The deadline
+/// is only registered with the shared timer if the wrapped future doesn't
+/// complete immediately, and is cancelled once the future completes or is
+/// dropped.
+#[pin_project]
+pub(crate) struct TimeoutFuture<F> {
+    #[pin]
+    future: F,
+    duration: Duration,
+    deadline: Option<Instant>,
+    deadline_timer: Option<Deadline>,
+}
+
+impl<F> TimeoutFuture<F> {
+    /// Construct a new timeout future.
+    pub(crate) fn new(future: F, duration: Duration) -> Self {
+        Self {
+            future,
+            duration,
+            deadline: None,
+            deadline_timer: None,
+        }
+    }
+}
+
+impl<F> future::Future for TimeoutFuture<F>
+where
+    F: future::Future<Output = Result<Value, VmError>>,
+{
+    type Output = Result<Value, VmError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let duration = *this.duration;
+        let deadline = *this
+            .deadline
+            .get_or_insert_with(|| Instant::now() + duration);
+
+        if let Poll::Ready(result) = this.future.poll(cx) {
+            *this.deadline_timer = None;
+            return Poll::Ready(result);
+        }
+
+        if Instant::now() >= deadline {
+            return Poll::Ready(Err(VmError::from(VmErrorKind::HostTimeout { duration })));
+        }
+
+        match this.deadline_timer {
+            Some(timer) => timer.update(cx.waker()),
+            None => *this.deadline_timer = Some(Deadline::new(deadline, cx.waker())),
+        }
+
+        Poll::Pending
+    }
+}
+
 impl FromValue for Shared<Future> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         value.into_shared_future()
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FUTURE_TYPE.hash)
+    }
 }
 
 impl FromValue for Future {
     fn from_value(value: Value) -> Result<Self, VmError> {
         value.into_future()
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FUTURE_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &Future {
@@ -127,6 +196,10 @@ impl UnsafeFromValue for &Future {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FUTURE_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &mut Future {
@@ -141,6 +214,10 @@ impl UnsafeFromValue for &mut Future {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::FUTURE_TYPE.hash)
+    }
 }
 
 impl Named for Future {
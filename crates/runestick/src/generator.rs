@@ -1,5 +1,5 @@
 use crate::{
-    FromValue, GeneratorState, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared,
+    FromValue, GeneratorState, Hash, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared,
     UnsafeFromValue, Value, Vm, VmError, VmErrorKind, VmExecution,
 };
 use std::fmt;
@@ -97,6 +97,10 @@ impl FromValue for Shared<Generator> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_generator()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::GENERATOR_TYPE.hash)
+    }
 }
 
 impl FromValue for Generator {
@@ -104,6 +108,10 @@ impl FromValue for Generator {
         let generator = value.into_generator()?;
         Ok(generator.take()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::GENERATOR_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &Generator {
@@ -119,6 +127,10 @@ impl UnsafeFromValue for &Generator {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::GENERATOR_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &mut Generator {
@@ -133,4 +145,8 @@ impl UnsafeFromValue for &mut Generator {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::GENERATOR_TYPE.hash)
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{FromValue, Mut, RawMut, RawRef, Ref, Shared, UnsafeFromValue, Value, VmError};
+use crate::{FromValue, Hash, Mut, RawMut, RawRef, Ref, Shared, UnsafeFromValue, Value, VmError};
 
 /// The state of a generator.
 #[derive(Debug)]
@@ -25,6 +25,10 @@ impl FromValue for Shared<GeneratorState> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_generator_state()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::GENERATOR_STATE_TYPE.hash)
+    }
 }
 
 impl FromValue for GeneratorState {
@@ -32,6 +36,10 @@ impl FromValue for GeneratorState {
         let state = value.into_generator_state()?;
         Ok(state.take()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::GENERATOR_STATE_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &GeneratorState {
@@ -47,6 +55,10 @@ impl UnsafeFromValue for &GeneratorState {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::GENERATOR_STATE_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &mut GeneratorState {
@@ -61,4 +73,8 @@ impl UnsafeFromValue for &mut GeneratorState {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::GENERATOR_STATE_TYPE.hash)
+    }
 }
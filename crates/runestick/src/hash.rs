@@ -4,7 +4,6 @@ use std::any;
 use std::fmt;
 use std::hash;
 use std::hash::{BuildHasher as _, BuildHasherDefault, Hash as _, Hasher as _};
-use std::mem;
 use twox_hash::XxHash64;
 
 const SEP: usize = 0x7f;
@@ -49,9 +48,9 @@ impl Hash {
 
     /// Construct a hash from a type id.
     pub fn from_type_id(type_id: any::TypeId) -> Self {
-        // Safety: a type id is exactly a 64-bit unsigned integer.
-        // And has an identical bit pattern to `Hash`.
-        unsafe { mem::transmute(type_id) }
+        // NB: a type id isn't guaranteed to be 64 bits wide, so it's hashed
+        // rather than reinterpreted as a hash.
+        Self::of(type_id)
     }
 
     /// Construct a hash to an instance function, where the instance is a
@@ -1,6 +1,7 @@
+use crate::protocol_caller::{EnvProtocolCaller, ProtocolCaller as _};
 use crate::{
-    FromValue, Function, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, ToValue,
-    UnsafeFromValue, Value, VmError, VmErrorKind,
+    FromValue, Function, Hash, InstallWith, Mut, Named, Protocol, RawMut, RawRef, RawStr, Ref,
+    ToValue, UnsafeFromValue, Value, VmError, VmErrorKind,
 };
 use std::fmt;
 use std::iter;
@@ -28,19 +29,23 @@ trait RuneIterator: fmt::Debug {
     /// Get the next back value out of the iterator.
     fn next_back(&mut self) -> Result<Option<Value>, VmError>;
 
+    /// Test if the size hint of the iterator is exact.
+    #[inline]
+    fn is_exact_size(&self) -> bool {
+        matches!(self.size_hint(), (lower, Some(upper)) if lower == upper)
+    }
+
     /// Get the length of the iterator if it is an exact length iterator.
     #[inline]
     fn len(&self) -> Result<usize, VmError> {
-        let (lower, upper) = self.size_hint();
-
-        if !matches!(upper, Some(upper) if lower == upper) {
+        if !self.is_exact_size() {
             return Err(VmError::panic(format!(
                 "`{:?}` is not an exact-sized iterator",
                 self
             )));
         }
 
-        Ok(lower)
+        Ok(self.size_hint().0)
     }
 }
 
@@ -109,6 +114,20 @@ impl Iterator {
         }
     }
 
+    /// Construct an iterator over the elements of `target` with an index less
+    /// than `len`, where each element is fetched using the
+    /// [INDEX_GET][crate::Protocol::INDEX_GET] protocol.
+    pub(crate) fn from_index_get(target: Value, len: usize) -> Self {
+        Self::from_double_ended(
+            "std::iter::IndexGet",
+            IndexGet {
+                target,
+                start: 0,
+                end: len,
+            },
+        )
+    }
+
     /// Creates an iterator that yields an element exactly once.
     pub fn once(value: Value) -> Self {
         Self {
@@ -121,6 +140,25 @@ impl Iterator {
         self.iter.size_hint()
     }
 
+    /// Get the number of remaining elements without consuming the iterator.
+    ///
+    /// This is only supported for iterators whose size hint is exact, like
+    /// iterators over vectors or host iterators implementing
+    /// [ExactSizeIterator][std::iter::ExactSizeIterator]. Other iterators
+    /// raise a panic, in which case `count` can be used instead.
+    pub fn len(&self) -> Result<usize, VmError> {
+        self.iter.len()
+    }
+
+    /// Test if there are no remaining elements, without consuming the
+    /// iterator.
+    ///
+    /// Like [len][Iterator::len], this is only supported for iterators whose
+    /// size hint is exact.
+    pub fn is_empty(&self) -> Result<bool, VmError> {
+        Ok(self.len()? == 0)
+    }
+
     /// Get the next value out of the iterator.
     pub fn next(&mut self) -> Result<Option<Value>, VmError> {
         self.iter.next()
@@ -259,6 +297,24 @@ impl Iterator {
         }
     }
 
+    /// Step over the iterator by the given step, starting with its first
+    /// element.
+    ///
+    /// Errors if the step is `0`.
+    pub fn step_by(self, step: usize) -> Result<Self, VmError> {
+        if step == 0 {
+            return Err(VmError::panic("`step_by` requires a non-zero step"));
+        }
+
+        Ok(Self {
+            iter: IterRepr::StepBy(Box::new(StepBy {
+                iter: self.iter,
+                step: step - 1,
+                first_take: true,
+            })),
+        })
+    }
+
     /// Take the given number of elements from the iterator.
     pub fn take(self, n: usize) -> Self {
         Self {
@@ -277,6 +333,24 @@ impl Iterator {
         Ok(c)
     }
 
+    /// Consume the iterator and return its last element.
+    ///
+    /// Double-ended iterators are not walked through, instead the last element
+    /// is fetched directly from the back.
+    pub fn last(mut self) -> Result<Option<Value>, VmError> {
+        if self.iter.is_double_ended() {
+            return self.iter.next_back();
+        }
+
+        let mut last = None;
+
+        while let Some(value) = self.iter.next()? {
+            last = Some(value);
+        }
+
+        Ok(last)
+    }
+
     /// Create a peekable iterator.
     pub fn peekable(self) -> Self {
         Self {
@@ -352,6 +426,10 @@ impl FromValue for Iterator {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_iterator()?.take()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::ITERATOR_TYPE.hash)
+    }
 }
 
 impl<'a> UnsafeFromValue for &'a Iterator {
@@ -366,6 +444,10 @@ impl<'a> UnsafeFromValue for &'a Iterator {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::ITERATOR_TYPE.hash)
+    }
 }
 
 impl<'a> UnsafeFromValue for &'a mut Iterator {
@@ -380,6 +462,10 @@ impl<'a> UnsafeFromValue for &'a mut Iterator {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::ITERATOR_TYPE.hash)
+    }
 }
 
 /// The inner representation of an [Iterator]. It handles all the necessary
@@ -394,6 +480,7 @@ enum IterRepr {
     Chain(Box<Chain<Self, Self>>),
     Enumerate(Box<Enumerate<Self>>),
     Skip(Box<Skip<Self>>),
+    StepBy(Box<StepBy<Self>>),
     Take(Box<Take<Self>>),
     Peekable(Box<Peekable<Self>>),
     Empty,
@@ -413,6 +500,7 @@ impl RuneIterator for IterRepr {
             Self::Chain(iter) => iter.is_double_ended(),
             Self::Enumerate(iter) => iter.is_double_ended(),
             Self::Skip(iter) => iter.is_double_ended(),
+            Self::StepBy(iter) => iter.is_double_ended(),
             Self::Take(iter) => iter.is_double_ended(),
             Self::Peekable(iter) => iter.is_double_ended(),
             Self::Empty => true,
@@ -432,6 +520,7 @@ impl RuneIterator for IterRepr {
             Self::Chain(iter) => iter.size_hint(),
             Self::Enumerate(iter) => iter.size_hint(),
             Self::Skip(iter) => iter.size_hint(),
+            Self::StepBy(iter) => iter.size_hint(),
             Self::Take(iter) => iter.size_hint(),
             Self::Peekable(iter) => iter.size_hint(),
             Self::Empty => (0, Some(0)),
@@ -450,6 +539,7 @@ impl RuneIterator for IterRepr {
             Self::Chain(iter) => iter.next(),
             Self::Enumerate(iter) => iter.next(),
             Self::Skip(iter) => iter.next(),
+            Self::StepBy(iter) => iter.next(),
             Self::Take(iter) => iter.next(),
             Self::Peekable(iter) => iter.next(),
             Self::Empty => Ok(None),
@@ -473,6 +563,7 @@ impl RuneIterator for IterRepr {
             Self::Chain(iter) => iter.next_back(),
             Self::Enumerate(iter) => iter.next_back(),
             Self::Skip(iter) => iter.next_back(),
+            Self::StepBy(iter) => iter.next_back(),
             Self::Take(iter) => iter.next_back(),
             Self::Peekable(iter) => iter.next_back(),
             Self::Empty => Ok(None),
@@ -493,6 +584,7 @@ impl fmt::Debug for IterRepr {
             Self::Chain(iter) => write!(f, "{:?}", iter),
             Self::Enumerate(iter) => write!(f, "{:?}", iter),
             Self::Skip(iter) => write!(f, "{:?}", iter),
+            Self::StepBy(iter) => write!(f, "{:?}", iter),
             Self::Take(iter) => write!(f, "{:?}", iter),
             Self::Peekable(iter) => write!(f, "{:?}", iter),
             Self::Empty => write!(f, "std::iter::Empty"),
@@ -649,7 +741,9 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        // NB: any number of elements might be filtered out.
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
     }
 
     fn next(&mut self) -> Result<Option<Value>, VmError> {
@@ -673,6 +767,47 @@ where
     }
 }
 
+/// Iterator over a value which supports indexing by integer.
+struct IndexGet {
+    target: Value,
+    start: usize,
+    end: usize,
+}
+
+impl IndexGet {
+    fn get(&self, index: usize) -> Result<Value, VmError> {
+        EnvProtocolCaller.call_protocol_fn(Protocol::INDEX_GET, self.target.clone(), (index,))
+    }
+}
+
+impl IteratorTrait for IndexGet {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        if self.start == self.end {
+            return Ok(None);
+        }
+
+        let value = self.get(self.start)?;
+        self.start += 1;
+        Ok(Some(value))
+    }
+}
+
+impl DoubleEndedIteratorTrait for IndexGet {
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        if self.start == self.end {
+            return Ok(None);
+        }
+
+        self.end -= 1;
+        self.get(self.end).map(Some)
+    }
+}
+
 /// The trait for interacting with an iterator.
 ///
 /// This has a blanket implementation, and is primarily used to restrict the
@@ -890,7 +1025,9 @@ where
 {
     #[inline]
     fn is_double_ended(&self) -> bool {
-        self.iter.is_double_ended()
+        // NB: walking from the back requires the exact length of the inner
+        // iterator, to stop before the skipped elements.
+        self.iter.is_double_ended() && self.iter.is_exact_size()
     }
 
     #[inline]
@@ -935,6 +1072,94 @@ where
     }
 }
 
+#[derive(Debug)]
+struct StepBy<I> {
+    iter: I,
+    /// The number of elements skipped between each element produced.
+    step: usize,
+    first_take: bool,
+}
+
+impl<I> StepBy<I>
+where
+    I: RuneIterator,
+{
+    /// The number of elements to skip at the back to find the last element
+    /// which is part of the step.
+    fn next_back_index(&self) -> Result<usize, VmError> {
+        let rem = self.iter.len()? % (self.step + 1);
+
+        Ok(if self.first_take {
+            if rem == 0 {
+                self.step
+            } else {
+                rem - 1
+            }
+        } else {
+            rem
+        })
+    }
+}
+
+impl<I> RuneIterator for StepBy<I>
+where
+    I: RuneIterator,
+{
+    #[inline]
+    fn is_double_ended(&self) -> bool {
+        // NB: walking from the back requires the exact length of the inner
+        // iterator, to find the last element which is part of the step.
+        self.iter.is_double_ended() && self.iter.is_exact_size()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let step = self.step + 1;
+
+        let size = |n: usize| {
+            if self.first_take {
+                if n == 0 {
+                    0
+                } else {
+                    1 + (n - 1) / step
+                }
+            } else {
+                n / step
+            }
+        };
+
+        (size(lower), upper.map(size))
+    }
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<Value>, VmError> {
+        if self.first_take {
+            self.first_take = false;
+            return self.iter.next();
+        }
+
+        for _ in 0..self.step {
+            if self.iter.next()?.is_none() {
+                return Ok(None);
+            }
+        }
+
+        self.iter.next()
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Result<Option<Value>, VmError> {
+        for _ in 0..self.next_back_index()? {
+            if self.iter.next_back()?.is_none() {
+                return Ok(None);
+            }
+        }
+
+        self.iter.next_back()
+    }
+}
+
 #[derive(Debug)]
 struct Take<I> {
     iter: I,
@@ -947,7 +1172,9 @@ where
 {
     #[inline]
     fn is_double_ended(&self) -> bool {
-        self.iter.is_double_ended()
+        // NB: walking from the back requires the exact length of the inner
+        // iterator, to skip past the elements which aren't taken.
+        self.iter.is_double_ended() && self.iter.is_exact_size()
     }
 
     #[inline]
@@ -984,6 +1211,16 @@ where
             return Ok(None);
         }
 
+        // NB: skip over the elements at the back which fall outside of the
+        // taken range.
+        let len = self.iter.len()?;
+
+        for _ in self.n..len {
+            if self.iter.next_back()?.is_none() {
+                return Ok(None);
+            }
+        }
+
         self.n -= 1;
         self.iter.next_back()
     }
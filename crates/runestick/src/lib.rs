@@ -54,6 +54,7 @@ mod vm;
 #[macro_use]
 mod macros;
 mod access;
+pub mod allocation;
 mod any_obj;
 mod args;
 mod awaited;
@@ -85,6 +86,7 @@ pub mod modules;
 mod named;
 mod names;
 mod object;
+mod overflow_policy;
 mod panic;
 mod protocol;
 mod protocol_caller;
@@ -100,6 +102,7 @@ mod stack;
 mod static_string;
 mod static_type;
 mod stream;
+mod timer;
 mod to_value;
 mod tuple;
 mod type_info;
@@ -166,6 +169,7 @@ pub use self::label::{DebugLabel, Label};
 pub use self::location::Location;
 pub use self::module::{InstFnNameHash, InstallWith, Module};
 pub use self::named::Named;
+pub use self::overflow_policy::OverflowPolicy;
 pub use self::raw_str::RawStr;
 pub use self::runtime_context::RuntimeContext;
 pub use self::select::Select;
@@ -192,7 +196,9 @@ pub use crate::any::Any;
 pub use crate::awaited::Awaited;
 pub use crate::bytes::Bytes;
 pub use crate::call::Call;
-pub use crate::context::{Context, ContextError, ContextSignature, ContextTypeInfo};
+pub use crate::context::{
+    CallObserver, CallPhase, Context, ContextError, ContextSignature, ContextTypeInfo,
+};
 pub use crate::debug::{DebugInfo, DebugInst};
 pub use crate::function::{Function, SyncFunction};
 pub use crate::future::Future;
@@ -220,7 +226,7 @@ pub use crate::vm_error::{VmError, VmErrorKind, VmIntegerRepr};
 pub use crate::vm_execution::{VmExecution, VmSendExecution};
 pub use crate::vm_halt::{VmHalt, VmHaltInfo};
 pub(crate) use runestick_macros::__internal_impl_any;
-pub use runestick_macros::{Any, FromValue};
+pub use runestick_macros::{rune_function, Any, FromValue};
 
 mod collections {
     pub use hashbrown::{hash_map, HashMap};
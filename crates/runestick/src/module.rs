@@ -4,14 +4,17 @@
 //! through native code.
 
 use crate::context::{ContextError, Handler, Macro};
+use crate::future::TimeoutFuture;
 use crate::{collections::HashMap, ConstValue};
 use crate::{
     FromValue, Future, GeneratorState, Hash, IntoComponent, Item, Named, Protocol, Stack,
     StaticType, ToValue, TypeCheck, TypeInfo, TypeOf, UnsafeFromValue, Value, VmError, VmErrorKind,
 };
+use once_cell::sync::OnceCell;
 use std::any;
 use std::future;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Trait to handle the installation of auxilliary functions for a type
 /// installed into a module.
@@ -94,6 +97,8 @@ pub(crate) struct ModuleType {
     pub(crate) name: Box<str>,
     /// Type information for the installed type.
     pub(crate) type_info: TypeInfo,
+    /// Whether the type is hidden from the item namespace.
+    pub(crate) hidden: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -112,11 +117,16 @@ impl ModuleAssociatedKind {
     }
 }
 
+#[derive(Clone)]
 pub(crate) struct ModuleAssociatedFn {
     pub(crate) handler: Arc<Handler>,
     pub(crate) args: Option<usize>,
     pub(crate) type_info: TypeInfo,
     pub(crate) name: String,
+    /// The type hashes of the arguments, including the instance.
+    pub(crate) arg_types: Option<Box<[Option<Hash>]>>,
+    /// The type hash of the value returned, if it's statically known.
+    pub(crate) return_type: Option<Hash>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -129,12 +139,60 @@ pub(crate) struct ModuleAssocKey {
 pub(crate) struct ModuleFn {
     pub(crate) handler: Arc<Handler>,
     pub(crate) args: Option<usize>,
+    pub(crate) arg_types: Option<Box<[Option<Hash>]>>,
+    /// The names of the arguments, if they were provided.
+    pub(crate) arg_names: Option<Box<[Box<str>]>>,
+    /// The type the function is a static function of, if any.
+    pub(crate) associated_type: Option<Hash>,
+    /// The type hash of the value returned, if it's statically known.
+    pub(crate) return_type: Option<Hash>,
+    /// If the function can be evaluated at compile time.
+    pub(crate) constant: bool,
 }
 
 pub(crate) struct ModuleMacro {
     pub(crate) handler: Arc<Macro>,
 }
 
+/// A constant registered in a module.
+#[derive(Clone)]
+pub(crate) enum ModuleConst {
+    /// A constant which has already been evaluated.
+    Value(ConstValue),
+    /// A constant which is evaluated the first time it's accessed.
+    Lazy(Arc<LazyConst>),
+}
+
+impl ModuleConst {
+    /// Get the value of the constant, evaluating it if necessary.
+    pub(crate) fn get(&self) -> &ConstValue {
+        match self {
+            Self::Value(value) => value,
+            Self::Lazy(lazy) => lazy.get(),
+        }
+    }
+}
+
+impl From<ConstValue> for ModuleConst {
+    fn from(value: ConstValue) -> Self {
+        Self::Value(value)
+    }
+}
+
+/// A lazily evaluated constant, see [Module::constant_lazy].
+pub(crate) struct LazyConst {
+    value: OnceCell<ConstValue>,
+    init: Box<dyn Fn() -> ConstValue + Send + Sync>,
+}
+
+impl LazyConst {
+    /// Get the value of the constant, evaluating it if this is the first
+    /// access.
+    pub(crate) fn get(&self) -> &ConstValue {
+        self.value.get_or_init(|| (self.init)())
+    }
+}
+
 /// A collection of functions that can be looked up by type.
 #[derive(Default)]
 pub struct Module {
@@ -145,7 +203,7 @@ pub struct Module {
     /// Macro handlers.
     pub(crate) macros: HashMap<Item, ModuleMacro>,
     /// Constant values.
-    pub(crate) constants: HashMap<Item, ConstValue>,
+    pub(crate) constants: HashMap<Item, ModuleConst>,
     /// Instance functions.
     pub(crate) associated_functions: HashMap<ModuleAssocKey, ModuleAssociatedFn>,
     /// Registered types.
@@ -154,6 +212,8 @@ pub struct Module {
     pub(crate) unit_type: Option<ModuleUnitType>,
     /// Registered generator state type.
     pub(crate) internal_enums: Vec<ModuleInternalEnum>,
+    /// The identity declared through [Module::set_identity], if any.
+    identity: Option<Hash>,
 }
 
 impl Module {
@@ -195,9 +255,125 @@ impl Module {
             unit_type: None,
             internal_enums: Vec::new(),
             constants: Default::default(),
+            identity: None,
         }
     }
 
+    /// Declare the identity of the module, which states that any module with
+    /// the same identity and contents has the same implementation.
+    ///
+    /// This allows [Context::install][crate::Context::install] to skip a
+    /// module which is constructed more than once, like a module which is
+    /// installed by several independent parts of an application. Without a
+    /// declared identity only the same module is skipped, since native
+    /// handlers can't be compared.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// fn module() -> runestick::Module {
+    ///     let mut module = runestick::Module::with_item(&["http"]);
+    ///     module.set_identity("http@1.0.0");
+    ///     module.function(&["get"], |url: String| url).unwrap();
+    ///     module
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut context = runestick::Context::new();
+    /// context.install(&module())?;
+    /// context.install(&module())?;
+    /// # Ok(()) }
+    /// ```
+    pub fn set_identity(&mut self, identity: &str) {
+        self.identity = Some(Hash::of(identity));
+    }
+
+    /// Get the identity of the module.
+    ///
+    /// Two modules have the same identity if they are registered under the
+    /// same item and register the same set of functions, macros, constants
+    /// and types. Since native handlers can't be compared, they're identified
+    /// by their address unless an identity has been declared through
+    /// [set_identity][Module::set_identity]. Lazy constants are similarly
+    /// never evaluated here.
+    ///
+    /// This is used by [Context::install][crate::Context::install] to skip
+    /// modules which have already been installed.
+    pub fn identity(&self) -> Hash {
+        // NB: without a declared identity, two modules are only the same if
+        // they share their handlers.
+        let handler = |ptr: *const ()| match self.identity {
+            Some(..) => None,
+            None => Some(ptr as usize),
+        };
+
+        let mut functions = self
+            .functions
+            .iter()
+            .map(|(name, f)| (name, f.args, handler(Arc::as_ptr(&f.handler) as *const ())))
+            .collect::<Vec<_>>();
+        functions.sort();
+
+        let mut macros = self
+            .macros
+            .iter()
+            .map(|(name, m)| (name, handler(Arc::as_ptr(&m.handler) as *const ())))
+            .collect::<Vec<_>>();
+        macros.sort();
+
+        let mut constants = self
+            .constants
+            .iter()
+            .map(|(name, value)| match value {
+                ModuleConst::Value(value) => (name, Some(format!("{:?}", value)), None),
+                ModuleConst::Lazy(lazy) => (name, None, handler(Arc::as_ptr(lazy) as *const ())),
+            })
+            .collect::<Vec<_>>();
+        constants.sort();
+
+        let mut types = self
+            .types
+            .iter()
+            .map(|(hash, ty)| (*hash, &*ty.name, ty.hidden))
+            .collect::<Vec<_>>();
+        types.sort();
+
+        let mut associated_functions = self
+            .associated_functions
+            .iter()
+            .map(|(key, f)| {
+                (
+                    key.type_hash,
+                    key.hash,
+                    Hash::of(key.kind),
+                    f.args,
+                    handler(Arc::as_ptr(&f.handler) as *const ()),
+                )
+            })
+            .collect::<Vec<_>>();
+        associated_functions.sort();
+
+        let unit_type = self.unit_type.as_ref().map(|ty| &*ty.name);
+
+        let internal_enums = self
+            .internal_enums
+            .iter()
+            .map(|e| (e.name, &e.base_type, e.static_type.hash))
+            .collect::<Vec<_>>();
+
+        Hash::of((
+            &self.item,
+            self.identity,
+            functions,
+            macros,
+            constants,
+            types,
+            associated_functions,
+            unit_type,
+            internal_enums,
+        ))
+    }
+
     /// Register a type. Registering a type is mandatory in order to register
     /// instance functions using that type.
     ///
@@ -227,7 +403,8 @@ impl Module {
     /// module.inst_fn("len", MyBytes::len)?;
     ///
     /// let mut context = runestick::Context::new();
-    /// assert!(context.install(&module).is_err());
+    /// context.install(&module)?;
+    /// assert!(context.validate().is_err());
     ///
     /// // Register `len` properly.
     /// let mut module = runestick::Module::default();
@@ -236,25 +413,104 @@ impl Module {
     /// module.inst_fn("len", MyBytes::len)?;
     ///
     /// let mut context = runestick::Context::new();
-    /// assert!(context.install(&module).is_ok());
+    /// context.install(&module)?;
+    /// assert!(context.validate().is_ok());
     /// # Ok(()) }
     /// ```
     pub fn ty<T>(&mut self) -> Result<(), ContextError>
+    where
+        T: Named + TypeOf + InstallWith,
+    {
+        self.install_type::<T>(false, T::type_info())
+    }
+
+    /// Register a type with custom type information, which is used when
+    /// describing the type in errors.
+    ///
+    /// This is useful for types which wrap something else, like handles, where
+    /// the name of the Rust type isn't meaningful to users of a script. The
+    /// type is still identified by the type hash of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Any, RawStr, TypeInfo};
+    ///
+    /// #[derive(Any)]
+    /// struct RawFd(i32);
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    /// module.ty_with_info::<RawFd>(TypeInfo::Any(RawStr::from_str("FileHandle")))?;
+    /// # Ok(()) }
+    /// ```
+    pub fn ty_with_info<T>(&mut self, type_info: TypeInfo) -> Result<(), ContextError>
+    where
+        T: Named + TypeOf + InstallWith,
+    {
+        self.install_type::<T>(false, type_info)
+    }
+
+    /// Register a type which is hidden from scripts.
+    ///
+    /// The type can be used for protocol and instance function dispatch just
+    /// like one registered with [ty][Module::ty], but it's not added to the
+    /// item namespace. So scripts can't name the type or its associated
+    /// functions directly.
+    ///
+    /// This is useful for internal helper types which only exist to carry
+    /// protocol implementations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Any, Protocol};
+    ///
+    /// #[derive(Any)]
+    /// struct Marker;
+    ///
+    /// impl Marker {
+    ///     fn string_display(&self, s: &mut String) -> std::fmt::Result {
+    ///         use std::fmt::Write as _;
+    ///         write!(s, "marker")
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    /// module.ty_hidden::<Marker>()?;
+    /// module.inst_fn(Protocol::STRING_DISPLAY, Marker::string_display)?;
+    ///
+    /// let mut context = runestick::Context::new();
+    /// context.install(&module)?;
+    /// assert!(!context.contains_name(&runestick::Item::with_item(&["Marker"])));
+    /// # Ok(()) }
+    /// ```
+    pub fn ty_hidden<T>(&mut self) -> Result<(), ContextError>
+    where
+        T: Named + TypeOf + InstallWith,
+    {
+        self.install_type::<T>(true, T::type_info())
+    }
+
+    fn install_type<T>(&mut self, hidden: bool, type_info: TypeInfo) -> Result<(), ContextError>
     where
         T: Named + TypeOf + InstallWith,
     {
         let type_hash = T::type_hash();
-        let type_info = T::type_info();
 
         let ty = ModuleType {
             name: String::from(&*T::NAME).into_boxed_str(),
             type_info,
+            hidden,
         };
 
         if let Some(old) = self.types.insert(type_hash, ty) {
             return Err(ContextError::ConflictingType {
                 item: Item::with_item(&[T::NAME]),
                 existing: old.type_info,
+                existing_module: self.item.clone(),
+                module: self.item.clone(),
             });
         }
 
@@ -262,6 +518,38 @@ impl Module {
         Ok(())
     }
 
+    /// Check that the given name isn't used by a function or a constant, for
+    /// when a function is being registered under it.
+    fn check_free_name(&self, name: &Item) -> Result<(), ContextError> {
+        if self.functions.contains_key(name) {
+            return Err(ContextError::ConflictingFunctionName { name: name.clone() });
+        }
+
+        if self.constants.contains_key(name) {
+            return Err(ContextError::ConflictingConstantFunction { name: name.clone() });
+        }
+
+        Ok(())
+    }
+
+    /// Check that the given name isn't used by a constant or a function, for
+    /// when a constant is being registered under it.
+    fn check_free_constant_name(&self, name: &Item) -> Result<(), ContextError> {
+        if self.constants.contains_key(name) {
+            return Err(ContextError::ConflictingConstantName {
+                name: name.clone(),
+                existing_module: self.item.clone(),
+                module: self.item.clone(),
+            });
+        }
+
+        if self.functions.contains_key(name) {
+            return Err(ContextError::ConflictingConstantFunction { name: name.clone() });
+        }
+
+        Ok(())
+    }
+
     /// Construct type information for the `unit` type.
     ///
     /// Registering this allows the given type to be used in Rune scripts when
@@ -402,23 +690,65 @@ impl Module {
 
     /// Register a function that cannot error internally.
     ///
+    /// Returns the hash of the registered function, which is the hash it can
+    /// be looked up through once the module has been installed into a
+    /// [Context][crate::Context].
+    ///
+    /// # Errors
+    ///
+    /// How an error returned from the function is handled depends on its
+    /// type:
+    /// * A `Result<T, E>` where `E` can be converted into a value is returned
+    ///   to the script as a regular `Result`, where it can be matched on or
+    ///   propagated with the `?` operator.
+    /// * A `Result<T, VmError>` or `Result<T, Panic>` is not visible to the
+    ///   script. Instead it aborts the virtual machine, and the error is
+    ///   returned to the caller of the virtual machine.
+    ///
+    /// ```rust
+    /// use runestick::{Panic, VmError};
+    ///
+    /// fn parse(s: &str) -> Result<i64, String> {
+    ///     s.parse().map_err(|_| format!("not a number: {}", s))
+    /// }
+    ///
+    /// fn checked_parse(s: &str) -> Result<i64, VmError> {
+    ///     s.parse().map_err(|_| VmError::panic(format!("not a number: {}", s)))
+    /// }
+    ///
+    /// fn unreachable() -> Result<(), Panic> {
+    ///     Err(Panic::custom("unreachable"))
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::new();
+    /// module.function(&["parse"], parse)?;
+    /// module.function(&["checked_parse"], checked_parse)?;
+    /// module.function(&["unreachable"], unreachable)?;
+    /// # Ok(()) }
+    /// ```
+    ///
     /// # Examples
     ///
     /// ```rust
+    /// use runestick::{Hash, Item};
+    ///
     /// fn add_ten(value: i64) -> i64 {
     ///     value + 10
     /// }
     ///
     /// # fn main() -> runestick::Result<()> {
-    /// let mut module = runestick::Module::default();
+    /// let mut module = runestick::Module::with_item(&["math"]);
+    ///
+    /// let hash = module.function(&["add_ten"], add_ten)?;
+    /// assert_eq!(hash, Hash::type_hash(&Item::with_item(&["math", "add_ten"])));
     ///
-    /// module.function(&["add_ten"], add_ten)?;
     /// module.function(&["empty"], || Ok::<_, runestick::Error>(()))?;
     /// module.function(&["string"], |a: String| Ok::<_, runestick::Error>(()))?;
     /// module.function(&["optional"], |a: Option<String>| Ok::<_, runestick::Error>(()))?;
     /// # Ok(()) }
     /// ```
-    pub fn function<Func, Args, N>(&mut self, name: N, f: Func) -> Result<(), ContextError>
+    pub fn function<Func, Args, N>(&mut self, name: N, f: Func) -> Result<Hash, ContextError>
     where
         Func: Function<Args>,
         N: IntoIterator,
@@ -426,19 +756,259 @@ impl Module {
     {
         let name = Item::with_item(name);
 
-        if self.functions.contains_key(&name) {
-            return Err(ContextError::ConflictingFunctionName { name });
-        }
+        self.check_free_name(&name)?;
+
+        let hash = Hash::type_hash(&self.item.join(&name));
 
         self.functions.insert(
             name,
             ModuleFn {
                 handler: Arc::new(move |stack, args| f.fn_call(stack, args)),
                 args: Some(Func::args()),
+                arg_types: Some(Func::arg_type_hashes().into()),
+                arg_names: None,
+                associated_type: None,
+                return_type: Func::return_type_hash(),
+                constant: false,
             },
         );
 
-        Ok(())
+        Ok(hash)
+    }
+
+    /// Register a function like [function][Module::function], along with the
+    /// names of its arguments.
+    ///
+    /// The names are purely informational and don't affect how the function
+    /// is called. They can be looked up through
+    /// [Context::lookup_arg_names][crate::Context::lookup_arg_names].
+    ///
+    /// Errors if the number of names doesn't match the number of arguments
+    /// the function takes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// fn connect(host: String, port: i64) -> String {
+    ///     format!("{}:{}", host, port)
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::with_item(&["net"]);
+    /// module.function_named_args(&["connect"], &["host", "port"], connect)?;
+    /// assert!(module.function_named_args(&["other"], &["host"], connect).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn function_named_args<Func, Args, N>(
+        &mut self,
+        name: N,
+        arg_names: &[&str],
+        f: Func,
+    ) -> Result<Hash, ContextError>
+    where
+        Func: Function<Args>,
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        let name = Item::with_item(name);
+
+        if arg_names.len() != Func::args() {
+            return Err(ContextError::ArgumentNamesMismatch {
+                name,
+                actual: arg_names.len(),
+                expected: Func::args(),
+            });
+        }
+
+        let hash = self.function(&name, f)?;
+
+        if let Some(f) = self.functions.get_mut(&name) {
+            f.arg_names = Some(arg_names.iter().map(|&name| name.into()).collect());
+        }
+
+        Ok(hash)
+    }
+
+    /// Register a function which can be evaluated at compile time.
+    ///
+    /// The function is registered like with [function][Module::function], but
+    /// calls to it where every argument is a constant expression are evaluated
+    /// by the compiler, with the result embedded as a constant. The function
+    /// must therefore be pure, its result can only depend on its arguments.
+    ///
+    /// Only functions taking and returning values which can be represented as
+    /// constants, like numbers and strings, can be evaluated this way. If the
+    /// function errors when evaluated by the compiler, the call is left to be
+    /// performed at runtime instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// fn square(n: i64) -> i64 {
+    ///     n * n
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::with_item(&["math"]);
+    /// module.const_function(&["square"], square)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn const_function<Func, Args, N>(&mut self, name: N, f: Func) -> Result<Hash, ContextError>
+    where
+        Func: Function<Args>,
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        let name = Item::with_item(name);
+        let hash = self.function(&name, f)?;
+
+        if let Some(f) = self.functions.get_mut(&name) {
+            f.constant = true;
+        }
+
+        Ok(hash)
+    }
+
+    /// Register a function whose trailing parameters are optional, defaulting
+    /// to the given `defaults` when they're omitted by the caller.
+    ///
+    /// The defaults apply to the last `defaults.len()` parameters of the
+    /// function, so a function taking three arguments with two defaults can
+    /// be called with one, two or three arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::ConstValue;
+    ///
+    /// fn connect(host: &str, port: i64) -> String {
+    ///     format!("{}:{}", host, port)
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// // Can be called as either `connect(host)` or `connect(host, port)`.
+    /// module.function_defaults(&["connect"], &[ConstValue::Integer(8080)], connect)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn function_defaults<Func, Args, N>(
+        &mut self,
+        name: N,
+        defaults: &[ConstValue],
+        f: Func,
+    ) -> Result<Hash, ContextError>
+    where
+        Func: Function<Args>,
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        let name = Item::with_item(name);
+
+        self.check_free_name(&name)?;
+
+        let full = Func::args();
+
+        let required = match full.checked_sub(defaults.len()) {
+            Some(required) => required,
+            None => {
+                return Err(ContextError::TooManyDefaults {
+                    name,
+                    defaults: defaults.len(),
+                    args: full,
+                })
+            }
+        };
+
+        let hash = Hash::type_hash(&self.item.join(&name));
+        let defaults: Arc<[ConstValue]> = defaults.into();
+
+        self.functions.insert(
+            name,
+            ModuleFn {
+                handler: Arc::new(move |stack, args| {
+                    if args < required || args > full {
+                        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+                            actual: args,
+                            expected: full,
+                            function: None,
+                        }));
+                    }
+
+                    for value in &defaults[args - required..] {
+                        stack.push(value.clone().into_value());
+                    }
+
+                    f.fn_call(stack, full)
+                }),
+                args: None,
+                arg_types: Some(Func::arg_type_hashes().into()),
+                arg_names: None,
+                associated_type: None,
+                return_type: Func::return_type_hash(),
+                constant: false,
+            },
+        );
+
+        Ok(hash)
+    }
+
+    /// Register a static function associated with the type `T`.
+    ///
+    /// The function is registered under the item of the type, so it's called
+    /// as `T::name` just like if it had been registered through
+    /// [function][Module::function] with the name of the type as a prefix. But
+    /// it's also recorded as belonging to the type, which is visible through
+    /// [Context::iter_static_functions][crate::Context::iter_static_functions].
+    ///
+    /// Returns the hash of the registered function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Any, Context, Hash, Item, TypeOf};
+    ///
+    /// #[derive(Any)]
+    /// struct MyBytes {
+    ///     queue: Vec<String>,
+    /// }
+    ///
+    /// impl MyBytes {
+    ///     fn new() -> Self {
+    ///         Self {
+    ///             queue: Vec::new(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.ty::<MyBytes>()?;
+    /// let hash = module.static_fn::<MyBytes, _, _>("new", MyBytes::new)?;
+    /// assert_eq!(hash, Hash::type_hash(&Item::with_item(&["MyBytes", "new"])));
+    ///
+    /// let mut context = Context::new();
+    /// context.install(&module)?;
+    ///
+    /// let mut functions = context.iter_static_functions(<MyBytes as TypeOf>::type_hash());
+    /// assert!(matches!(functions.next(), Some((h, _)) if h == hash));
+    /// assert!(functions.next().is_none());
+    /// # Ok(()) }
+    /// ```
+    pub fn static_fn<T, Func, Args>(&mut self, name: &str, f: Func) -> Result<Hash, ContextError>
+    where
+        T: Named + TypeOf,
+        Func: Function<Args>,
+    {
+        let item = Item::with_item(&[&*T::NAME, name]);
+        let hash = self.function(&item, f)?;
+
+        if let Some(f) = self.functions.get_mut(&item) {
+            f.associated_type = Some(T::type_hash());
+        }
+
+        Ok(hash)
     }
 
     /// Register a constant value, at a crate, module or associated level.
@@ -463,9 +1033,7 @@ impl Module {
     {
         let name = Item::with_item(name);
 
-        if self.constants.contains_key(&name) {
-            return Err(ContextError::ConflictingConstantName { name });
-        }
+        self.check_free_constant_name(&name)?;
 
         let value = match value.to_value() {
             Ok(v) => v,
@@ -477,113 +1045,419 @@ impl Module {
             Err(e) => return Err(ContextError::ValueError { error: e }),
         };
 
-        self.constants.insert(name, constant_value);
+        self.constants
+            .insert(name, ModuleConst::Value(constant_value));
+
+        Ok(())
+    }
+
+    /// Register a constant value which is computed the first time it's used,
+    /// either by a script being compiled against it or through the runtime
+    /// context.
+    ///
+    /// The function is called at most once, after which the value is cached.
+    /// This is useful for constants which are expensive to construct, like
+    /// large embedded data tables.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::ConstValue;
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.constant_lazy(&["SQUARES"], || {
+    ///     ConstValue::Vec((0..1024).map(|n| ConstValue::Integer(n * n)).collect())
+    /// })?;
+    /// # Ok(()) }
+    /// ```
+    pub fn constant_lazy<N, F>(&mut self, name: N, f: F) -> Result<(), ContextError>
+    where
+        N: IntoIterator,
+        N::Item: IntoComponent,
+        F: 'static + Fn() -> ConstValue + Send + Sync,
+    {
+        let name = Item::with_item(name);
+
+        self.check_free_constant_name(&name)?;
+
+        let lazy = LazyConst {
+            value: OnceCell::new(),
+            init: Box::new(f),
+        };
+
+        self.constants
+            .insert(name, ModuleConst::Lazy(Arc::new(lazy)));
+        Ok(())
+    }
+
+    /// Register a native macro handler.
+    pub fn macro_<N, M, A, O>(&mut self, name: N, f: M) -> Result<(), ContextError>
+    where
+        M: 'static + Send + Sync + Copy + Fn(&A) -> Result<O, crate::Error>,
+        A: any::Any,
+        O: any::Any,
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        let name = Item::with_item(name);
+
+        if self.macros.contains_key(&name) {
+            return Err(ContextError::ConflictingFunctionName { name });
+        }
+
+        let handler: Arc<Macro> = Arc::new(move |a| {
+            let a = match a.downcast_ref::<A>() {
+                Some(a) => a,
+                None => {
+                    return Err(crate::Error::msg(format!(
+                        "expected argument #1 `{}`",
+                        std::any::type_name::<A>()
+                    )));
+                }
+            };
+
+            let output = f(a)?;
+            Ok(Box::new(output))
+        });
+
+        self.macros.insert(name, ModuleMacro { handler });
+        Ok(())
+    }
+
+    /// Register a function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.async_function(&["empty"], || async { () })?;
+    /// module.async_function(&["empty_fallible"], || async { Ok::<_, runestick::Error>(()) })?;
+    /// module.async_function(&["string"], |a: String| async { Ok::<_, runestick::Error>(()) })?;
+    /// module.async_function(&["optional"], |a: Option<String>| async { Ok::<_, runestick::Error>(()) })?;
+    /// # Ok(()) }
+    /// ```
+    pub fn async_function<Func, Args, N>(&mut self, name: N, f: Func) -> Result<Hash, ContextError>
+    where
+        Func: AsyncFunction<Args>,
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        let name = Item::with_item(name);
+
+        self.check_free_name(&name)?;
+
+        let hash = Hash::type_hash(&self.item.join(&name));
+
+        self.functions.insert(
+            name,
+            ModuleFn {
+                handler: Arc::new(move |stack, args| f.fn_call(stack, args)),
+                args: Some(Func::args()),
+                arg_types: Some(Func::arg_type_hashes().into()),
+                arg_names: None,
+                associated_type: None,
+                return_type: Func::return_type_hash(),
+                constant: false,
+            },
+        );
+
+        Ok(hash)
+    }
+
+    /// Register an async function which is cancelled with a
+    /// [HostTimeout][VmErrorKind::HostTimeout] error if the future it returns
+    /// doesn't complete within the given `timeout`.
+    ///
+    /// The timeout applies to each individual call, counting from when the
+    /// returned future is first polled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.async_function_timeout(&["empty"], Duration::from_secs(1), || async { () })?;
+    /// # Ok(()) }
+    /// ```
+    pub fn async_function_timeout<Func, Args, N>(
+        &mut self,
+        name: N,
+        timeout: Duration,
+        f: Func,
+    ) -> Result<Hash, ContextError>
+    where
+        Func: AsyncFunction<Args>,
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        let name = Item::with_item(name);
+
+        self.check_free_name(&name)?;
 
-        Ok(())
+        let hash = Hash::type_hash(&self.item.join(&name));
+
+        self.functions.insert(
+            name,
+            ModuleFn {
+                handler: Arc::new(move |stack, args| {
+                    f.fn_call(stack, args)?;
+                    let future = stack.pop()?.into_future()?;
+                    stack.push(Future::new(TimeoutFuture::new(future, timeout)));
+                    Ok(())
+                }),
+                args: Some(Func::args()),
+                arg_types: Some(Func::arg_type_hashes().into()),
+                arg_names: None,
+                associated_type: None,
+                return_type: Func::return_type_hash(),
+                constant: false,
+            },
+        );
+
+        Ok(hash)
     }
 
-    /// Register a native macro handler.
-    pub fn macro_<N, M, A, O>(&mut self, name: N, f: M) -> Result<(), ContextError>
+    /// Register a raw function which interacts directly with the virtual
+    /// machine.
+    ///
+    /// Arguments should be accessed through [Stack::pop_arg], which reports
+    /// conversion errors the same way as functions registered through
+    /// [function][Module::function] do.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Stack, VmError};
+    ///
+    /// fn sum(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    ///     let mut sum = 0;
+    ///
+    ///     for n in (0..args).rev() {
+    ///         sum += stack.pop_arg::<i64>(n, args)?;
+    ///     }
+    ///
+    ///     stack.push(sum);
+    ///     Ok(())
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    /// module.raw_fn(&["sum"], sum)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn raw_fn<F, N>(&mut self, name: N, f: F) -> Result<Hash, ContextError>
     where
-        M: 'static + Send + Sync + Copy + Fn(&A) -> Result<O, crate::Error>,
-        A: any::Any,
-        O: any::Any,
+        F: 'static + Copy + Fn(&mut Stack, usize) -> Result<(), VmError> + Send + Sync,
         N: IntoIterator,
         N::Item: IntoComponent,
     {
         let name = Item::with_item(name);
 
-        if self.macros.contains_key(&name) {
-            return Err(ContextError::ConflictingFunctionName { name });
-        }
+        self.check_free_name(&name)?;
 
-        let handler: Arc<Macro> = Arc::new(move |a| {
-            let a = match a.downcast_ref::<A>() {
-                Some(a) => a,
-                None => {
-                    return Err(crate::Error::msg(format!(
-                        "expected argument #1 `{}`",
-                        std::any::type_name::<A>()
-                    )));
-                }
-            };
+        let hash = Hash::type_hash(&self.item.join(&name));
 
-            let output = f(a)?;
-            Ok(Box::new(output))
-        });
+        self.functions.insert(
+            name,
+            ModuleFn {
+                handler: Arc::new(move |stack, args| f(stack, args)),
+                args: None,
+                arg_types: None,
+                arg_names: None,
+                associated_type: None,
+                return_type: None,
+                constant: false,
+            },
+        );
 
-        self.macros.insert(name, ModuleMacro { handler });
-        Ok(())
+        Ok(hash)
     }
 
-    /// Register a function.
+    /// Register a raw function which takes a fixed number of arguments.
+    ///
+    /// This is like [raw_fn][Module::raw_fn], except that the number of
+    /// arguments is checked before the function is called, so the function
+    /// can rely on always being passed exactly `arity` arguments.
     ///
     /// # Examples
     ///
     /// ```rust
+    /// use runestick::{Stack, VmError};
+    ///
+    /// fn add(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    ///     let b = stack.pop_arg::<i64>(1, args)?;
+    ///     let a = stack.pop_arg::<i64>(0, args)?;
+    ///     stack.push(a + b);
+    ///     Ok(())
+    /// }
+    ///
     /// # fn main() -> runestick::Result<()> {
     /// let mut module = runestick::Module::default();
-    ///
-    /// module.async_function(&["empty"], || async { () })?;
-    /// module.async_function(&["empty_fallible"], || async { Ok::<_, runestick::Error>(()) })?;
-    /// module.async_function(&["string"], |a: String| async { Ok::<_, runestick::Error>(()) })?;
-    /// module.async_function(&["optional"], |a: Option<String>| async { Ok::<_, runestick::Error>(()) })?;
+    /// module.raw_fn_arity(&["add"], 2, add)?;
     /// # Ok(()) }
     /// ```
-    pub fn async_function<Func, Args, N>(&mut self, name: N, f: Func) -> Result<(), ContextError>
+    pub fn raw_fn_arity<F, N>(&mut self, name: N, arity: usize, f: F) -> Result<Hash, ContextError>
     where
-        Func: AsyncFunction<Args>,
+        F: 'static + Copy + Fn(&mut Stack, usize) -> Result<(), VmError> + Send + Sync,
         N: IntoIterator,
         N::Item: IntoComponent,
     {
         let name = Item::with_item(name);
 
-        if self.functions.contains_key(&name) {
-            return Err(ContextError::ConflictingFunctionName { name });
-        }
+        self.check_free_name(&name)?;
+
+        let hash = Hash::type_hash(&self.item.join(&name));
 
         self.functions.insert(
             name,
             ModuleFn {
-                handler: Arc::new(move |stack, args| f.fn_call(stack, args)),
-                args: Some(Func::args()),
+                handler: Arc::new(move |stack, args| {
+                    if args != arity {
+                        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+                            actual: args,
+                            expected: arity,
+                            function: None,
+                        }));
+                    }
+
+                    f(stack, args)
+                }),
+                args: Some(arity),
+                arg_types: None,
+                arg_names: None,
+                associated_type: None,
+                return_type: None,
+                constant: false,
             },
         );
 
-        Ok(())
+        Ok(hash)
     }
 
-    /// Register a raw function which interacts directly with the virtual
-    /// machine.
-    pub fn raw_fn<F, N>(&mut self, name: N, f: F) -> Result<(), ContextError>
+    /// Register a raw function from a boxed function object.
+    ///
+    /// This is like [raw_fn][Module::raw_fn], except that the function doesn't
+    /// have to be `Copy`. It's intended for functions which are constructed
+    /// dynamically, like ones provided by a plugin registry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Stack, VmError};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let greeting = String::from("hello");
+    ///
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.raw_fn_boxed(
+    ///     &["greeting"],
+    ///     Box::new(move |stack: &mut Stack, args: usize| {
+    ///         let name = stack.pop_arg::<String>(0, args)?;
+    ///         stack.push(format!("{}, {}", greeting, name));
+    ///         Ok(())
+    ///     }),
+    /// )?;
+    /// # Ok(()) }
+    /// ```
+    pub fn raw_fn_boxed<N>(&mut self, name: N, f: Box<Handler>) -> Result<Hash, ContextError>
     where
-        F: 'static + Copy + Fn(&mut Stack, usize) -> Result<(), VmError> + Send + Sync,
         N: IntoIterator,
         N::Item: IntoComponent,
     {
         let name = Item::with_item(name);
 
-        if self.functions.contains_key(&name) {
-            return Err(ContextError::ConflictingFunctionName { name });
-        }
+        self.check_free_name(&name)?;
+
+        let hash = Hash::type_hash(&self.item.join(&name));
 
         self.functions.insert(
             name,
             ModuleFn {
-                handler: Arc::new(move |stack, args| f(stack, args)),
+                handler: Arc::from(f),
                 args: None,
+                arg_types: None,
+                arg_names: None,
+                associated_type: None,
+                return_type: None,
+                constant: false,
             },
         );
 
-        Ok(())
+        Ok(hash)
+    }
+
+    /// Register `alias` as another name for the function `existing`, with
+    /// both names relative to the item of the module.
+    ///
+    /// Both names refer to the same implementation. Returns the hash of the
+    /// alias.
+    ///
+    /// Errors if `existing` hasn't been registered in this module, or if a
+    /// function or constant named `alias` already exists. Instance functions
+    /// are aliased through [alias_inst_fn][Module::alias_inst_fn].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Item, Module};
+    ///
+    /// fn add(a: i64, b: i64) -> i64 {
+    ///     a + b
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = Module::with_item(&["math"]);
+    /// module.function(&["add"], add)?;
+    /// module.alias_fn(&Item::with_item(&["add"]), &Item::with_item(&["plus"]))?;
+    /// # Ok(()) }
+    /// ```
+    pub fn alias_fn(&mut self, existing: &Item, alias: &Item) -> Result<Hash, ContextError> {
+        let f = match self.functions.get(existing) {
+            Some(f) => ModuleFn {
+                handler: f.handler.clone(),
+                args: f.args,
+                arg_types: f.arg_types.clone(),
+                arg_names: f.arg_names.clone(),
+                associated_type: None,
+                return_type: f.return_type,
+                constant: f.constant,
+            },
+            None => {
+                return Err(ContextError::MissingFunction {
+                    name: existing.clone(),
+                })
+            }
+        };
+
+        self.check_free_name(alias)?;
+
+        let hash = Hash::type_hash(&self.item.join(alias));
+        self.functions.insert(alias.clone(), f);
+        Ok(hash)
     }
 
     /// Register an instance function.
     ///
+    /// The type of the instance doesn't have to be registered in this module.
+    /// It can also be registered by any other module installed into the same
+    /// [Context][crate::Context], which makes it possible to write modules
+    /// that extend existing types. See [Context::validate][crate::Context::validate]
+    /// for how to check that the type was eventually installed.
+    ///
+    /// Returns the hash of the registered instance function.
+    ///
     /// # Examples
     ///
     /// ```rust
-    /// use runestick::Any;
+    /// use runestick::{Any, Hash, TypeOf};
     ///
     /// #[derive(Any)]
     /// struct MyBytes {
@@ -607,13 +1481,15 @@ impl Module {
     ///
     /// module.ty::<MyBytes>()?;
     /// module.function(&["MyBytes", "new"], MyBytes::new)?;
-    /// module.inst_fn("len", MyBytes::len)?;
+    ///
+    /// let hash = module.inst_fn("len", MyBytes::len)?;
+    /// assert_eq!(hash, Hash::instance_function(<MyBytes as TypeOf>::type_hash(), "len"));
     ///
     /// let mut context = runestick::Context::new();
     /// context.install(&module)?;
     /// # Ok(()) }
     /// ```
-    pub fn inst_fn<N, Func, Args>(&mut self, name: N, f: Func) -> Result<(), ContextError>
+    pub fn inst_fn<N, Func, Args>(&mut self, name: N, f: Func) -> Result<Hash, ContextError>
     where
         N: InstFnNameHash,
         Func: InstFn<Args>,
@@ -627,7 +1503,7 @@ impl Module {
         protocol: Protocol,
         name: N,
         f: Func,
-    ) -> Result<(), ContextError>
+    ) -> Result<Hash, ContextError>
     where
         N: InstFnNameHash,
         Func: InstFn<Args>,
@@ -635,13 +1511,114 @@ impl Module {
         self.assoc_fn(name, f, ModuleAssociatedKind::FieldFn(protocol))
     }
 
+    /// Install a getter for the field with the given name, which allows it to
+    /// be read with field syntax like `value.name`.
+    ///
+    /// This registers the [GET][Protocol::GET] field function for the field,
+    /// and is the runtime equivalent of `#[rune(get, get_with = "...")]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Any;
+    ///
+    /// #[derive(Any)]
+    /// struct User {
+    ///     first: String,
+    ///     last: String,
+    /// }
+    ///
+    /// impl User {
+    ///     fn name(&self) -> String {
+    ///         format!("{} {}", self.first, self.last)
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.ty::<User>()?;
+    /// module.field_getter("name", User::name)?;
+    ///
+    /// let mut context = runestick::Context::new();
+    /// context.install(&module)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn field_getter<N, Func, Args>(&mut self, name: N, f: Func) -> Result<Hash, ContextError>
+    where
+        N: InstFnNameHash,
+        Func: InstFn<Args>,
+    {
+        self.field_fn(Protocol::GET, name, f)
+    }
+
+    /// Install a setter for the field with the given name, which allows it to
+    /// be assigned with field syntax like `value.name = 42`.
+    ///
+    /// This registers the [SET][Protocol::SET] field function for the field.
+    /// See [field_getter][Module::field_getter] for the corresponding getter.
+    pub fn field_setter<N, Func, Args>(&mut self, name: N, f: Func) -> Result<Hash, ContextError>
+    where
+        N: InstFnNameHash,
+        Func: InstFn<Args>,
+    {
+        self.field_fn(Protocol::SET, name, f)
+    }
+
+    /// Install a property with the given name, which is read through `getter`
+    /// and written through `setter`.
+    ///
+    /// This registers the [GET][Protocol::GET] and [SET][Protocol::SET] field
+    /// functions for the property, and is useful for computed properties which
+    /// don't correspond to a field in the struct.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Any;
+    ///
+    /// #[derive(Any)]
+    /// struct Temperature {
+    ///     celsius: f64,
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.ty::<Temperature>()?;
+    /// module.property(
+    ///     "fahrenheit",
+    ///     |t: &Temperature| t.celsius * 1.8 + 32.0,
+    ///     |t: &mut Temperature, value: f64| t.celsius = (value - 32.0) / 1.8,
+    /// )?;
+    ///
+    /// let mut context = runestick::Context::new();
+    /// context.install(&module)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn property<N, Get, GetArgs, Set, SetArgs>(
+        &mut self,
+        name: N,
+        getter: Get,
+        setter: Set,
+    ) -> Result<(), ContextError>
+    where
+        N: InstFnNameHash,
+        Get: InstFn<GetArgs>,
+        Set: InstFn<SetArgs>,
+    {
+        self.field_getter(name, getter)?;
+        self.field_setter(name, setter)?;
+        Ok(())
+    }
+
     /// Install an associated function.
     fn assoc_fn<N, Func, Args>(
         &mut self,
         name: N,
         f: Func,
         kind: ModuleAssociatedKind,
-    ) -> Result<(), ContextError>
+    ) -> Result<Hash, ContextError>
     where
         N: InstFnNameHash,
         Func: InstFn<Args>,
@@ -658,7 +1635,12 @@ impl Module {
         let name = name.into_name();
 
         if self.associated_functions.contains_key(&key) {
-            return Err(ContextError::ConflictingInstanceFunction { type_info, name });
+            return Err(ContextError::ConflictingInstanceFunction {
+                type_info,
+                name,
+                existing_module: self.item.clone(),
+                module: self.item.clone(),
+            });
         }
 
         let handler: Arc<Handler> = Arc::new(move |stack, args| f.fn_call(stack, args));
@@ -668,13 +1650,21 @@ impl Module {
             args: Some(Func::args()),
             type_info,
             name,
+            arg_types: Some(Func::arg_type_hashes().into()),
+            return_type: Func::return_type_hash(),
         };
 
+        let hash = key.kind.hash(key.type_hash, key.hash);
         self.associated_functions.insert(key, instance_function);
-        Ok(())
+        Ok(hash)
     }
 
-    /// Register an instance function.
+    /// Register an async instance function.
+    ///
+    /// The instance and any borrowed arguments stay borrowed until the
+    /// returned future completes, so a function taking `&mut self` can modify
+    /// the instance across await points. Accessing the instance from a script
+    /// while the future is pending results in an access error.
     ///
     /// # Examples
     ///
@@ -701,7 +1691,7 @@ impl Module {
     /// module.async_inst_fn("test", MyType::test)?;
     /// # Ok(()) }
     /// ```
-    pub fn async_inst_fn<N, Func, Args>(&mut self, name: N, f: Func) -> Result<(), ContextError>
+    pub fn async_inst_fn<N, Func, Args>(&mut self, name: N, f: Func) -> Result<Hash, ContextError>
     where
         N: InstFnNameHash,
         Func: AsyncInstFn<Args>,
@@ -718,7 +1708,12 @@ impl Module {
         let name = name.into_name();
 
         if self.associated_functions.contains_key(&key) {
-            return Err(ContextError::ConflictingInstanceFunction { type_info, name });
+            return Err(ContextError::ConflictingInstanceFunction {
+                type_info,
+                name,
+                existing_module: self.item.clone(),
+                module: self.item.clone(),
+            });
         }
 
         let handler: Arc<Handler> = Arc::new(move |stack, args| f.fn_call(stack, args));
@@ -728,10 +1723,89 @@ impl Module {
             args: Some(Func::args()),
             type_info,
             name,
+            arg_types: Some(Func::arg_type_hashes().into()),
+            return_type: Func::return_type_hash(),
         };
 
+        let hash = key.kind.hash(key.type_hash, key.hash);
         self.associated_functions.insert(key, instance_function);
-        Ok(())
+        Ok(hash)
+    }
+
+    /// Register `alias` as another name for the instance function `existing`
+    /// of the type `T`.
+    ///
+    /// Both names refer to the same implementation. Returns the hash of the
+    /// alias.
+    ///
+    /// Errors if `existing` hasn't been registered in this module, or if an
+    /// instance function named `alias` already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Any, Module};
+    ///
+    /// #[derive(Any)]
+    /// struct Queue {
+    ///     items: Vec<i64>,
+    /// }
+    ///
+    /// impl Queue {
+    ///     fn push(&mut self, item: i64) {
+    ///         self.items.push(item);
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = Module::new();
+    /// module.ty::<Queue>()?;
+    /// module.inst_fn("push", Queue::push)?;
+    /// module.alias_inst_fn::<Queue>("push", "enqueue")?;
+    /// # Ok(()) }
+    /// ```
+    pub fn alias_inst_fn<T>(&mut self, existing: &str, alias: &str) -> Result<Hash, ContextError>
+    where
+        T: TypeOf,
+    {
+        let type_hash = T::type_hash();
+
+        let existing_key = ModuleAssocKey {
+            type_hash,
+            hash: existing.inst_fn_name_hash(),
+            kind: ModuleAssociatedKind::Instance,
+        };
+
+        let key = ModuleAssocKey {
+            type_hash,
+            hash: alias.inst_fn_name_hash(),
+            kind: ModuleAssociatedKind::Instance,
+        };
+
+        let mut f = match self.associated_functions.get(&existing_key) {
+            Some(f) => f.clone(),
+            None => {
+                return Err(ContextError::MissingInstanceFunction {
+                    type_info: T::type_info(),
+                    name: existing.to_owned(),
+                })
+            }
+        };
+
+        if self.associated_functions.contains_key(&key) {
+            return Err(ContextError::ConflictingInstanceFunction {
+                type_info: T::type_info(),
+                name: alias.to_owned(),
+                existing_module: self.item.clone(),
+                module: self.item.clone(),
+            });
+        }
+
+        f.name = alias.to_owned();
+
+        let hash = key.kind.hash(key.type_hash, key.hash);
+        self.associated_functions.insert(key, f);
+        Ok(hash)
     }
 }
 
@@ -773,6 +1847,14 @@ pub trait Function<Args>: 'static + Copy + Send + Sync {
     /// Get the number of arguments.
     fn args() -> usize;
 
+    /// Get the type hashes of the arguments, if they are statically known.
+    ///
+    /// Arguments which accept any value, like [Value], are reported as `None`.
+    fn arg_type_hashes() -> Vec<Option<Hash>>;
+
+    /// Get the type hash of the value returned, if it's statically known.
+    fn return_type_hash() -> Option<Hash>;
+
     /// Perform the vm call.
     fn fn_call(self, stack: &mut Stack, args: usize) -> Result<(), VmError>;
 }
@@ -785,6 +1867,14 @@ pub trait AsyncFunction<Args>: 'static + Copy + Send + Sync {
     /// Get the number of arguments.
     fn args() -> usize;
 
+    /// Get the type hashes of the arguments, if they are statically known.
+    ///
+    /// Arguments which accept any value, like [Value], are reported as `None`.
+    fn arg_type_hashes() -> Vec<Option<Hash>>;
+
+    /// Get the type hash of the value returned, if it's statically known.
+    fn return_type_hash() -> Option<Hash>;
+
     /// Perform the vm call.
     fn fn_call(self, stack: &mut Stack, args: usize) -> Result<(), VmError>;
 }
@@ -805,6 +1895,15 @@ pub trait InstFn<Args>: 'static + Copy + Send + Sync {
     /// Access the value type info of the instance.
     fn instance_type_info() -> TypeInfo;
 
+    /// Get the type hashes of the instance and the arguments, if they are
+    /// statically known.
+    ///
+    /// Arguments which accept any value, like [Value], are reported as `None`.
+    fn arg_type_hashes() -> Vec<Option<Hash>>;
+
+    /// Get the type hash of the value returned, if it's statically known.
+    fn return_type_hash() -> Option<Hash>;
+
     /// Perform the vm call.
     fn fn_call(self, stack: &mut Stack, args: usize) -> Result<(), VmError>;
 }
@@ -825,6 +1924,15 @@ pub trait AsyncInstFn<Args>: 'static + Copy + Send + Sync {
     /// Access the value type of the instance.
     fn instance_type_info() -> TypeInfo;
 
+    /// Get the type hashes of the instance and the arguments, if they are
+    /// statically known.
+    ///
+    /// Arguments which accept any value, like [Value], are reported as `None`.
+    fn arg_type_hashes() -> Vec<Option<Hash>>;
+
+    /// Get the type hash of the value returned, if it's statically known.
+    fn return_type_hash() -> Option<Hash>;
+
     /// Perform the vm call.
     fn fn_call(self, stack: &mut Stack, args: usize) -> Result<(), VmError>;
 }
@@ -852,6 +1960,14 @@ macro_rules! impl_register {
                 $count
             }
 
+            fn arg_type_hashes() -> Vec<Option<Hash>> {
+                vec![$(<$ty as UnsafeFromValue>::type_hint(),)*]
+            }
+
+            fn return_type_hash() -> Option<Hash> {
+                <Return as ToValue>::type_hint()
+            }
+
             fn fn_call(
                 self,
                 stack: &mut Stack,
@@ -894,6 +2010,14 @@ macro_rules! impl_register {
                 $count
             }
 
+            fn arg_type_hashes() -> Vec<Option<Hash>> {
+                vec![$(<$ty as UnsafeFromValue>::type_hint(),)*]
+            }
+
+            fn return_type_hash() -> Option<Hash> {
+                <Return::Output as ToValue>::type_hint()
+            }
+
             fn fn_call(
                 self,
                 stack: &mut Stack,
@@ -915,6 +2039,9 @@ macro_rules! impl_register {
                     impl_register!{@unsafe-vars $count, $($ty, $var, $num,)*}
 
                     Future::new(async move {
+                        // NB: the guards keep the arguments borrowed, so they
+                        // have to be held until the future completes.
+                        let _guards = ($($var.1,)*);
                         let output = self($(<$ty>::unsafe_coerce($var.0),)*).await;
                         let value = output.to_value()?;
                         Ok(value)
@@ -948,6 +2075,14 @@ macro_rules! impl_register {
                 Instance::type_info()
             }
 
+            fn arg_type_hashes() -> Vec<Option<Hash>> {
+                vec![<Instance as UnsafeFromValue>::type_hint(), $(<$ty as UnsafeFromValue>::type_hint(),)*]
+            }
+
+            fn return_type_hash() -> Option<Hash> {
+                <Return as ToValue>::type_hint()
+            }
+
             fn fn_call(self, stack: &mut Stack, args: usize) -> Result<(), VmError> {
                 impl_register!{@check-args ($count + 1), args}
 
@@ -996,6 +2131,14 @@ macro_rules! impl_register {
                 Instance::type_info()
             }
 
+            fn arg_type_hashes() -> Vec<Option<Hash>> {
+                vec![<Instance as UnsafeFromValue>::type_hint(), $(<$ty as UnsafeFromValue>::type_hint(),)*]
+            }
+
+            fn return_type_hash() -> Option<Hash> {
+                <Return::Output as ToValue>::type_hint()
+            }
+
             fn fn_call(self, stack: &mut Stack, args: usize) -> Result<(), VmError> {
                 impl_register!{@check-args ($count + 1), args}
 
@@ -1014,6 +2157,11 @@ macro_rules! impl_register {
                     impl_register!{@unsafe-inst-vars inst, $count, $($ty, $var, $num,)*}
 
                     Future::new(async move {
+                        // NB: the guards keep the instance and the arguments
+                        // borrowed, so they have to be held until the future
+                        // completes. This is what makes it sound for an async
+                        // function to hold onto `&mut self` across awaits.
+                        let _guards = (inst.1, $($var.1,)*);
                         let output = self(Instance::unsafe_coerce(inst.0), $(<$ty>::unsafe_coerce($var.0),)*).await;
                         let value = output.to_value()?;
                         Ok(value)
@@ -1043,6 +2191,7 @@ macro_rules! impl_register {
                 Err(e) => return Err(VmError::from(VmErrorKind::BadArgument {
                     error: e.unpack_critical()?,
                     arg: $count - $num,
+                    function: None,
                 })),
             };
         )*
@@ -1055,6 +2204,7 @@ macro_rules! impl_register {
             Err(e) => return Err(VmError::from(VmErrorKind::BadArgument {
                 error: e.unpack_critical()?,
                 arg: 0,
+                function: None,
             })),
         };
 
@@ -1064,6 +2214,7 @@ macro_rules! impl_register {
                 Err(e) => return Err(VmError::from(VmErrorKind::BadArgument {
                     error: e.unpack_critical()?,
                     arg: 1 + $count - $num,
+                    function: None,
                 })),
             };
         )*
@@ -1074,6 +2225,7 @@ macro_rules! impl_register {
             return Err(VmError::from(VmErrorKind::BadArgumentCount {
                 actual: $actual,
                 expected: $expected,
+                function: None,
             }));
         }
     };
@@ -1,17 +1,19 @@
 //! `std::any` module.
 
-use crate::{Any, ContextError, Module, Protocol, Value};
-use std::any::TypeId as StdTypeId;
+use crate::{Any, ContextError, Hash, Module, Protocol, Value};
 use std::fmt;
 use std::fmt::Write as _;
 
+/// The type id of a value, which is the hash of its type.
+///
+/// Displaying a type id shows the hash, like `Hash(0x8a2e5b4c1f0d3e7a)`.
 #[derive(Any, Debug)]
 #[rune(module = "crate")]
 #[repr(transparent)]
-struct TypeId(StdTypeId);
+struct TypeId(Hash);
 
 fn type_id_of_val(item: Value) -> TypeId {
-    unsafe { std::mem::transmute(item.type_hash().expect("no type known for item!")) }
+    TypeId(item.type_hash().expect("no type known for item!"))
 }
 
 fn format_type_id(item: &TypeId, buf: &mut String) -> fmt::Result {
@@ -21,6 +23,7 @@ fn format_type_id(item: &TypeId, buf: &mut String) -> fmt::Result {
 /// Construct the `std::any` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["any"]);
+    module.set_identity(super::IDENTITY);
 
     module.function(&["type_name_of_val"], Value::into_type_name)?;
 
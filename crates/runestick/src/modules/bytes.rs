@@ -1,28 +1,118 @@
 //! `std::bytes` module.
+//!
+//! Native functions can take bytes as `Bytes`, `&[u8]` or `Vec<u8>`, and
+//! should return `Bytes` for the value to be a `Bytes` in the script.
 
-use crate::{Bytes, ContextError, Module};
+use crate::allocation;
+use crate::{Bytes, ContextError, Module, Protocol, VmError, VmErrorKind};
+use std::collections::TryReserveError;
 
 /// Construct the `std::bytes` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["bytes"]);
+    module.set_identity(super::IDENTITY);
 
     module.ty::<Bytes>()?;
     module.function(&["Bytes", "new"], Bytes::new)?;
-    module.function(&["Bytes", "with_capacity"], Bytes::with_capacity)?;
+    module.function(&["Bytes", "with_capacity"], bytes_with_capacity)?;
     module.function(&["Bytes", "from_vec"], Bytes::from_vec)?;
 
     module.inst_fn("into_vec", Bytes::into_vec)?;
-    module.inst_fn("extend", Bytes::extend)?;
-    module.inst_fn("extend_str", Bytes::extend_str)?;
+    module.inst_fn("extend", bytes_extend)?;
+    module.inst_fn("extend_str", bytes_extend_str)?;
     module.inst_fn("pop", Bytes::pop)?;
     module.inst_fn("last", Bytes::last)?;
 
     module.inst_fn("len", Bytes::len)?;
     module.inst_fn("capacity", Bytes::capacity)?;
     module.inst_fn("clear", Bytes::clear)?;
-    module.inst_fn("reserve", Bytes::reserve)?;
-    module.inst_fn("reserve_exact", Bytes::reserve_exact)?;
+    module.inst_fn("reserve", bytes_reserve)?;
+    module.inst_fn("reserve_exact", bytes_reserve_exact)?;
     module.inst_fn("clone", Bytes::clone)?;
     module.inst_fn("shrink_to_fit", Bytes::shrink_to_fit)?;
+    module.inst_fn("get", bytes_get)?;
+    module.inst_fn(Protocol::INDEX_GET, bytes_index_get)?;
+    module.inst_fn(Protocol::INDEX_SET, bytes_index_set)?;
     Ok(module)
 }
+
+/// Construct empty bytes with capacity for at least `capacity` bytes.
+fn bytes_with_capacity(capacity: usize) -> Result<Bytes, VmError> {
+    let mut bytes = Bytes::new();
+    bytes_reserve(&mut bytes, capacity)?;
+    Ok(bytes)
+}
+
+/// Append all bytes in `other`.
+fn bytes_extend(bytes: &mut Bytes, other: &Bytes) -> Result<(), VmError> {
+    bytes_reserve(bytes, other.len())?;
+    bytes.extend(other);
+    Ok(())
+}
+
+/// Append the bytes of the given string.
+fn bytes_extend_str(bytes: &mut Bytes, s: &str) -> Result<(), VmError> {
+    bytes_reserve(bytes, s.len())?;
+    bytes.extend_str(s);
+    Ok(())
+}
+
+/// Reserve capacity for at least `additional` more bytes.
+///
+/// Panics if the capacity can't be allocated. Any growth is taken from the
+/// [allocation] budget first.
+fn bytes_reserve(bytes: &mut Bytes, additional: usize) -> Result<(), VmError> {
+    let reserve = allocation::take_growth::<u8>(bytes.len(), bytes.capacity(), additional)?;
+    bytes
+        .bytes
+        .try_reserve_exact(reserve)
+        .map_err(|error| reserve_error(additional, error))
+}
+
+/// Reserve capacity for exactly `additional` more bytes.
+fn bytes_reserve_exact(bytes: &mut Bytes, additional: usize) -> Result<(), VmError> {
+    let required = bytes.len().saturating_add(additional);
+    allocation::take_bytes(required.saturating_sub(bytes.capacity()))?;
+    bytes
+        .bytes
+        .try_reserve_exact(additional)
+        .map_err(|error| reserve_error(additional, error))
+}
+
+fn reserve_error(additional: usize, error: TryReserveError) -> VmError {
+    VmError::panic(format!(
+        "failed to reserve capacity for {} bytes: {}",
+        additional, error
+    ))
+}
+
+/// Get the byte at the given index, or `None` if it's out of bounds.
+fn bytes_get(bytes: &Bytes, index: usize) -> Option<u8> {
+    bytes.bytes.get(index).copied()
+}
+
+/// Get the byte at the given index, erroring if it's out of bounds.
+fn bytes_index_get(bytes: &Bytes, index: usize) -> Result<u8, VmError> {
+    match bytes.bytes.get(index) {
+        Some(b) => Ok(*b),
+        None => Err(out_of_range(bytes, index)),
+    }
+}
+
+/// Set the byte at the given index, erroring if it's out of bounds.
+fn bytes_index_set(bytes: &mut Bytes, index: usize, value: u8) -> Result<(), VmError> {
+    match bytes.bytes.get_mut(index) {
+        Some(b) => {
+            *b = value;
+            Ok(())
+        }
+        None => Err(out_of_range(bytes, index)),
+    }
+}
+
+fn out_of_range(bytes: &Bytes, index: usize) -> VmError {
+    VmError::from(VmErrorKind::OutOfRange {
+        index: index.into(),
+        len: bytes.len().into(),
+    })
+}
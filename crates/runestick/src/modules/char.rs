@@ -1,14 +1,17 @@
 //! The `std::char` module.
 
-use crate::{ContextError, Module, Value, VmError, VmErrorKind};
+use crate::{ContextError, Module};
 use std::char::ParseCharError;
+use std::convert::TryFrom as _;
 
 /// Construct the `std::char` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["char"]);
+    module.set_identity(super::IDENTITY);
     module.ty::<ParseCharError>()?;
 
-    module.function(&["from_int"], char_from_int_impl)?;
+    module.function(&["from_int"], char_from_int)?;
+    module.function(&["to_int"], char_to_int)?;
     module.function(&["is_alphabetic"], char::is_alphabetic)?;
     module.function(&["is_alphanumeric"], char::is_alphanumeric)?;
     module.function(&["is_control"], char::is_control)?;
@@ -19,17 +22,48 @@ pub fn module() -> Result<Module, ContextError> {
 
     module.function(&["to_digit"], char::to_digit)?;
 
+    module.inst_fn("to_int", char_to_int)?;
+    module.inst_fn("is_alphabetic", char::is_alphabetic)?;
+    module.inst_fn("is_alphanumeric", char::is_alphanumeric)?;
+    module.inst_fn("is_control", char::is_control)?;
+    module.inst_fn("is_lowercase", char::is_lowercase)?;
+    module.inst_fn("is_numeric", char::is_numeric)?;
+    module.inst_fn("is_uppercase", char::is_uppercase)?;
+    module.inst_fn("is_whitespace", char::is_whitespace)?;
+    module.inst_fn("to_digit", char::to_digit)?;
+    module.inst_fn("to_lowercase", char_to_lowercase)?;
+    module.inst_fn("to_uppercase", char_to_uppercase)?;
+
     Ok(module)
 }
 
-fn char_from_int_impl(value: i64) -> Result<Option<Value>, VmError> {
-    if value < 0 {
-        Err(VmError::from(VmErrorKind::Underflow))
-    } else if value > u32::MAX as i64 {
-        Err(VmError::from(VmErrorKind::Overflow))
-    } else {
-        Ok(std::char::from_u32(value as u32).map(|v| v.into()))
-    }
+/// Convert an integer into the character with the corresponding unicode
+/// scalar value.
+///
+/// Returns `None` if the integer isn't a valid unicode scalar value.
+fn char_from_int(value: i64) -> Option<char> {
+    std::char::from_u32(u32::try_from(value).ok()?)
+}
+
+/// Convert a character into its unicode scalar value.
+fn char_to_int(c: char) -> i64 {
+    u32::from(c) as i64
+}
+
+/// Convert a character into lowercase.
+///
+/// This returns a string since some characters map to more than one
+/// character.
+fn char_to_lowercase(c: char) -> String {
+    c.to_lowercase().collect()
+}
+
+/// Convert a character into uppercase.
+///
+/// This returns a string since some characters map to more than one
+/// character, like `'ß'` which becomes `"SS"`.
+fn char_to_uppercase(c: char) -> String {
+    c.to_uppercase().collect()
 }
 
 crate::__internal_impl_any!(ParseCharError);
@@ -4,6 +4,7 @@ use crate::{ContextError, Module};
 /// Construct the `std::cmp` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["cmp"]);
+    module.set_identity(super::IDENTITY);
 
     module.ty::<std::cmp::Ordering>()?;
 
@@ -408,6 +408,7 @@ impl VecDeque {
 /// The `std::collections` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["collections"]);
+    module.set_identity(super::IDENTITY);
     module.ty::<HashMap>()?;
     module.function(&["HashMap", "new"], HashMap::new)?;
     module.function(&["HashMap", "from"], hashmap_from)?;
@@ -5,6 +5,7 @@ use crate::{ContextError, Module, Panic, Value};
 /// Construct the `std` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate("std");
+    module.set_identity(super::IDENTITY);
 
     module.unit("unit")?;
     module.ty::<bool>()?;
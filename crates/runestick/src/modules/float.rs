@@ -18,6 +18,7 @@ crate::__internal_impl_any!(ParseFloatError);
 /// Install the core package into the given functions namespace.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["float"]);
+    module.set_identity(super::IDENTITY);
 
     module.ty::<ParseFloatError>()?;
     module.function(&["parse"], parse)?;
@@ -6,6 +6,7 @@ use crate::{ContextError, Future, Module, Shared, Stack, Value, VmError, VmError
 /// Construct the `std::future` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["future"]);
+    module.set_identity(super::IDENTITY);
     module.ty::<Future>()?;
     module.raw_fn(&["join"], raw_join)?;
     Ok(module)
@@ -59,6 +60,7 @@ fn raw_join(stack: &mut Stack, args: usize) -> Result<(), VmError> {
         return Err(VmError::from(VmErrorKind::BadArgumentCount {
             actual: args,
             expected: 1,
+            function: None,
         }));
     }
 
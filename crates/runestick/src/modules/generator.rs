@@ -5,6 +5,7 @@ use crate::{ContextError, Generator, Module, Protocol};
 /// Construct the `std::generator` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["generator"]);
+    module.set_identity(super::IDENTITY);
     module.ty::<Generator>()?;
     module.generator_state(&["GeneratorState"])?;
 
@@ -6,6 +6,7 @@ use std::num::ParseIntError;
 /// Construct the `std::int` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["int"]);
+    module.set_identity(super::IDENTITY);
 
     module.ty::<ParseIntError>()?;
 
@@ -9,6 +9,7 @@ use std::io::Write as _;
 /// Construct the `std::io` module.
 pub fn module(stdio: bool) -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["io"]);
+    module.set_identity(super::IDENTITY);
 
     module.ty::<io::Error>()?;
     module.inst_fn(Protocol::STRING_DISPLAY, format_io_error)?;
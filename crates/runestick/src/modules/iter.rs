@@ -7,6 +7,7 @@ use crate::{
 /// Construct the `std::iter` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["iter"]);
+    module.set_identity(super::IDENTITY);
     module.ty::<Iterator>()?;
 
     // Sorted for ease of finding
@@ -18,6 +19,8 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("filter", Iterator::filter)?;
     module.inst_fn("find", Iterator::find)?;
     module.inst_fn("flat_map", Iterator::flat_map)?;
+    module.inst_fn("last", Iterator::last)?;
+    module.inst_fn("len", Iterator::len)?;
     module.inst_fn("map", Iterator::map)?;
     module.inst_fn("next", Iterator::next)?;
     module.inst_fn("next_back", Iterator::next_back)?;
@@ -29,6 +32,7 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("size_hint", Iterator::size_hint)?;
     module.inst_fn("sum", Iterator::sum)?;
     module.inst_fn("skip", Iterator::skip)?;
+    module.inst_fn("step_by", Iterator::step_by)?;
     module.inst_fn("take", Iterator::take)?;
     module.inst_fn("count", Iterator::count)?;
     module.inst_fn("all", Iterator::all)?;
@@ -5,6 +5,7 @@ use crate::{ContextError, Module, Value, VmError};
 /// Construct the `std` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["mem"]);
+    module.set_identity(super::IDENTITY);
     module.function(&["drop"], drop_impl)?;
     Ok(module)
 }
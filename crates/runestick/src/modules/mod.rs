@@ -21,4 +21,9 @@ pub mod option;
 pub mod result;
 pub mod stream;
 pub mod string;
+pub mod time;
 pub mod vec;
+
+/// The identity of the modules provided by this crate, see
+/// [Module::set_identity][crate::Module::set_identity].
+pub(crate) const IDENTITY: &str = concat!("runestick@", env!("CARGO_PKG_VERSION"));
@@ -1,15 +1,17 @@
 //! The `std::object` module.
 
-use crate::{ContextError, Iterator, Module, Object, Protocol, Value};
+use crate::allocation;
+use crate::{ContextError, Iterator, Module, Object, Protocol, Value, VmError};
 
 /// Construct the `std::object` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["object"]);
+    module.set_identity(super::IDENTITY);
 
     module.ty::<Object>()?;
 
     module.inst_fn("len", Object::len)?;
-    module.inst_fn("insert", Object::insert)?;
+    module.inst_fn("insert", insert)?;
     module.inst_fn("clear", Object::clear)?;
     module.inst_fn("contains_key", contains_key)?;
     module.inst_fn("get", get)?;
@@ -21,6 +23,17 @@ pub fn module() -> Result<Module, ContextError> {
     Ok(module)
 }
 
+/// Insert a value, returning the value previously stored under the same key.
+///
+/// Adding a new key is taken from the [allocation] budget first.
+fn insert(object: &mut Object, key: String, value: Value) -> Result<Option<Value>, VmError> {
+    if !object.contains_key(&key) {
+        allocation::take_elements::<(String, Value)>(1)?;
+    }
+
+    Ok(object.insert(key, value))
+}
+
 fn contains_key(object: &Object, key: &str) -> bool {
     object.contains_key(key)
 }
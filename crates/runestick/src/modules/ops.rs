@@ -5,6 +5,7 @@ use crate::{ContextError, Module, Protocol, Range, Value};
 /// Construct the `std::ops` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["ops"]);
+    module.set_identity(super::IDENTITY);
     module.ty::<Range>()?;
     module.inst_fn("contains_int", Range::contains_int)?;
     module.field_fn(Protocol::SET, "start", range_set_start)?;
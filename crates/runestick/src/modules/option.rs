@@ -5,6 +5,7 @@ use crate::{ContextError, Function, Module, Protocol, Shared, Value, VmError};
 /// Construct the `std::option` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["option"]);
+    module.set_identity(super::IDENTITY);
     module.option(&["Option"])?;
     // Sorted for ease of finding
     module.inst_fn("and_then", and_then_impl)?;
@@ -5,6 +5,7 @@ use crate::{ContextError, Function, Module, Value, VmError};
 /// Construct the `std::result` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["result"]);
+    module.set_identity(super::IDENTITY);
     // Sorted for ease of finding
     module.result(&["Result"])?;
     module.inst_fn("ok", ok)?;
@@ -1,16 +1,19 @@
 //! The `std::string` module.
 
+use crate::allocation;
 use crate::{Any, Bytes, ContextError, Iterator, Module, Protocol, Value, VmError, VmErrorKind};
+use std::collections::TryReserveError;
 
 /// Construct the `std::string` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["string"]);
+    module.set_identity(super::IDENTITY);
 
     module.ty::<String>()?;
 
     module.function(&["String", "from_str"], <String as From<&str>>::from)?;
     module.function(&["String", "new"], String::new)?;
-    module.function(&["String", "with_capacity"], String::with_capacity)?;
+    module.function(&["String", "with_capacity"], string_with_capacity)?;
 
     module.inst_fn("cmp", str::cmp)?;
     module.inst_fn("len", String::len)?;
@@ -18,15 +21,16 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("ends_with", str::ends_with::<&str>)?;
     module.inst_fn("capacity", String::capacity)?;
     module.inst_fn("clear", String::clear)?;
-    module.inst_fn("push", String::push)?;
-    module.inst_fn("push_str", String::push_str)?;
-    module.inst_fn("reserve", String::reserve)?;
-    module.inst_fn("reserve_exact", String::reserve_exact)?;
+    module.inst_fn("push", string_push)?;
+    module.inst_fn("push_str", string_push_str)?;
+    module.inst_fn("reserve", string_reserve)?;
+    module.inst_fn("reserve_exact", string_reserve_exact)?;
     module.inst_fn("into_bytes", into_bytes)?;
     module.inst_fn("clone", String::clone)?;
     module.inst_fn("shrink_to_fit", String::shrink_to_fit)?;
     module.inst_fn("char_at", char_at)?;
     module.inst_fn("split", string_split)?;
+    module.inst_fn("splitn", string_splitn)?;
     module.inst_fn("trim", string_trim)?;
     module.inst_fn("trim_end", string_trim_end)?;
     module.inst_fn("replace", str::replace::<&str>)?;
@@ -35,7 +39,7 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("is_empty", str::is_empty)?;
     module.inst_fn("chars", string_chars)?;
     module.inst_fn(Protocol::ADD, add)?;
-    module.inst_fn(Protocol::ADD_ASSIGN, String::push_str)?;
+    module.inst_fn(Protocol::ADD_ASSIGN, string_push_str)?;
     module.inst_fn(Protocol::INDEX_GET, string_index_get)?;
     module.inst_fn("get", string_get)?;
 
@@ -62,6 +66,52 @@ impl NotCharBoundary {
     }
 }
 
+/// Construct an empty string with capacity for at least `capacity` bytes.
+fn string_with_capacity(capacity: usize) -> Result<String, VmError> {
+    let mut s = String::new();
+    string_reserve(&mut s, capacity)?;
+    Ok(s)
+}
+
+/// Reserve capacity for at least `additional` more bytes.
+///
+/// Panics if the capacity can't be allocated. Any growth is taken from the
+/// [allocation] budget first.
+fn string_reserve(s: &mut String, additional: usize) -> Result<(), VmError> {
+    let reserve = allocation::take_growth::<u8>(s.len(), s.capacity(), additional)?;
+    s.try_reserve_exact(reserve)
+        .map_err(|error| reserve_error(additional, error))
+}
+
+/// Reserve capacity for exactly `additional` more bytes.
+fn string_reserve_exact(s: &mut String, additional: usize) -> Result<(), VmError> {
+    let required = s.len().saturating_add(additional);
+    allocation::take_bytes(required.saturating_sub(s.capacity()))?;
+    s.try_reserve_exact(additional)
+        .map_err(|error| reserve_error(additional, error))
+}
+
+fn reserve_error(additional: usize, error: TryReserveError) -> VmError {
+    VmError::panic(format!(
+        "failed to reserve capacity for {} bytes: {}",
+        additional, error
+    ))
+}
+
+/// Append a character to the end of the string.
+fn string_push(s: &mut String, c: char) -> Result<(), VmError> {
+    string_reserve(s, c.len_utf8())?;
+    s.push(c);
+    Ok(())
+}
+
+/// Append a string slice to the end of the string.
+fn string_push_str(s: &mut String, other: &str) -> Result<(), VmError> {
+    string_reserve(s, other.len())?;
+    s.push_str(other);
+    Ok(())
+}
+
 /// into_bytes shim for strings.
 fn into_bytes(s: String) -> Bytes {
     Bytes::from_vec(s.into_bytes())
@@ -75,24 +125,35 @@ fn char_at(s: &str, index: usize) -> Option<char> {
     s[index..].chars().next()
 }
 
-fn string_split(this: &str, value: Value) -> Result<Iterator, VmError> {
-    let lines = match value {
+/// Split the string by the given separator, which can be either a string or a
+/// character.
+///
+/// Like in Rust, splitting an empty string produces a vector containing a
+/// single empty string.
+fn string_split(this: &str, value: Value) -> Result<Vec<String>, VmError> {
+    Ok(match value {
         Value::String(s) => this
             .split(s.borrow_ref()?.as_str())
             .map(String::from)
-            .collect::<Vec<String>>(),
-        Value::StaticString(s) => this
-            .split(s.as_str())
-            .map(String::from)
-            .collect::<Vec<String>>(),
-        Value::Char(pat) => this.split(pat).map(String::from).collect::<Vec<String>>(),
+            .collect(),
+        Value::StaticString(s) => this.split(s.as_str()).map(String::from).collect(),
+        Value::Char(pat) => this.split(pat).map(String::from).collect(),
         value => return Err(VmError::bad_argument::<String>(0, &value)?),
-    };
+    })
+}
 
-    Ok(Iterator::from_double_ended(
-        "std::str::Split",
-        lines.into_iter(),
-    ))
+/// Split the string by the given separator into at most `n` substrings, where
+/// the last substring contains the remainder of the string.
+fn string_splitn(this: &str, n: usize, value: Value) -> Result<Vec<String>, VmError> {
+    Ok(match value {
+        Value::String(s) => this
+            .splitn(n, s.borrow_ref()?.as_str())
+            .map(String::from)
+            .collect(),
+        Value::StaticString(s) => this.splitn(n, s.as_str()).map(String::from).collect(),
+        Value::Char(pat) => this.splitn(n, pat).map(String::from).collect(),
+        value => return Err(VmError::bad_argument::<String>(1, &value)?),
+    })
 }
 
 fn string_trim(this: &str) -> String {
@@ -124,51 +185,72 @@ fn string_chars(s: &str) -> Iterator {
     Iterator::from_double_ended("std::str::Chars", iter)
 }
 
-/// Get a specific string index.
-fn string_get(s: &str, key: Value) -> Result<Option<String>, VmError> {
-    use crate::{FromValue as _, RangeLimits, TypeOf as _};
+/// Get the byte offset of the character at the given character index.
+///
+/// The index one past the last character maps to the length of the string.
+fn char_offset(s: &str, index: usize) -> Option<usize> {
+    match s.char_indices().nth(index) {
+        Some((offset, _)) => Some(offset),
+        None if s.chars().count() == index => Some(s.len()),
+        None => None,
+    }
+}
+
+/// Get the substring between the given character indexes, which have already
+/// been checked to be in bounds.
+fn char_slice(s: &str, start: usize, end: usize) -> &str {
+    let start = char_offset(s, start).unwrap_or(s.len());
+    let end = char_offset(s, end).unwrap_or(s.len());
+    &s[start..end]
+}
+
+/// Get the character at the given character index.
+fn char_at_index(s: &str, index: i64) -> Option<char> {
+    use std::convert::TryFrom as _;
+
+    s.chars().nth(usize::try_from(index).ok()?)
+}
+
+/// Construct the error raised when indexing a string with an unsupported key.
+fn unsupported_index_get(index: &Value) -> Result<VmError, VmError> {
+    use crate::TypeOf as _;
 
+    Ok(VmError::from(VmErrorKind::UnsupportedIndexGet {
+        target: String::type_info(),
+        index: index.type_info()?,
+    }))
+}
+
+/// Get a specific string index.
+///
+/// Strings are indexed by character rather than by byte, so an integer index
+/// returns the character at that position, and a range returns the substring
+/// covering the characters in the range.
+fn string_get(s: &str, key: Value) -> Result<Option<Value>, VmError> {
     match key {
+        Value::Integer(index) => Ok(char_at_index(s, index).map(Value::from)),
         Value::Range(range) => {
-            let range = range.borrow_ref()?;
-
-            let start = match range.start.clone() {
-                Some(value) => Some(<usize>::from_value(value)?),
-                None => None,
-            };
-
-            let end = match range.end.clone() {
-                Some(value) => Some(<usize>::from_value(value)?),
-                None => None,
-            };
-
-            let out = match range.limits {
-                RangeLimits::HalfOpen => match (start, end) {
-                    (Some(start), Some(end)) => s.get(start..end),
-                    (Some(start), None) => s.get(start..),
-                    (None, Some(end)) => s.get(..end),
-                    (None, None) => s.get(..),
-                },
-                RangeLimits::Closed => match (start, end) {
-                    (Some(start), Some(end)) => s.get(start..=end),
-                    (None, Some(end)) => s.get(..=end),
-                    _ => return Err(VmError::from(VmErrorKind::UnsupportedRange)),
-                },
-            };
-
-            return Ok(match out {
-                Some(out) => Some(out.to_owned()),
-                None => None,
-            });
+            let bounds = range.borrow_ref()?.try_bounds(s.chars().count())?;
+            Ok(bounds.map(|(start, end)| Value::from(char_slice(s, start, end).to_owned())))
         }
-        index => Err(VmError::from(VmErrorKind::UnsupportedIndexGet {
-            target: String::type_info(),
-            index: index.type_info()?,
-        })),
+        index => Err(unsupported_index_get(&index)?),
     }
 }
 
-/// Get a specific string index.
-fn string_index_get(s: &str, key: Value) -> Result<String, VmError> {
-    string_get(s, key)?.ok_or_else(|| VmError::panic("missing string slice"))
+/// Get a specific string index, erroring if it's out of bounds.
+fn string_index_get(s: &str, key: Value) -> Result<Value, VmError> {
+    match key {
+        Value::Integer(index) => match char_at_index(s, index) {
+            Some(c) => Ok(Value::from(c)),
+            None => Err(VmError::from(VmErrorKind::OutOfRange {
+                index: index.into(),
+                len: s.chars().count().into(),
+            })),
+        },
+        Value::Range(range) => {
+            let (start, end) = range.borrow_ref()?.bounds(s.chars().count())?;
+            Ok(Value::from(char_slice(s, start, end).to_owned()))
+        }
+        index => Err(unsupported_index_get(&index)?),
+    }
 }
@@ -0,0 +1,91 @@
+//! The `std::time` module.
+
+use crate::{ContextError, Module, Protocol, VmError, VmErrorKind};
+use std::fmt;
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+
+/// Construct the `std::time` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::with_crate_item("std", &["time"]);
+    module.set_identity(super::IDENTITY);
+
+    module.ty::<Duration>()?;
+    module.function(&["Duration", "from_secs"], Duration::from_secs)?;
+    module.function(&["Duration", "from_millis"], Duration::from_millis)?;
+    module.function(&["Duration", "from_micros"], Duration::from_micros)?;
+    module.function(&["Duration", "from_nanos"], Duration::from_nanos)?;
+    module.function(&["Duration", "from_secs_f64"], duration_from_secs_f64)?;
+    module.inst_fn("as_secs", Duration::as_secs)?;
+    module.inst_fn("as_millis", Duration::as_millis)?;
+    module.inst_fn("as_micros", Duration::as_micros)?;
+    module.inst_fn("as_nanos", Duration::as_nanos)?;
+    module.inst_fn("as_secs_f64", Duration::as_secs_f64)?;
+    module.inst_fn("checked_add", duration_checked_add)?;
+    module.inst_fn("checked_sub", duration_checked_sub)?;
+    module.inst_fn(Protocol::ADD, duration_add)?;
+    module.inst_fn(Protocol::SUB, duration_sub)?;
+    module.inst_fn(Protocol::EQ, duration_eq)?;
+    module.inst_fn(Protocol::STRING_DEBUG, duration_string_debug)?;
+
+    module.ty::<Instant>()?;
+    module.function(&["Instant", "now"], Instant::now)?;
+    module.inst_fn("elapsed", Instant::elapsed)?;
+    module.inst_fn("duration_since", instant_duration_since)?;
+    module.inst_fn(Protocol::ADD, instant_add)?;
+    module.inst_fn(Protocol::STRING_DEBUG, instant_string_debug)?;
+
+    Ok(module)
+}
+
+/// Construct a duration from a number of seconds, erroring if it's negative
+/// or too large to be represented.
+fn duration_from_secs_f64(secs: f64) -> Result<Duration, VmError> {
+    Duration::try_from_secs_f64(secs)
+        .map_err(|_| VmError::panic(format!("cannot convert `{}` seconds into a duration", secs)))
+}
+
+fn duration_checked_add(a: &Duration, b: &Duration) -> Option<Duration> {
+    a.checked_add(*b)
+}
+
+fn duration_checked_sub(a: &Duration, b: &Duration) -> Option<Duration> {
+    a.checked_sub(*b)
+}
+
+fn duration_add(a: &Duration, b: &Duration) -> Result<Duration, VmError> {
+    a.checked_add(*b)
+        .ok_or_else(|| VmError::from(VmErrorKind::Overflow))
+}
+
+fn duration_sub(a: &Duration, b: &Duration) -> Result<Duration, VmError> {
+    a.checked_sub(*b)
+        .ok_or_else(|| VmError::from(VmErrorKind::Underflow))
+}
+
+fn duration_eq(a: &Duration, b: &Duration) -> bool {
+    a == b
+}
+
+fn duration_string_debug(duration: &Duration, s: &mut String) -> fmt::Result {
+    write!(s, "{:?}", duration)
+}
+
+/// Get the duration elapsed since `earlier`, which is zero if `earlier` is
+/// later than this instant.
+fn instant_duration_since(instant: &Instant, earlier: &Instant) -> Duration {
+    instant.saturating_duration_since(*earlier)
+}
+
+fn instant_add(instant: &Instant, duration: &Duration) -> Result<Instant, VmError> {
+    instant
+        .checked_add(*duration)
+        .ok_or_else(|| VmError::from(VmErrorKind::Overflow))
+}
+
+fn instant_string_debug(instant: &Instant, s: &mut String) -> fmt::Result {
+    write!(s, "{:?}", instant)
+}
+
+crate::__internal_impl_any!(Duration);
+crate::__internal_impl_any!(Instant);
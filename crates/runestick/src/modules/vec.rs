@@ -1,27 +1,74 @@
 //! The `std::vec` module.
 
-use crate::{ContextError, Module, Protocol, Value, Vec};
+use crate::allocation;
+use crate::protocol_caller::{EnvProtocolCaller, ProtocolCaller as _};
+use crate::{
+    ContextError, FromValue as _, Function, Hash, Iterator, Module, OverflowPolicy, Protocol,
+    Range, Ref, Shared, Value, Vec, Vm, VmError, VmErrorKind,
+};
+use std::cmp;
+use std::fmt;
 
 /// Construct the `std::vec` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["vec"]);
+    module.set_identity(super::IDENTITY);
 
     module.ty::<Vec>()?;
 
     module.function(&["Vec", "new"], Vec::new)?;
+    module.function(&["Vec", "concat"], vec_concat)?;
+    module.function(&["Vec", "with_capacity"], vec_with_capacity)?;
+    module.function(&["zip"], vec_zip)?;
+    module.inst_fn("all", vec_all)?;
+    module.inst_fn("any", vec_any)?;
+    module.inst_fn("binary_search", vec_binary_search)?;
+    module.inst_fn("capacity", Vec::capacity)?;
     module.inst_fn("clear", Vec::clear)?;
     module.inst_fn("clone", Vec::clone)?;
-    module.inst_fn("extend", Vec::extend)?;
+    module.inst_fn("concat_with", vec_add)?;
+    module.inst_fn("dedup_by_key", vec_dedup_by_key)?;
+    module.inst_fn("drain", vec_drain)?;
+    module.inst_fn("ends_with", vec_ends_with)?;
+    module.inst_fn("extend", vec_extend)?;
+    module.inst_fn("extend_from_slice", vec_extend_from_slice)?;
+    module.inst_fn("filter", vec_filter)?;
+    module.inst_fn("find", vec_find)?;
+    module.inst_fn("find_index", vec_find_index)?;
+    module.inst_fn("flatten", vec_flatten)?;
+    module.inst_fn("for_each_mut", vec_for_each_mut)?;
     module.inst_fn("get", vec_get)?;
+    module.inst_fn("group_by", vec_group_by)?;
+    module.inst_fn("is_empty", Vec::is_empty)?;
     module.inst_fn("iter", Vec::into_iterator)?;
+    module.inst_fn("iter_rev", vec_iter_rev)?;
     module.inst_fn("len", Vec::len)?;
+    module.inst_fn("map", vec_map)?;
+    module.inst_fn("max", vec_max)?;
+    module.inst_fn("max_by_key", vec_max_by_key)?;
+    module.inst_fn("min", vec_min)?;
+    module.inst_fn("min_by_key", vec_min_by_key)?;
+    module.inst_fn("partition", vec_partition)?;
     module.inst_fn("pop", Vec::pop)?;
-    module.inst_fn("push", Vec::push)?;
+    module.inst_fn("position", vec_find_index)?;
+    module.inst_fn("push", vec_push)?;
+    module.inst_fn("rchunks", vec_rchunks)?;
     module.inst_fn("remove", Vec::remove)?;
+    module.inst_fn("reserve", vec_reserve)?;
+    module.inst_fn("reversed", vec_reversed)?;
     module.inst_fn("sort_by", sort_by)?;
-    module.inst_fn("insert", Vec::insert)?;
+    module.inst_fn("splice", vec_splice)?;
+    module.inst_fn("starts_with", vec_starts_with)?;
+    module.inst_fn("sum", vec_sum)?;
+    module.inst_fn("truncate", Vec::truncate)?;
+    module.inst_fn("insert", vec_insert)?;
+    module.inst_fn("into_iter", vec_into_iter)?;
     module.inst_fn(Protocol::INTO_ITER, Vec::into_iterator)?;
+    module.inst_fn(Protocol::ADD, vec_add)?;
+    module.inst_fn(Protocol::INDEX_GET, vec_index_get)?;
     module.inst_fn(Protocol::INDEX_SET, Vec::set)?;
+    module.inst_fn(Protocol::PARTIAL_CMP, vec_partial_cmp)?;
+    module.inst_fn(Protocol::STRING_DISPLAY, vec_string_display)?;
 
     // TODO: parameterize with generics.
     module.inst_fn("sort_int", sort_int)?;
@@ -30,16 +77,684 @@ pub fn module() -> Result<Module, ContextError> {
 }
 
 /// Sort a vector of integers.
-fn sort_int(vec: &mut Vec) {
+///
+/// Errors if the vector contains anything which isn't an integer.
+fn sort_int(vec: &mut Vec) -> Result<(), VmError> {
+    for value in vec.iter() {
+        if !matches!(value, Value::Integer(..)) {
+            return Err(VmError::expected::<i64>(value.type_info()?));
+        }
+    }
+
     vec.sort_by(|a, b| match (a, b) {
-        (Value::Integer(a), Value::Integer(b)) => a.cmp(&b),
-        // NB: fall back to sorting by address.
-        _ => (a as *const _ as usize).cmp(&(b as *const _ as usize)),
+        (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+        // NB: all elements have been checked to be integers above.
+        _ => cmp::Ordering::Equal,
     });
+
+    Ok(())
+}
+
+/// Remove the elements in the given range from the vector, returning them as a
+/// new vector.
+fn vec_drain(vec: &mut Vec, range: &Range) -> Result<Vec, VmError> {
+    let (start, end) = range.bounds(vec.len())?;
+    Ok(vec.drain(start..end).collect::<std::vec::Vec<_>>().into())
+}
+
+/// Replace the elements in the given range with clones of the elements in
+/// `replacement`, returning the removed elements as a new vector.
+///
+/// The replacement doesn't have to be the same length as the range. Like with
+/// [vec_extend_from_slice], the replacement is allowed to be the vector itself.
+fn vec_splice(vec: Shared<Vec>, range: &Range, replacement: Ref<Vec>) -> Result<Vec, VmError> {
+    let values = replacement.to_vec();
+    drop(replacement);
+
+    let mut vec = vec.borrow_mut()?;
+    let (start, end) = range.bounds(vec.len())?;
+    vec_reserve(&mut vec, values.len().saturating_sub(end - start))?;
+    let removed = vec.splice(start..end, values).collect::<std::vec::Vec<_>>();
+    Ok(removed.into())
+}
+
+/// Append clones of all elements in `other` to the end of the vector.
+///
+/// The elements of `other` are copied out before the vector is borrowed for
+/// writing, so `other` is allowed to be the vector itself, in which case its
+/// contents are duplicated.
+fn vec_extend_from_slice(vec: Shared<Vec>, other: Ref<Vec>) -> Result<(), VmError> {
+    let values = other.to_vec();
+    drop(other);
+
+    let mut vec = vec.borrow_mut()?;
+    vec_reserve(&mut vec, values.len())?;
+
+    for value in values {
+        vec.push(value);
+    }
+
+    Ok(())
+}
+
+/// Construct an empty vector with capacity for at least `capacity` elements.
+///
+/// Panics if the capacity can't be allocated.
+fn vec_with_capacity(capacity: usize) -> Result<Vec, VmError> {
+    let mut vec = Vec::new();
+    vec_reserve(&mut vec, capacity)?;
+    Ok(vec)
+}
+
+/// Reserve capacity for at least `additional` more elements.
+///
+/// Panics if the capacity can't be allocated, instead of aborting the process
+/// like reserving capacity in Rust does. Any growth is taken from the
+/// [allocation] budget first.
+fn vec_reserve(vec: &mut Vec, additional: usize) -> Result<(), VmError> {
+    let reserve = allocation::take_growth::<Value>(vec.len(), vec.capacity(), additional)?;
+
+    vec.try_reserve_exact(reserve).map_err(|error| {
+        VmError::panic(format!(
+            "failed to reserve capacity for {} elements: {}",
+            additional, error
+        ))
+    })
+}
+
+/// Append an element to the back of the vector.
+fn vec_push(vec: &mut Vec, value: Value) -> Result<(), VmError> {
+    vec_reserve(vec, 1)?;
+    vec.push(value);
+    Ok(())
+}
+
+/// Insert an element at `index`, shifting all elements after it to the right.
+fn vec_insert(vec: &mut Vec, index: usize, value: Value) -> Result<(), VmError> {
+    vec_reserve(vec, 1)?;
+    vec.insert(index, value);
+    Ok(())
+}
+
+/// Append every element produced by iterating over `value`.
+fn vec_extend(vec: &mut Vec, value: Value) -> Result<(), VmError> {
+    let mut it = value.into_iter()?;
+
+    while let Some(value) = it.next()? {
+        vec_push(vec, value)?;
+    }
+
+    Ok(())
+}
+
+/// Iterate over the vector in chunks of `size` elements, starting at the end.
+///
+/// Each chunk is a new vector holding clones of the elements. If the length
+/// of the vector isn't divisible by `size`, the last chunk produced holds the
+/// first elements of the vector and is shorter than `size`.
+fn vec_rchunks(vec: &Vec, size: usize) -> Result<Iterator, VmError> {
+    if size == 0 {
+        return Err(VmError::panic("chunk size must be non-zero"));
+    }
+
+    let chunks = vec
+        .rchunks(size)
+        .map(|chunk| Value::vec(chunk.to_vec()))
+        .collect::<std::vec::Vec<_>>();
+
+    Ok(Iterator::from_double_ended(
+        "std::vec::RChunks",
+        chunks.into_iter(),
+    ))
+}
+
+/// Format the vector as `[a, b, c]`.
+///
+/// Each element is formatted using its own [Protocol::STRING_DISPLAY], falling
+/// back to [Protocol::STRING_DEBUG] for elements which don't implement it.
+fn vec_string_display(vec: &Vec, s: &mut String) -> Result<fmt::Result, VmError> {
+    let mut part = String::new();
+    let mut buf = String::new();
+
+    s.push('[');
+
+    for (index, value) in vec.iter().enumerate() {
+        if index > 0 {
+            s.push_str(", ");
+        }
+
+        part.clear();
+
+        let result = match value.string_display(&mut part, &mut buf) {
+            Err(error) if is_missing_display(value, &error)? => {
+                part.clear();
+                value.string_debug(&mut part)?
+            }
+            result => result?,
+        };
+
+        if let Err(error) = result {
+            return Ok(Err(error));
+        }
+
+        s.push_str(&part);
+    }
+
+    s.push(']');
+    Ok(Ok(()))
 }
 
-fn vec_get(vec: &Vec, index: usize) -> Option<Value> {
-    vec.get(index).cloned()
+/// Test if the error was raised because the given value doesn't implement
+/// [Protocol::STRING_DISPLAY].
+fn is_missing_display(value: &Value, error: &VmError) -> Result<bool, VmError> {
+    let expected = Hash::instance_function(value.type_hash()?, Protocol::STRING_DISPLAY.hash);
+    Ok(matches!(error.kind(), VmErrorKind::MissingFunction { hash } if *hash == expected))
+}
+
+/// Construct an iterator which consumes the vector, moving its elements out of
+/// it instead of cloning them.
+///
+/// The vector can't be used after it's been consumed.
+fn vec_into_iter(vec: Vec) -> Iterator {
+    Iterator::from_double_ended("std::vec::IntoIter", vec.into_iter())
+}
+
+/// Construct an iterator over the elements of the vector, starting from the
+/// end.
+///
+/// Like `iter`, this snapshots the elements of the vector when it's created, so
+/// modifying the vector while iterating doesn't affect which elements are
+/// produced. This is the same as `iter().rev()`.
+fn vec_iter_rev(vec: &Vec) -> Iterator {
+    Iterator::from_double_ended("std::vec::IterRev", vec.clone().into_iter().rev())
+}
+
+/// Get the element at the given index, or a new vector with the elements in
+/// the given range.
+///
+/// Returns `None` if the index or range is out of bounds.
+fn vec_get(vec: &Vec, index: Value) -> Result<Option<Value>, VmError> {
+    use std::convert::TryFrom as _;
+
+    match index {
+        Value::Integer(index) => Ok(usize::try_from(index)
+            .ok()
+            .and_then(|index| vec.get(index).cloned())),
+        Value::Range(range) => {
+            let bounds = range.borrow_ref()?.try_bounds(vec.len())?;
+            Ok(bounds.map(|(start, end)| Value::vec(vec[start..end].to_vec())))
+        }
+        index => Err(unsupported_index_get(&index)?),
+    }
+}
+
+/// Get a new vector with the elements in the given range, erroring if it's out
+/// of bounds.
+///
+/// Integer indexes are handled directly by the virtual machine.
+fn vec_index_get(vec: &Vec, index: Value) -> Result<Value, VmError> {
+    match index {
+        Value::Range(range) => {
+            let (start, end) = range.borrow_ref()?.bounds(vec.len())?;
+            Ok(Value::vec(vec[start..end].to_vec()))
+        }
+        index => Err(unsupported_index_get(&index)?),
+    }
+}
+
+/// Construct the error raised when indexing a vector with an unsupported key.
+fn unsupported_index_get(index: &Value) -> Result<VmError, VmError> {
+    use crate::TypeOf as _;
+
+    Ok(VmError::from(VmErrorKind::UnsupportedIndexGet {
+        target: Vec::type_info(),
+        index: index.type_info()?,
+    }))
+}
+
+/// Eagerly map every element in the vector into a new vector.
+fn vec_map(vec: &Vec, f: &Function) -> Result<Vec, VmError> {
+    let mut output = Vec::with_capacity(vec.len());
+
+    for value in vec {
+        output.push(f.call::<_, Value>((value.clone(),))?);
+    }
+
+    Ok(output)
+}
+
+/// Eagerly construct a new vector out of the elements matching the given
+/// predicate.
+fn vec_filter(vec: &Vec, f: &Function) -> Result<Vec, VmError> {
+    let mut output = Vec::new();
+
+    for value in vec {
+        if f.call::<_, bool>((value.clone(),))? {
+            output.push(value.clone());
+        }
+    }
+
+    Ok(output)
+}
+
+/// Find the first element matching the given predicate.
+fn vec_find(vec: &Vec, f: &Function) -> Result<Option<Value>, VmError> {
+    Ok(vec_find_index(vec, f)?.map(|index| vec[index].clone()))
+}
+
+/// Find the index of the first element matching the given predicate.
+///
+/// This is also available as `position`, like in Rust.
+fn vec_find_index(vec: &Vec, f: &Function) -> Result<Option<usize>, VmError> {
+    for (index, value) in vec.iter().enumerate() {
+        if f.call::<_, bool>((value.clone(),))? {
+            return Ok(Some(index));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Test if every element matches the given predicate, stopping at the first
+/// one which doesn't.
+///
+/// An empty vector returns `true`.
+fn vec_all(vec: &Vec, f: &Function) -> Result<bool, VmError> {
+    for value in vec.iter() {
+        if !f.call::<_, bool>((value.clone(),))? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Test if any element matches the given predicate, stopping at the first
+/// one which does.
+///
+/// An empty vector returns `false`.
+fn vec_any(vec: &Vec, f: &Function) -> Result<bool, VmError> {
+    Ok(vec_find_index(vec, f)?.is_some())
+}
+
+/// Construct a new vector with the elements of this one in reverse order.
+fn vec_reversed(vec: &Vec) -> Vec {
+    Vec::from(vec.iter().rev().cloned().collect::<std::vec::Vec<_>>())
+}
+
+/// Remove consecutive elements which map to the same key, keeping the first
+/// one of each run.
+///
+/// Keys are compared the same way as `==`. The key function is called once
+/// for every element, before any elements are removed.
+fn vec_dedup_by_key(vec: &mut Vec, f: &Function) -> Result<(), VmError> {
+    let mut keys = std::vec::Vec::with_capacity(vec.len());
+
+    for value in vec.iter() {
+        keys.push(f.call::<_, Value>((value.clone(),))?);
+    }
+
+    let len = crate::env::with(|context, unit| {
+        let mut vm = Vm::new(context.clone(), unit.clone());
+        let mut len = 0;
+
+        for index in 0..keys.len() {
+            if len > 0 && Value::value_ptr_eq(&mut vm, &keys[len - 1], &keys[index])? {
+                continue;
+            }
+
+            keys.swap(len, index);
+            vec.swap(len, index);
+            len += 1;
+        }
+
+        Ok(len)
+    })?;
+
+    vec.truncate(len);
+    Ok(())
+}
+
+/// Partition the vector into two new vectors, where the first one contains
+/// the elements matching the given predicate and the second one contains the
+/// rest.
+fn vec_partition(vec: &Vec, f: &Function) -> Result<(Vec, Vec), VmError> {
+    let mut matching = Vec::new();
+    let mut rest = Vec::new();
+
+    for value in vec {
+        if f.call::<_, bool>((value.clone(),))? {
+            matching.push(value.clone());
+        } else {
+            rest.push(value.clone());
+        }
+    }
+
+    Ok((matching, rest))
+}
+
+/// Split the vector into runs of consecutive elements, where the closure
+/// returns `true` for every pair of neighbours within a run.
+fn vec_group_by(vec: &Vec, f: &Function) -> Result<Vec, VmError> {
+    let mut groups = Vec::new();
+    let mut it = vec.iter();
+
+    let mut group = match it.next() {
+        Some(value) => vec![value.clone()],
+        None => return Ok(groups),
+    };
+
+    for value in it {
+        let last = group.last().expect("groups are never empty");
+
+        if !f.call::<_, bool>((last.clone(), value.clone()))? {
+            groups.push(Value::from(Vec::from(std::mem::take(&mut group))));
+        }
+
+        group.push(value.clone());
+    }
+
+    groups.push(Value::from(Vec::from(group)));
+    Ok(groups)
+}
+
+/// Transform every element of the vector in place, replacing it with the
+/// value returned by the closure.
+///
+/// The vector is exclusively borrowed for the duration of the call, so any
+/// attempt by the closure to access it results in an error.
+fn vec_for_each_mut(vec: &mut Vec, f: &Function) -> Result<(), VmError> {
+    for value in vec.iter_mut() {
+        *value = f.call::<_, Value>((value.clone(),))?;
+    }
+
+    Ok(())
+}
+
+/// Flatten a vector of vectors into a single vector.
+///
+/// Note that this only flattens one level, any vectors nested deeper than that
+/// are kept as they are.
+fn vec_flatten(vec: &Vec) -> Result<Vec, VmError> {
+    let mut output = Vec::new();
+
+    for value in vec {
+        match value {
+            Value::Vec(inner) => {
+                for value in &*inner.borrow_ref()? {
+                    output.push(value.clone());
+                }
+            }
+            actual => return Err(VmError::expected::<Vec>(actual.type_info()?)),
+        }
+    }
+
+    Ok(output)
+}
+
+/// Concatenate a vector of vectors into a single vector, like
+/// `Vec::concat([a, b])`.
+///
+/// This is the free function counterpart of `flatten`.
+fn vec_concat(vecs: &Vec) -> Result<Vec, VmError> {
+    vec_flatten(vecs)
+}
+
+/// Construct a new vector with clones of the elements of `a` followed by the
+/// elements of `b`, like `a + b` or `a.concat_with(b)`.
+///
+/// Neither of the vectors is modified. Unlike `Vec::concat`, which flattens a
+/// vector of vectors, this takes exactly two vectors.
+fn vec_add(a: &Vec, b: &Vec) -> Vec {
+    let mut output = std::vec::Vec::with_capacity(a.len() + b.len());
+    output.extend(a.iter().cloned());
+    output.extend(b.iter().cloned());
+    Vec::from(output)
+}
+
+/// Combine two vectors into a vector of pairs, like `std::vec::zip(a, b)`.
+///
+/// The result is as long as the shorter of the two vectors, and the elements
+/// are cloned into it.
+fn vec_zip(a: &Vec, b: &Vec) -> std::vec::Vec<(Value, Value)> {
+    a.iter().cloned().zip(b.iter().cloned()).collect()
+}
+
+/// Sum all the elements in the vector using the `ADD` protocol.
+///
+/// Since there's no zero value which works for every type, summing an empty
+/// vector returns `None`. Integer overflow is handled according to the
+/// [OverflowPolicy] of the context.
+fn vec_sum(vec: &Vec) -> Result<Option<Value>, VmError> {
+    let mut it = vec.iter();
+
+    let mut sum = match it.next() {
+        Some(value) => value.clone(),
+        None => return Ok(None),
+    };
+
+    let policy = crate::env::overflow_policy();
+
+    for value in it {
+        sum = match (sum, value) {
+            (Value::Integer(a), Value::Integer(b)) => match policy {
+                OverflowPolicy::Wrapping => Value::from(a.wrapping_add(*b)),
+                OverflowPolicy::Checked => match a.checked_add(*b) {
+                    Some(n) => Value::from(n),
+                    None => return Err(VmError::from(VmErrorKind::Overflow)),
+                },
+                OverflowPolicy::Panicking => match a.checked_add(*b) {
+                    Some(n) => Value::from(n),
+                    None => return Err(VmError::panic("attempt to add with overflow")),
+                },
+            },
+            (Value::Float(a), Value::Float(b)) => Value::from(a + b),
+            (a, b) => call_binary_protocol(Protocol::ADD, a, b.clone())?,
+        };
+    }
+
+    Ok(Some(sum))
+}
+
+/// Get the smallest element in the vector using the `PARTIAL_CMP` protocol.
+///
+/// If several elements are equally small, the first one is returned.
+fn vec_min(vec: &Vec) -> Result<Option<Value>, VmError> {
+    vec_extreme(vec, cmp::Ordering::Less)
+}
+
+/// Get the largest element in the vector using the `PARTIAL_CMP` protocol.
+///
+/// If several elements are equally large, the first one is returned.
+fn vec_max(vec: &Vec) -> Result<Option<Value>, VmError> {
+    vec_extreme(vec, cmp::Ordering::Greater)
+}
+
+/// Find the first element which no other element compares as `ordering`
+/// against.
+fn vec_extreme(vec: &Vec, ordering: cmp::Ordering) -> Result<Option<Value>, VmError> {
+    let mut it = vec.iter();
+
+    let mut current = match it.next() {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    for value in it {
+        if partial_cmp(value, current)? == ordering {
+            current = value;
+        }
+    }
+
+    Ok(Some(current.clone()))
+}
+
+/// Get the element in the vector with the smallest key, as computed by the
+/// given function and compared using the `PARTIAL_CMP` protocol.
+///
+/// If several elements have equally small keys, the first one is returned.
+fn vec_min_by_key(vec: &Vec, f: &Function) -> Result<Option<Value>, VmError> {
+    vec_extreme_by_key(vec, f, cmp::Ordering::Less)
+}
+
+/// Get the element in the vector with the largest key, as computed by the
+/// given function and compared using the `PARTIAL_CMP` protocol.
+///
+/// If several elements have equally large keys, the last one is returned.
+fn vec_max_by_key(vec: &Vec, f: &Function) -> Result<Option<Value>, VmError> {
+    vec_extreme_by_key(vec, f, cmp::Ordering::Greater)
+}
+
+/// Find the element whose key no other key compares as `ordering` against.
+///
+/// Ties are resolved like they are in Rust, where the first element is kept
+/// when looking for the smallest key and the last one when looking for the
+/// largest.
+fn vec_extreme_by_key(
+    vec: &Vec,
+    f: &Function,
+    ordering: cmp::Ordering,
+) -> Result<Option<Value>, VmError> {
+    let mut it = vec.iter();
+
+    let (mut current, mut current_key) = match it.next() {
+        Some(value) => (value, f.call::<_, Value>((value.clone(),))?),
+        None => return Ok(None),
+    };
+
+    for value in it {
+        let key = f.call::<_, Value>((value.clone(),))?;
+
+        let replace = match partial_cmp(&key, &current_key)? {
+            cmp::Ordering::Equal => ordering == cmp::Ordering::Greater,
+            o => o == ordering,
+        };
+
+        if replace {
+            current = value;
+            current_key = key;
+        }
+    }
+
+    Ok(Some(current.clone()))
+}
+
+/// Binary search a sorted vector for the given value, using the `PARTIAL_CMP`
+/// protocol.
+///
+/// Returns `Ok` with the index of a matching element if one is found,
+/// otherwise `Err` with the index where the value could be inserted while
+/// keeping the vector sorted. If there are several matching elements, any one
+/// of them may be returned.
+fn vec_binary_search(vec: &Vec, value: Value) -> Result<Result<usize, usize>, VmError> {
+    let mut low = 0;
+    let mut high = vec.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+
+        match partial_cmp(&vec[mid], &value)? {
+            cmp::Ordering::Less => low = mid + 1,
+            cmp::Ordering::Greater => high = mid,
+            cmp::Ordering::Equal => return Ok(Ok(mid)),
+        }
+    }
+
+    Ok(Err(low))
+}
+
+/// Test if the vector starts with the elements of `prefix`, comparing them the
+/// same way as `==`.
+fn vec_starts_with(vec: &Vec, prefix: &Vec) -> Result<bool, VmError> {
+    if prefix.len() > vec.len() {
+        return Ok(false);
+    }
+
+    elements_eq(&vec[..prefix.len()], prefix)
+}
+
+/// Test if the vector ends with the elements of `suffix`, comparing them the
+/// same way as `==`.
+fn vec_ends_with(vec: &Vec, suffix: &Vec) -> Result<bool, VmError> {
+    if suffix.len() > vec.len() {
+        return Ok(false);
+    }
+
+    elements_eq(&vec[vec.len() - suffix.len()..], suffix)
+}
+
+/// Compare two equally long sequences of values element-wise, using the `EQ`
+/// protocol for values which need it.
+fn elements_eq(a: &[Value], b: &[Value]) -> Result<bool, VmError> {
+    crate::env::with(|context, unit| {
+        let mut vm = Vm::new(context.clone(), unit.clone());
+
+        for (a, b) in a.iter().zip(b.iter()) {
+            if !Value::value_ptr_eq(&mut vm, a, b)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    })
+}
+
+/// Compare two vectors lexicographically, using the `PARTIAL_CMP` protocol for
+/// their elements.
+///
+/// Equality doesn't need a protocol since vectors are already compared
+/// element-wise by `==`.
+fn vec_partial_cmp(a: &Vec, b: &Vec) -> Result<Option<cmp::Ordering>, VmError> {
+    for (a, b) in a.iter().zip(b.iter()) {
+        match partial_cmp(a, b)? {
+            cmp::Ordering::Equal => (),
+            ordering => return Ok(Some(ordering)),
+        }
+    }
+
+    Ok(Some(a.len().cmp(&b.len())))
+}
+
+/// Compare two values, erroring if they are incomparable.
+fn partial_cmp(a: &Value, b: &Value) -> Result<cmp::Ordering, VmError> {
+    let ordering = match (a, b) {
+        (Value::Integer(a), Value::Integer(b)) => Some(a.cmp(b)),
+        (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+        (a, b) => {
+            let value = call_binary_protocol(Protocol::PARTIAL_CMP, a.clone(), b.clone())?;
+            Option::<cmp::Ordering>::from_value(value)?
+        }
+    };
+
+    match ordering {
+        Some(ordering) => Ok(ordering),
+        None => Err(VmError::from(VmErrorKind::Incomparable {
+            lhs: a.type_info()?,
+            rhs: b.type_info()?,
+        })),
+    }
+}
+
+/// Call the given binary protocol, reporting an unsupported operation if it
+/// isn't implemented for the left-hand side.
+fn call_binary_protocol(protocol: Protocol, lhs: Value, rhs: Value) -> Result<Value, VmError> {
+    let hash = Hash::instance_function(lhs.type_hash()?, protocol);
+
+    let error = match EnvProtocolCaller.call_protocol_fn(protocol, lhs.clone(), (rhs.clone(),)) {
+        Ok(value) => return Ok(value),
+        Err(error) => error,
+    };
+
+    match error.kind() {
+        VmErrorKind::MissingFunction { hash: missing } if *missing == hash => {
+            Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                op: protocol.name,
+                lhs: lhs.type_info()?,
+                rhs: rhs.type_info()?,
+            }))
+        }
+        _ => Err(error),
+    }
 }
 
 fn sort_by(vec: &mut Vec, comparator: &crate::Function) {
@@ -1,6 +1,6 @@
 use crate::collections::{btree_map, BTreeMap};
 use crate::{
-    FromValue, InstallWith, Item, Mut, Named, RawMut, RawRef, RawStr, Ref, ToValue,
+    FromValue, Hash, InstallWith, Item, Mut, Named, RawMut, RawRef, RawStr, Ref, ToValue,
     UnsafeFromValue, Value, Vm, VmError,
 };
 use std::borrow;
@@ -54,6 +54,13 @@ pub type Values<'a> = btree_map::Values<'a, String, Value>;
 
 /// Struct representing a dynamic anonymous object.
 ///
+/// Objects are iterated over in order of their keys, regardless of the order
+/// in which they were inserted. This makes the order deterministic, so
+/// serializing an object produces the same output every time. The tradeoff is
+/// that insertion order isn't preserved, and that lookups take logarithmic
+/// rather than constant time. Host functions which want to keep this ordering
+/// should accept a `BTreeMap<String, T>` rather than a `HashMap<String, T>`.
+///
 /// # Examples
 ///
 /// ```rust
@@ -191,25 +198,25 @@ impl Object {
         self.inner
     }
 
-    /// An iterator visiting all key-value pairs in arbitrary order.
+    /// An iterator visiting all key-value pairs in order of their keys.
     /// The iterator element type is `(&'a String, &'a Value)`.
     pub fn iter(&self) -> Iter<'_> {
         self.inner.iter()
     }
 
-    /// An iterator visiting all keys in arbitrary order.
+    /// An iterator visiting all keys in order of their keys.
     /// The iterator element type is `&'a String`.
     pub fn keys(&self) -> Keys<'_> {
         self.inner.keys()
     }
 
-    /// An iterator visiting all values in arbitrary order.
+    /// An iterator visiting all values in order of their keys.
     /// The iterator element type is `&'a Value`.
     pub fn values(&self) -> Values<'_> {
         self.inner.values()
     }
 
-    /// An iterator visiting all key-value pairs in arbitrary order,
+    /// An iterator visiting all key-value pairs in order of their keys,
     /// with mutable references to the values.
     /// The iterator element type is `(&'a String, &'a mut Value)`.
     pub fn iter_mut(&mut self) -> IterMut<'_> {
@@ -256,7 +263,7 @@ impl IntoIterator for Object {
     type IntoIter = IntoIter;
 
     /// Creates a consuming iterator, that is, one that moves each key-value
-    /// pair out of the object in arbitrary order. The object cannot be used
+    /// pair out of the object in order of their keys. The object cannot be used
     /// after calling this.
     fn into_iter(self) -> Self::IntoIter {
         self.inner.into_iter()
@@ -281,6 +288,10 @@ impl FromValue for Object {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_object()?.take()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::OBJECT_TYPE.hash)
+    }
 }
 
 impl FromValue for Mut<Object> {
@@ -289,6 +300,10 @@ impl FromValue for Mut<Object> {
         let object = object.into_mut()?;
         Ok(object)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::OBJECT_TYPE.hash)
+    }
 }
 
 impl FromValue for Ref<Object> {
@@ -297,6 +312,10 @@ impl FromValue for Ref<Object> {
         let object = object.into_ref()?;
         Ok(object)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::OBJECT_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &Object {
@@ -312,6 +331,10 @@ impl UnsafeFromValue for &Object {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::OBJECT_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &mut Object {
@@ -327,6 +350,10 @@ impl UnsafeFromValue for &mut Object {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::OBJECT_TYPE.hash)
+    }
 }
 
 impl Named for Object {
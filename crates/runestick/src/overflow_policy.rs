@@ -0,0 +1,22 @@
+/// How the virtual machine handles integer arithmetic that overflows.
+///
+/// This is consulted when adding, subtracting, or multiplying two integers,
+/// including through compound assignments like `a += b`. It's configured with
+/// [Context::set_overflow_policy][crate::Context::set_overflow_policy].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Raise a [VmErrorKind::Overflow][crate::VmErrorKind::Overflow] or
+    /// [VmErrorKind::Underflow][crate::VmErrorKind::Underflow] error.
+    Checked,
+    /// Wrap around at the boundary of the integer type.
+    Wrapping,
+    /// Panic with a message indicating which operation overflowed, like
+    /// `attempt to add with overflow`.
+    Panicking,
+}
+
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        Self::Checked
+    }
+}
@@ -211,6 +211,12 @@ impl Protocol {
         hash: Hash::new(0x61ff7c46ff00e74a),
     };
 
+    /// The function to implement for the bitwise not operation, `!value`.
+    pub const BIT_NOT: Protocol = Protocol {
+        name: "!",
+        hash: Hash::new(0x2c3f8f6e1b9d4a73),
+    };
+
     /// Protocol function used by template strings.
     pub const STRING_DISPLAY: Protocol = Protocol {
         name: "string_display",
@@ -224,12 +230,47 @@ impl Protocol {
     };
 
     /// Function used to convert an argument into an iterator.
+    ///
+    /// Types which implement [Protocol::NEXT] but not this protocol are
+    /// treated as their own iterators.
     pub const INTO_ITER: Protocol = Protocol {
         name: "into_iter",
         hash: Hash::new(0x15a85c8d774b4065),
     };
 
     /// The function to call to continue iteration.
+    ///
+    /// It's called with the iterator as its only argument and returns an
+    /// `Option`, where `None` signals that the iteration is done. This is what
+    /// drives `for` loops.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Any, Module, Protocol};
+    ///
+    /// #[derive(Any)]
+    /// struct Countdown {
+    ///     n: i64,
+    /// }
+    ///
+    /// impl Countdown {
+    ///     fn next(&mut self) -> Option<i64> {
+    ///         if self.n == 0 {
+    ///             return None;
+    ///         }
+    ///
+    ///         self.n -= 1;
+    ///         Some(self.n)
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = Module::new();
+    /// module.ty::<Countdown>()?;
+    /// module.inst_fn(Protocol::NEXT, Countdown::next)?;
+    /// # Ok(()) }
+    /// ```
     pub const NEXT: Protocol = Protocol {
         name: "next",
         hash: Hash::new(0xc3cde069de2ba320),
@@ -241,6 +282,24 @@ impl Protocol {
         hash: Hash::new(0x596e6428deabfda2),
     };
 
+    /// The function to implement for cloning a value.
+    ///
+    /// This is used by `value.clone()` for types which don't have a `clone`
+    /// instance function of their own.
+    pub const CLONE: Protocol = Protocol {
+        name: "clone",
+        hash: Hash::new(0x1e22c5314b93ef82),
+    };
+
+    /// The function to implement for partially comparing two values.
+    ///
+    /// Should return an `Option<Ordering>`, where `None` indicates that the
+    /// values can't be compared.
+    pub const PARTIAL_CMP: Protocol = Protocol {
+        name: "partial_cmp",
+        hash: Hash::new(0x0ceff21c5446c207),
+    };
+
     /// Function used to convert an argument into a future.
     pub const INTO_TYPE_NAME: Protocol = Protocol {
         name: "into_type_name",
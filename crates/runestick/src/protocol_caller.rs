@@ -72,6 +72,7 @@ impl ProtocolCaller for EnvProtocolCaller {
                 return Err(VmError::from(VmErrorKind::BadArgumentCount {
                     actual: args,
                     expected,
+                    function: None,
                 }));
             }
 
@@ -1,6 +1,6 @@
 use crate::{
-    FromValue, InstallWith, Iterator, Mut, Named, Panic, RawMut, RawRef, RawStr, Ref, ToValue,
-    UnsafeFromValue, Value, Vm, VmError, VmErrorKind,
+    FromValue, Hash, InstallWith, Iterator, Mut, Named, Panic, RawMut, RawRef, RawStr, Ref,
+    ToValue, UnsafeFromValue, Value, Vm, VmError, VmErrorKind,
 };
 use std::fmt;
 use std::ops;
@@ -75,6 +75,60 @@ impl Range {
         Ok(true)
     }
 
+    /// Resolve the range into the half-open bounds `start..end` of a sequence
+    /// with the given length.
+    ///
+    /// Errors with [RangeOutOfBounds][VmErrorKind::RangeOutOfBounds] if the
+    /// range starts after it ends, or if it ends past the end of the sequence.
+    pub(crate) fn bounds(&self, len: usize) -> Result<(usize, usize), VmError> {
+        let (start, end) = self.resolve(len)?;
+
+        if start > end || end > len {
+            return Err(VmError::from(VmErrorKind::RangeOutOfBounds {
+                start: start.into(),
+                end: end.into(),
+                len: len.into(),
+            }));
+        }
+
+        Ok((start, end))
+    }
+
+    /// Resolve the range into the half-open bounds `start..end` of a sequence
+    /// with the given length, or `None` if they're out of bounds.
+    pub(crate) fn try_bounds(&self, len: usize) -> Result<Option<(usize, usize)>, VmError> {
+        let (start, end) = self.resolve(len)?;
+
+        if start > end || end > len {
+            return Ok(None);
+        }
+
+        Ok(Some((start, end)))
+    }
+
+    /// Resolve the range into unchecked half-open indexes, where an open end
+    /// is resolved to `len`.
+    fn resolve(&self, len: usize) -> Result<(usize, usize), VmError> {
+        let start = match self.start.clone() {
+            Some(value) => <usize as FromValue>::from_value(value)?,
+            None => 0,
+        };
+
+        let end = match self.end.clone() {
+            Some(value) => <usize as FromValue>::from_value(value)?,
+            None => return Ok((start, len)),
+        };
+
+        let end = match self.limits {
+            RangeLimits::HalfOpen => end,
+            RangeLimits::Closed => end
+                .checked_add(1)
+                .ok_or_else(|| VmError::from(VmErrorKind::Overflow))?,
+        };
+
+        Ok((start, end))
+    }
+
     /// Test if the current range contains the given integer.
     pub(crate) fn contains_int(&self, n: i64) -> Result<bool, VmError> {
         let start: Option<i64> = match self.start.clone() {
@@ -204,10 +258,66 @@ where
     }
 }
 
+/// Coercing a [Range] into `start..end`.
+///
+/// Errors with [VmErrorKind::UnsupportedRange] if the range isn't of that
+/// form.
+impl<Idx> FromValue for ops::Range<Idx>
+where
+    Idx: FromValue,
+{
+    fn from_value(value: Value) -> Result<Self, VmError> {
+        let (start, end) = from_bounded_range(value, RangeLimits::HalfOpen)?;
+        Ok(start..end)
+    }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::RANGE_TYPE.hash)
+    }
+}
+
+/// Coercing a [Range] into `start..=end`.
+///
+/// Errors with [VmErrorKind::UnsupportedRange] if the range isn't of that
+/// form.
+impl<Idx> FromValue for ops::RangeInclusive<Idx>
+where
+    Idx: FromValue,
+{
+    fn from_value(value: Value) -> Result<Self, VmError> {
+        let (start, end) = from_bounded_range(value, RangeLimits::Closed)?;
+        Ok(start..=end)
+    }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::RANGE_TYPE.hash)
+    }
+}
+
+/// Convert the value into the start and end of a range with both bounds and
+/// the given limits.
+fn from_bounded_range<Idx>(value: Value, limits: RangeLimits) -> Result<(Idx, Idx), VmError>
+where
+    Idx: FromValue,
+{
+    let range = value.into_range()?.take()?;
+
+    match (range.start, range.end) {
+        (Some(start), Some(end)) if range.limits == limits => {
+            Ok((Idx::from_value(start)?, Idx::from_value(end)?))
+        }
+        _ => Err(VmError::from(VmErrorKind::UnsupportedRange)),
+    }
+}
+
 impl FromValue for Range {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_range()?.take()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::RANGE_TYPE.hash)
+    }
 }
 
 impl FromValue for Mut<Range> {
@@ -216,6 +326,10 @@ impl FromValue for Mut<Range> {
         let object = object.into_mut()?;
         Ok(object)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::RANGE_TYPE.hash)
+    }
 }
 
 impl FromValue for Ref<Range> {
@@ -224,6 +338,10 @@ impl FromValue for Ref<Range> {
         let object = object.into_ref()?;
         Ok(object)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::RANGE_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &Range {
@@ -239,6 +357,10 @@ impl UnsafeFromValue for &Range {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::RANGE_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &mut Range {
@@ -254,6 +376,10 @@ impl UnsafeFromValue for &mut Range {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::RANGE_TYPE.hash)
+    }
 }
 
 impl Named for Range {
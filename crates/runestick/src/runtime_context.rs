@@ -1,6 +1,7 @@
 use crate::collections::HashMap;
 use crate::context::Handler;
-use crate::{ConstValue, Hash, Item, TypeCheck};
+use crate::module::ModuleConst;
+use crate::{ConstValue, Hash, Item, OverflowPolicy, TypeCheck, TypeInfo};
 use std::fmt;
 use std::sync::Arc;
 
@@ -18,8 +19,14 @@ pub struct RuntimeContext {
     /// Registered types.
     pub(crate) types: HashMap<Hash, TypeCheck>,
 
+    /// Type information of registered types, by type hash.
+    pub(crate) type_infos: HashMap<Hash, TypeInfo>,
+
     /// Named constant values
-    pub(crate) constants: HashMap<Hash, ConstValue>,
+    pub(crate) constants: HashMap<Hash, ModuleConst>,
+
+    /// How integer arithmetic that overflows is handled.
+    pub(crate) overflow_policy: OverflowPolicy,
 }
 
 impl RuntimeContext {
@@ -33,14 +40,27 @@ impl RuntimeContext {
         Some(*self.types.get(&Hash::type_hash(item))?)
     }
 
+    /// Lookup the type information of the registered type with the given
+    /// type hash.
+    pub fn type_info(&self, type_hash: Hash) -> Option<&TypeInfo> {
+        self.type_infos.get(&type_hash)
+    }
+
     /// Lookup the given native function handler in the context.
     pub fn lookup(&self, hash: Hash) -> Option<&Arc<Handler>> {
         self.functions.get(&hash)
     }
 
     /// Read a constant value from the unit.
+    ///
+    /// Lazy constants are evaluated the first time they're read.
     pub fn constant(&self, hash: Hash) -> Option<&ConstValue> {
-        self.constants.get(&hash)
+        Some(self.constants.get(&hash)?.get())
+    }
+
+    /// Get the policy for integer arithmetic that overflows.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
     }
 }
 
@@ -31,6 +31,13 @@ impl<T> Shared<T> {
         }
     }
 
+    /// Test if this is the only reference to the shared value.
+    pub(crate) fn is_unique(&self) -> bool {
+        // Safety: Since we have a reference to this shared, we know that the
+        // inner is available.
+        unsafe { self.inner.as_ref().count.get() == 1 }
+    }
+
     /// Return a debug formatter, that when printed will display detailed
     /// diagnostics of this shared type.
     pub fn debug(&self) -> SharedDebug<'_, T> {
@@ -1,4 +1,4 @@
-use crate::{InstAddress, Value};
+use crate::{FromValue, InstAddress, Value, VmError, VmErrorKind};
 use std::borrow::Cow;
 use std::iter;
 use std::mem;
@@ -129,6 +129,61 @@ impl Stack {
         self.stack.pop().ok_or_else(|| StackError(()))
     }
 
+    /// Pop the argument at `index` out of a call with `total` arguments and
+    /// convert it into `T`.
+    ///
+    /// Arguments are pushed in order, so they have to be popped in reverse,
+    /// starting with the argument at index `total - 1`. If the conversion
+    /// fails the error is reported as a bad argument at the given index, the
+    /// same way it is for natively registered functions.
+    ///
+    /// This is the recommended way to access arguments in a
+    /// [raw_fn][crate::Module::raw_fn].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Stack, VmError};
+    ///
+    /// fn add(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    ///     let b = stack.pop_arg::<i64>(1, args)?;
+    ///     let a = stack.pop_arg::<i64>(0, args)?;
+    ///     stack.push(a + b);
+    ///     Ok(())
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut stack = Stack::new();
+    /// stack.push(1i64);
+    /// stack.push(2i64);
+    /// add(&mut stack, 2)?;
+    /// assert_eq!(stack.pop()?.into_integer()?, 3);
+    /// # Ok(()) }
+    /// ```
+    pub fn pop_arg<T>(&mut self, index: usize, total: usize) -> Result<T, VmError>
+    where
+        T: FromValue,
+    {
+        if index >= total {
+            return Err(VmError::from(VmErrorKind::BadArgumentCount {
+                actual: total,
+                expected: index + 1,
+                function: None,
+            }));
+        }
+
+        let value = self.pop()?;
+
+        match T::from_value(value) {
+            Ok(value) => Ok(value),
+            Err(e) => Err(VmError::from(VmErrorKind::BadArgument {
+                error: e.unpack_critical()?,
+                arg: index,
+                function: None,
+            })),
+        }
+    }
+
     /// Address a value on the stack.
     pub fn address(&mut self, address: InstAddress) -> Result<Value, StackError> {
         Ok(match address {
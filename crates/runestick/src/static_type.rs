@@ -111,6 +111,7 @@ pub static VEC_TYPE: &StaticType = &StaticType {
 };
 
 impl_static_type!(crate::Vec => VEC_TYPE);
+impl_static_type!(crate::Shared<crate::Vec> => VEC_TYPE);
 impl_static_type!(impl<T> vec::Vec<T> => VEC_TYPE);
 impl_static_type!([crate::Value] => VEC_TYPE);
 impl_static_type!(impl<T> crate::VecTuple<T> => VEC_TYPE);
@@ -1,22 +1,76 @@
 use crate::{
-    FromValue, GeneratorState, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared,
-    UnsafeFromValue, Value, Vm, VmError, VmErrorKind, VmExecution,
+    FromValue, GeneratorState, Hash, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared,
+    ToValue, UnsafeFromValue, Value, Vm, VmError, VmErrorKind, VmExecution,
 };
+use futures_util::stream::StreamExt as _;
 use std::fmt;
 use std::mem;
+use std::pin::Pin;
 
-/// A stream with a stored virtual machine.
+/// dyn stream alias.
+type DynStream = dyn futures_core::Stream<Item = Result<Value, VmError>> + 'static;
+
+/// A stream which is either driven by a virtual machine or by a native stream.
 pub struct Stream {
-    execution: Option<VmExecution>,
-    first: bool,
+    stream: StreamRepr,
+}
+
+enum StreamRepr {
+    Vm {
+        execution: Option<VmExecution>,
+        first: bool,
+    },
+    Native(Option<Pin<Box<DynStream>>>),
 }
 
 impl Stream {
     /// Construct a stream from a virtual machine.
     pub(crate) fn new(vm: Vm) -> Self {
         Self {
-            execution: Some(VmExecution::new(vm)),
-            first: true,
+            stream: StreamRepr::Vm {
+                execution: Some(VmExecution::new(vm)),
+                first: true,
+            },
+        }
+    }
+
+    /// Construct a stream from a native stream.
+    ///
+    /// Items are pulled from the native stream as they're requested, and it
+    /// is polled by the same executor which drives the virtual machine, just
+    /// like a [Future][crate::Future].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{Module, Stream, VmError};
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = Module::new();
+    ///
+    /// module.function(&["numbers"], || {
+    ///     let numbers = (1..=3i64).map(Ok::<_, VmError>);
+    ///     Stream::from_stream(futures_util::stream::iter(numbers))
+    /// })?;
+    /// # Ok(()) }
+    /// ```
+    pub fn from_stream<T, O>(stream: T) -> Self
+    where
+        T: 'static + futures_core::Stream<Item = Result<O, VmError>>,
+        O: ToValue,
+    {
+        let stream = stream.map(|value| value?.to_value());
+
+        Self {
+            stream: StreamRepr::Native(Some(Box::pin(stream))),
+        }
+    }
+
+    /// Check if the stream has completed.
+    pub fn is_completed(&self) -> bool {
+        match &self.stream {
+            StreamRepr::Vm { execution, .. } => execution.is_none(),
+            StreamRepr::Native(stream) => stream.is_none(),
         }
     }
 
@@ -29,30 +83,53 @@ impl Stream {
     }
 
     /// Get the next value produced by this stream.
+    ///
+    /// Native streams don't accept values, so for them the given value is
+    /// ignored.
     pub async fn resume(&mut self, value: Value) -> Result<GeneratorState, VmError> {
-        let execution = self
-            .execution
-            .as_mut()
-            .ok_or_else(|| VmErrorKind::GeneratorComplete)?;
-
-        if !mem::take(&mut self.first) {
-            execution.vm_mut()?.stack_mut().push(value);
+        match &mut self.stream {
+            StreamRepr::Vm { execution, first } => {
+                let state = {
+                    let execution = execution
+                        .as_mut()
+                        .ok_or_else(|| VmErrorKind::GeneratorComplete)?;
+
+                    if !mem::take(first) {
+                        execution.vm_mut()?.stack_mut().push(value);
+                    }
+
+                    execution.async_resume().await?
+                };
+
+                if state.is_complete() {
+                    *execution = None;
+                }
+
+                Ok(state)
+            }
+            StreamRepr::Native(stream) => {
+                let next = stream
+                    .as_mut()
+                    .ok_or_else(|| VmErrorKind::GeneratorComplete)?
+                    .next()
+                    .await;
+
+                Ok(match next {
+                    Some(value) => GeneratorState::Yielded(value?),
+                    None => {
+                        *stream = None;
+                        GeneratorState::Complete(Value::Unit)
+                    }
+                })
+            }
         }
-
-        let state = execution.async_resume().await?;
-
-        if state.is_complete() {
-            self.execution = None;
-        }
-
-        Ok(state)
     }
 }
 
 impl fmt::Debug for Stream {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Stream")
-            .field("completed", &self.execution.is_none())
+            .field("completed", &self.is_completed())
             .finish()
     }
 }
@@ -61,6 +138,10 @@ impl FromValue for Shared<Stream> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_stream()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STREAM_TYPE.hash)
+    }
 }
 
 impl FromValue for Stream {
@@ -68,6 +149,10 @@ impl FromValue for Stream {
         let stream = value.into_stream()?;
         Ok(stream.take()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STREAM_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &Stream {
@@ -83,6 +168,10 @@ impl UnsafeFromValue for &Stream {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STREAM_TYPE.hash)
+    }
 }
 
 impl UnsafeFromValue for &mut Stream {
@@ -97,6 +186,10 @@ impl UnsafeFromValue for &mut Stream {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STREAM_TYPE.hash)
+    }
 }
 
 impl Named for Stream {
@@ -0,0 +1,142 @@
+//! A timer shared by everything in the virtual machine which needs to be woken
+//! up at a deadline.
+//!
+//! Since the virtual machine isn't tied to any particular runtime, deadlines
+//! are driven by a single helper thread which is spawned the first time a
+//! deadline is registered.
+
+use crate::collections::HashMap;
+use once_cell::sync::OnceCell;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::task::Waker;
+use std::thread;
+use std::time::Instant;
+
+/// A deadline registered with the shared timer.
+///
+/// The deadline is cancelled when this is dropped.
+pub(crate) struct Deadline {
+    id: u64,
+}
+
+impl Deadline {
+    /// Register a deadline at the given instant, which wakes up the given
+    /// waker once it has passed.
+    pub(crate) fn new(at: Instant, waker: &Waker) -> Self {
+        let timer = timer();
+        let mut state = timer.lock();
+
+        let id = state.next_id;
+        state.next_id += 1;
+        state.deadlines.push(Reverse((at, id)));
+        state.wakers.insert(id, waker.clone());
+
+        drop(state);
+        timer.condvar.notify_one();
+        Self { id }
+    }
+
+    /// Update the waker to wake up once the deadline has passed.
+    pub(crate) fn update(&self, waker: &Waker) {
+        let mut state = timer().lock();
+
+        if let Some(existing) = state.wakers.get_mut(&self.id) {
+            if !existing.will_wake(waker) {
+                *existing = waker.clone();
+            }
+        }
+    }
+}
+
+impl Drop for Deadline {
+    fn drop(&mut self) {
+        let mut state = timer().lock();
+        state.wakers.remove(&self.id);
+
+        // Entries of cancelled deadlines are otherwise only removed from the
+        // heap once they've passed.
+        if state.wakers.is_empty() {
+            state.deadlines.clear();
+        }
+    }
+}
+
+struct Timer {
+    state: Mutex<State>,
+    condvar: Condvar,
+}
+
+impl Timer {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().expect("timer state poisoned")
+    }
+
+    /// Wake up wakers as their deadlines pass.
+    fn run(&self) {
+        let mut state = self.lock();
+        let mut expired = Vec::new();
+
+        loop {
+            let now = Instant::now();
+
+            while let Some(Reverse((at, id))) = state.deadlines.peek().copied() {
+                if at > now {
+                    break;
+                }
+
+                state.deadlines.pop();
+                expired.extend(state.wakers.remove(&id));
+            }
+
+            if !expired.is_empty() {
+                // Wakers are called without holding the lock, since waking
+                // might register or cancel deadlines.
+                drop(state);
+
+                for waker in expired.drain(..) {
+                    waker.wake();
+                }
+
+                state = self.lock();
+                continue;
+            }
+
+            state = match state.deadlines.peek() {
+                Some(Reverse((at, _))) => {
+                    let timeout = *at - now;
+                    self.condvar
+                        .wait_timeout(state, timeout)
+                        .expect("timer state poisoned")
+                        .0
+                }
+                None => self.condvar.wait(state).expect("timer state poisoned"),
+            };
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    deadlines: BinaryHeap<Reverse<(Instant, u64)>>,
+    wakers: HashMap<u64, Waker>,
+}
+
+/// Get the shared timer, spawning its thread if it isn't running yet.
+fn timer() -> &'static Timer {
+    static TIMER: OnceCell<Timer> = OnceCell::new();
+
+    TIMER.get_or_init(|| {
+        thread::Builder::new()
+            .name(String::from("runestick-timer"))
+            .spawn(|| timer().run())
+            .expect("failed to spawn timer thread");
+
+        Timer {
+            state: Mutex::new(State::default()),
+            condvar: Condvar::new(),
+        }
+    })
+}
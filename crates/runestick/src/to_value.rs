@@ -1,9 +1,20 @@
-use crate::{Any, AnyObj, Panic, Shared, Value, VmError, VmErrorKind};
+use crate::{Any, AnyObj, Hash, Panic, Shared, Value, VmError, VmErrorKind};
+use std::borrow::Cow;
+use std::path::PathBuf;
 
 /// Trait for converting types into values.
 pub trait ToValue: Sized {
     /// Convert into a value.
     fn to_value(self) -> Result<Value, VmError>;
+
+    /// The type hash of the values this type is converted into, if it's
+    /// statically known.
+    ///
+    /// This is used by [Context::validate][crate::Context::validate] to check
+    /// that the types returned by native functions are registered.
+    fn type_hint() -> Option<Hash> {
+        None
+    }
 }
 
 /// Trait for converting types into values.
@@ -27,6 +38,10 @@ where
     fn to_value(self) -> Result<Value, VmError> {
         Ok(Value::from(AnyObj::new(self)))
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(T::type_hash())
+    }
 }
 
 impl<T> UnsafeToValue for T
@@ -71,8 +86,45 @@ impl ToValue for Box<str> {
     }
 }
 
+/// A convenience conversion which copies the string into a newly allocated
+/// `String`. Prefer passing a `String` when one is already owned.
+impl ToValue for &str {
+    fn to_value(self) -> Result<Value, VmError> {
+        Ok(Value::from(Shared::new(self.to_owned())))
+    }
+}
+
+/// A convenience conversion which copies borrowed strings into a newly
+/// allocated `String`. Owned strings are moved without copying.
+impl ToValue for Cow<'_, str> {
+    fn to_value(self) -> Result<Value, VmError> {
+        Ok(Value::from(Shared::new(self.into_owned())))
+    }
+}
+
+// Path impls
+
+/// A path is converted into a string, which errors with
+/// [VmErrorKind::NonUtf8Path] if the path isn't valid UTF-8.
+impl ToValue for PathBuf {
+    fn to_value(self) -> Result<Value, VmError> {
+        match self.into_os_string().into_string() {
+            Ok(string) => Ok(Value::from(Shared::new(string))),
+            Err(path) => Err(VmError::from(VmErrorKind::NonUtf8Path {
+                path: PathBuf::from(path),
+            })),
+        }
+    }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::STRING_TYPE.hash)
+    }
+}
+
 // Result impls
 
+/// A `Result` with a [Panic] error isn't visible to the script. An error
+/// instead aborts the virtual machine with a [VmErrorKind::Panic].
 impl<T> ToValue for Result<T, Panic>
 where
     T: ToValue,
@@ -83,8 +135,14 @@ where
             Err(reason) => Err(VmError::from(VmErrorKind::Panic { reason })),
         }
     }
+
+    fn type_hint() -> Option<Hash> {
+        T::type_hint()
+    }
 }
 
+/// A `Result` with a [VmError] isn't visible to the script. An error instead
+/// aborts the virtual machine with the given error.
 impl<T> ToValue for Result<T, VmError>
 where
     T: ToValue,
@@ -95,8 +153,17 @@ where
             Err(error) => Err(error),
         }
     }
+
+    fn type_hint() -> Option<Hash> {
+        T::type_hint()
+    }
 }
 
+/// Any other `Result` is converted into a regular script `Result`, which can be
+/// matched on or propagated with the `?` operator.
+///
+/// Use this when the error is something the script is expected to inspect or
+/// handle, and a [VmError] when it should abort execution.
 impl<T, E> ToValue for Result<T, E>
 where
     T: ToValue,
@@ -177,3 +244,4 @@ macro_rules! impl_map {
 }
 
 impl_map!(std::collections::HashMap<String, T>);
+impl_map!(std::collections::BTreeMap<String, T>);
@@ -1,4 +1,4 @@
-use crate::{ConstValue, FromValue, Mut, Ref, Value, Vm, VmError};
+use crate::{ConstValue, FromValue, Hash, Mut, Ref, Value, Vm, VmError};
 use std::fmt;
 use std::ops;
 use std::slice;
@@ -156,12 +156,20 @@ impl FromValue for Mut<Tuple> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_tuple()?.into_mut()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::TUPLE_TYPE.hash)
+    }
 }
 
 impl FromValue for Ref<Tuple> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_tuple()?.into_ref()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::TUPLE_TYPE.hash)
+    }
 }
 
 impl FromValue for Tuple {
@@ -172,6 +180,10 @@ impl FromValue for Tuple {
             actual => Err(VmError::expected::<Self>(actual.type_info()?)),
         }
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::TUPLE_TYPE.hash)
+    }
 }
 
 macro_rules! impl_tuple {
@@ -190,7 +202,11 @@ macro_rules! impl_tuple {
             $($ty: $crate::FromValue,)*
         {
             fn from_value(value: $crate::Value) -> Result<Self, $crate::VmError> {
-                let tuple = value.into_tuple()?.take()?;
+                // NB: the tuple is borrowed rather than taken, since it might
+                // still be used by the script after it's been passed to a
+                // native function.
+                let tuple = value.into_tuple()?;
+                let tuple = tuple.borrow_ref()?;
 
                 if tuple.len() != $count {
                     return Err($crate::VmError::from($crate::VmErrorKind::ExpectedTupleLength {
@@ -200,7 +216,7 @@ macro_rules! impl_tuple {
                 }
 
                 #[allow(unused_mut, unused_variables)]
-                let mut it = Vec::from(tuple.into_inner()).into_iter();
+                let mut it = tuple.iter().cloned();
 
                 $(
                     let $var = match it.next() {
@@ -213,6 +229,10 @@ macro_rules! impl_tuple {
 
                 Ok(($($var,)*))
             }
+
+            fn type_hint() -> Option<$crate::Hash> {
+                Some($crate::TUPLE_TYPE.hash)
+            }
         }
 
         impl <$($ty,)*> $crate::ToValue for ($($ty,)*)
@@ -926,7 +926,14 @@ impl Value {
             Self::TupleStruct(tuple) => tuple.borrow_ref()?.type_info(),
             Self::Struct(object) => object.borrow_ref()?.type_info(),
             Self::Variant(empty) => empty.borrow_ref()?.type_info(),
-            Self::Any(any) => TypeInfo::Any(any.borrow_ref()?.type_name()),
+            Self::Any(any) => {
+                let any = any.borrow_ref()?;
+
+                match crate::env::type_info(any.type_hash()) {
+                    Some(type_info) => type_info,
+                    None => TypeInfo::Any(any.type_name()),
+                }
+            }
         })
     }
 
@@ -1161,6 +1168,7 @@ where
 }
 
 impl From<()> for Value {
+    #[inline]
     fn from((): ()) -> Self {
         Self::Unit
     }
@@ -1173,6 +1181,7 @@ impl crate::ToValue for Value {
 }
 
 impl crate::ToValue for () {
+    #[inline]
     fn to_value(self) -> Result<Value, VmError> {
         Ok(Value::from(()))
     }
@@ -1,8 +1,10 @@
 use crate::{
-    FromValue, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared, ToValue,
-    UnsafeFromValue, Value, Vm, VmError,
+    FromValue, Hash, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared, ToValue,
+    UnsafeFromValue, Value, Vm, VmError, VmErrorKind,
 };
 use std::cmp;
+use std::collections::TryReserveError;
+use std::convert::TryFrom as _;
 use std::fmt;
 use std::ops;
 use std::slice;
@@ -56,6 +58,28 @@ impl Vec {
         }
     }
 
+    /// Returns the number of elements the dynamic vector can hold without
+    /// reallocating.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Try to reserve capacity for at least `additional` more elements.
+    ///
+    /// Unlike [std::vec::Vec::reserve], this returns an error instead of
+    /// aborting if the capacity can't be allocated.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve(additional)
+    }
+
+    /// Try to reserve capacity for exactly `additional` more elements.
+    ///
+    /// Like [Vec::try_reserve], this returns an error instead of aborting if
+    /// the capacity can't be allocated.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.inner.try_reserve_exact(additional)
+    }
+
     /// Convert into inner std vector.
     pub fn into_inner(self) -> vec::Vec<Value> {
         self.inner
@@ -134,6 +158,25 @@ impl Vec {
         self.inner.remove(index);
     }
 
+    /// Removes the elements in the specified range from a dynamic vector,
+    /// returning an iterator over the removed elements.
+    pub fn drain<R>(&mut self, range: R) -> vec::Drain<'_, Value>
+    where
+        R: ops::RangeBounds<usize>,
+    {
+        self.inner.drain(range)
+    }
+
+    /// Replaces the elements in the specified range with the given values,
+    /// returning an iterator over the removed elements.
+    pub fn splice<R, I>(&mut self, range: R, values: I) -> vec::Splice<'_, I::IntoIter>
+    where
+        R: ops::RangeBounds<usize>,
+        I: IntoIterator<Item = Value>,
+    {
+        self.inner.splice(range, values)
+    }
+
     /// Clears the vector, removing all values.
     ///
     /// Note that this method has no effect on the allocated capacity of the
@@ -142,6 +185,15 @@ impl Vec {
         self.inner.clear();
     }
 
+    /// Shortens the vector, keeping the first `len` elements and dropping the
+    /// rest.
+    ///
+    /// If `len` is greater than the vector's current length, this has no
+    /// effect.
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
     /// Inserts an element at position index within the vector, shifting all
     /// elements after it to the right.
     pub fn insert(&mut self, index: usize, value: Value) {
@@ -248,22 +300,44 @@ impl From<Box<[Value]>> for Vec {
     }
 }
 
+impl FromValue for Shared<Vec> {
+    fn from_value(value: Value) -> Result<Self, VmError> {
+        value.into_vec()
+    }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::VEC_TYPE.hash)
+    }
+}
+
 impl FromValue for Mut<Vec> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_vec()?.into_mut()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::VEC_TYPE.hash)
+    }
 }
 
 impl FromValue for Ref<Vec> {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_vec()?.into_ref()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::VEC_TYPE.hash)
+    }
 }
 
 impl FromValue for Vec {
     fn from_value(value: Value) -> Result<Self, VmError> {
         Ok(value.into_vec()?.take()?)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::VEC_TYPE.hash)
+    }
 }
 
 impl<T> FromValue for vec::Vec<T>
@@ -271,7 +345,19 @@ where
     T: FromValue,
 {
     fn from_value(value: Value) -> Result<Self, VmError> {
-        let vec = value.into_vec()?;
+        let vec = match value {
+            // NB: bytes are converted one byte at a time, so that they can be
+            // passed to functions taking `Vec<u8>`.
+            Value::Bytes(bytes) => {
+                return bytes
+                    .borrow_ref()?
+                    .iter()
+                    .map(|b| T::from_value(Value::Byte(*b)))
+                    .collect();
+            }
+            value => value.into_vec()?,
+        };
+
         let vec = vec.take()?;
 
         let mut output = vec::Vec::with_capacity(vec.len());
@@ -282,6 +368,50 @@ where
 
         Ok(output)
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::VEC_TYPE.hash)
+    }
+}
+
+/// Arrays can be converted from both vectors and tuples, as long as they have
+/// exactly the expected number of elements.
+impl<T, const N: usize> FromValue for [T; N]
+where
+    T: FromValue,
+{
+    fn from_value(value: Value) -> Result<Self, VmError> {
+        let values = match value {
+            Value::Unit => vec::Vec::new(),
+            // NB: the tuple is borrowed rather than taken, since it might
+            // still be used by the script after it's been passed to a native
+            // function.
+            Value::Tuple(tuple) => tuple.borrow_ref()?.iter().cloned().collect(),
+            value => value.into_vec()?.take()?.into_inner(),
+        };
+
+        if values.len() != N {
+            return Err(VmError::from(VmErrorKind::ExpectedArrayLength {
+                actual: values.len(),
+                expected: N,
+            }));
+        }
+
+        let mut output = vec::Vec::with_capacity(N);
+
+        for value in values {
+            output.push(T::from_value(value)?);
+        }
+
+        match <[T; N]>::try_from(output) {
+            Ok(array) => Ok(array),
+            Err(..) => Err(VmError::from(VmErrorKind::IterationError)),
+        }
+    }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::VEC_TYPE.hash)
+    }
 }
 
 impl<'a> UnsafeFromValue for &'a [Value] {
@@ -299,6 +429,10 @@ impl<'a> UnsafeFromValue for &'a [Value] {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::VEC_TYPE.hash)
+    }
 }
 
 impl<'a> UnsafeFromValue for &'a Vec {
@@ -313,6 +447,10 @@ impl<'a> UnsafeFromValue for &'a Vec {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &*output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::VEC_TYPE.hash)
+    }
 }
 
 impl<'a> UnsafeFromValue for &'a mut Vec {
@@ -327,6 +465,25 @@ impl<'a> UnsafeFromValue for &'a mut Vec {
     unsafe fn unsafe_coerce(output: Self::Output) -> Self {
         &mut *output
     }
+
+    fn type_hint() -> Option<Hash> {
+        Some(crate::VEC_TYPE.hash)
+    }
+}
+
+impl<T, const N: usize> ToValue for [T; N]
+where
+    T: ToValue,
+{
+    fn to_value(self) -> Result<Value, VmError> {
+        let mut vec = vec::Vec::with_capacity(N);
+
+        for value in self {
+            vec.push(value.to_value()?);
+        }
+
+        Ok(Value::from(Shared::new(Vec::from(vec))))
+    }
 }
 
 impl<T> ToValue for vec::Vec<T>
@@ -58,6 +58,10 @@ macro_rules! impl_from_value_tuple_vec {
 
                 Ok(VecTuple(($($value,)*)))
             }
+
+            fn type_hint() -> Option<$crate::Hash> {
+                Some($crate::VEC_TYPE.hash)
+            }
         }
 
         impl<$($ty,)*> ToValue for VecTuple<($($ty,)*)>
@@ -1,14 +1,17 @@
+use crate::allocation;
 use crate::budget;
 use crate::future::SelectFuture;
 use crate::unit::UnitFn;
 use crate::{
     Args, Awaited, BorrowMut, Bytes, Call, Format, FormatSpec, FromValue, Function, Future,
     Generator, GuardedArgs, Hash, Inst, InstAddress, InstAssignOp, InstFnNameHash, InstOp,
-    InstRangeLimits, InstTarget, InstValue, InstVariant, IntoTypeHash, Object, Panic, Protocol,
-    Range, RangeLimits, RuntimeContext, Select, Shared, Stack, Stream, Struct, Tuple, TypeCheck,
-    Unit, UnitStruct, Value, Variant, VariantData, Vec, VmError, VmErrorKind, VmExecution, VmHalt,
-    VmIntegerRepr, VmSendExecution,
+    InstRangeLimits, InstTarget, InstValue, InstVariant, IntoTypeHash, Object, OverflowPolicy,
+    Panic, Protocol, Range, RangeLimits, RuntimeContext, Select, Shared, Stack, Stream, Struct,
+    Tuple, TypeCheck, Unit, UnitStruct, Value, Variant, VariantData, Vec, VmError, VmErrorKind,
+    VmExecution, VmHalt, VmIntegerRepr, VmSendExecution,
 };
+use std::cell::Cell;
+use std::cmp;
 use std::fmt;
 use std::mem;
 use std::sync::Arc;
@@ -58,6 +61,47 @@ macro_rules! target_value {
     }};
 }
 
+/// Select the error and integer operation to use for an arithmetic operation
+/// according to the given [OverflowPolicy].
+macro_rules! overflow_op {
+    ($policy:expr, $error:expr, $checked:path, $wrapping:path, $message:literal) => {{
+        let ops: (fn() -> VmErrorKind, fn(i64, i64) -> Option<i64>) = match $policy {
+            OverflowPolicy::Checked => (|| $error, $checked),
+            OverflowPolicy::Wrapping => (|| $error, |a, b| Some($wrapping(a, b))),
+            OverflowPolicy::Panicking => (
+                || VmErrorKind::Panic {
+                    reason: Panic::custom($message),
+                },
+                $checked,
+            ),
+        };
+
+        ops
+    }};
+}
+
+/// The default maximum depth of nested calls in a virtual machine.
+const DEFAULT_MAX_CALL_DEPTH: usize = 16384;
+
+thread_local! {
+    /// The call depth of the virtual machine which is calling a native function
+    /// on this thread, and the maximum call depth it allows.
+    ///
+    /// Native functions which call back into scripts do so through a new
+    /// virtual machine, which picks this up to count its calls on top of the
+    /// calls of the virtual machine calling it.
+    static OUTER_CALL_DEPTH: Cell<(usize, usize)> = const { Cell::new((0, usize::MAX)) };
+}
+
+/// Restores the outer call depth when a native function returns.
+struct CallDepthGuard((usize, usize));
+
+impl Drop for CallDepthGuard {
+    fn drop(&mut self) {
+        OUTER_CALL_DEPTH.with(|tls| tls.set(self.0));
+    }
+}
+
 /// A stack which references variables indirectly from a slab.
 #[derive(Debug, Clone)]
 pub struct Vm {
@@ -71,6 +115,11 @@ pub struct Vm {
     pub(crate) stack: Stack,
     /// Frames relative to the stack.
     call_frames: vec::Vec<CallFrame>,
+    /// The maximum number of call frames allowed before erroring.
+    max_call_depth: usize,
+    /// The call depth and maximum call depth of the virtual machine calling
+    /// this one through a native function, if any.
+    outer_call_depth: (usize, usize),
 }
 
 impl Vm {
@@ -91,6 +140,8 @@ impl Vm {
             ip: 0,
             stack,
             call_frames: vec::Vec::new(),
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            outer_call_depth: (0, usize::MAX),
         }
     }
 
@@ -119,6 +170,24 @@ impl Vm {
         self.ip = ip;
     }
 
+    /// Set the maximum depth of nested calls, after which calling another
+    /// function errors with [StackOverflow][VmErrorKind::StackOverflow]
+    /// instead of growing the stack further.
+    ///
+    /// This defaults to 16384. Calls made by scripts called back from native
+    /// functions count towards the depth of the virtual machine calling the
+    /// native function, and are limited by the lower of the two maximums.
+    #[inline]
+    pub fn set_max_call_depth(&mut self, max_call_depth: usize) {
+        self.max_call_depth = max_call_depth;
+    }
+
+    /// Get the maximum depth of nested calls.
+    #[inline]
+    pub fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+
     /// Get the stack.
     #[inline]
     pub fn call_frames(&self) -> &[CallFrame] {
@@ -418,7 +487,9 @@ impl Vm {
             }
         };
 
+        let _depth = self.enter_native_call();
         handler(&mut self.stack, count)?;
+        allocation::take_returned(self.stack.last()?)?;
         Ok(true)
     }
 
@@ -450,14 +521,22 @@ impl Vm {
             }
         };
 
+        let _depth = self.enter_native_call();
         handler(&mut self.stack, count)?;
+        allocation::take_returned(self.stack.last()?)?;
         Ok(true)
     }
 
+    /// Internal impl of a comparison operation.
+    ///
+    /// Values other than numbers are compared through their
+    /// [PARTIAL_CMP][Protocol::PARTIAL_CMP] protocol, where values which are
+    /// incomparable (`None`) compare as `false` like in Rust.
     fn internal_boolean_ops(
         &mut self,
         int_op: fn(i64, i64) -> bool,
         float_op: fn(f64, f64) -> bool,
+        ordering_op: fn(cmp::Ordering) -> bool,
         op: &'static str,
         lhs: InstAddress,
         rhs: InstAddress,
@@ -469,11 +548,20 @@ impl Vm {
             (Value::Integer(lhs), Value::Integer(rhs)) => int_op(lhs, rhs),
             (Value::Float(lhs), Value::Float(rhs)) => float_op(lhs, rhs),
             (lhs, rhs) => {
-                return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
-                    op,
-                    lhs: lhs.type_info()?,
-                    rhs: rhs.type_info()?,
-                }))
+                if !self.call_instance_fn(lhs.clone(), Protocol::PARTIAL_CMP, (&rhs,))? {
+                    return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                        op,
+                        lhs: lhs.type_info()?,
+                        rhs: rhs.type_info()?,
+                    }));
+                }
+
+                // NB: protocols can only be implemented natively, so the
+                // result is available as soon as the call returns.
+                match Option::<cmp::Ordering>::from_value(self.stack.pop()?)? {
+                    Some(ordering) => ordering_op(ordering),
+                    None => false,
+                }
             }
         };
 
@@ -481,11 +569,29 @@ impl Vm {
         Ok(())
     }
 
+    /// Make the call depth of this virtual machine visible to any virtual
+    /// machine run by a native function it's about to call, until the returned
+    /// guard is dropped.
+    #[inline]
+    fn enter_native_call(&self) -> CallDepthGuard {
+        let (outer_depth, outer_max) = self.outer_call_depth;
+        let depth = outer_depth + self.call_frames.len() + 1;
+        let max = cmp::min(self.max_call_depth, outer_max);
+        CallDepthGuard(OUTER_CALL_DEPTH.with(|tls| tls.replace((depth, max))))
+    }
+
     /// Push a new call frame.
     ///
     /// This will cause the `args` number of elements on the stack to be
     /// associated and accessible to the new call frame.
     pub(crate) fn push_call_frame(&mut self, ip: usize, args: usize) -> Result<(), VmError> {
+        let (outer_depth, outer_max) = self.outer_call_depth;
+        let max_call_depth = cmp::min(self.max_call_depth, outer_max);
+
+        if outer_depth + self.call_frames.len() >= max_call_depth {
+            return Err(VmError::from(VmErrorKind::StackOverflow { max_call_depth }));
+        }
+
         let stack_top = self.stack.swap_stack_bottom(args)?;
 
         self.call_frames.push(CallFrame {
@@ -1366,6 +1472,7 @@ impl Vm {
             return Err(VmError::from(VmErrorKind::BadArgumentCount {
                 actual: args,
                 expected,
+                function: None,
             }));
         }
 
@@ -1541,6 +1648,7 @@ impl Vm {
     /// Construct a new vec.
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_vec(&mut self, count: usize) -> Result<(), VmError> {
+        allocation::take_elements::<Value>(count)?;
         let vec = Vec::from(self.stack.pop_sequence(count)?);
         self.stack.push(Shared::new(vec));
         Ok(())
@@ -1549,6 +1657,7 @@ impl Vm {
     /// Construct a new tuple.
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_tuple(&mut self, count: usize) -> Result<(), VmError> {
+        allocation::take_elements::<Value>(count)?;
         let tuple = self.stack.pop_sequence(count)?;
         self.stack.push(Tuple::from(tuple));
         Ok(())
@@ -1557,6 +1666,7 @@ impl Vm {
     /// Construct a new tuple with a fixed number of arguments.
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_tuple_n(&mut self, args: &[InstAddress]) -> Result<(), VmError> {
+        allocation::take_elements::<Value>(args.len())?;
         let mut tuple = vec![Value::Unit; args.len()];
 
         for (n, arg) in args.iter().enumerate().rev() {
@@ -1583,6 +1693,10 @@ impl Vm {
             Value::Bool(value) => Value::from(!value),
             Value::Integer(value) => Value::from(!value),
             other => {
+                if self.call_instance_fn(other.clone(), Protocol::BIT_NOT, ())? {
+                    return Ok(());
+                }
+
                 let operand = other.type_info()?;
                 return Err(VmError::from(VmErrorKind::UnsupportedUnaryOperation {
                     op: "!",
@@ -1621,30 +1735,54 @@ impl Vm {
 
         match op {
             InstOp::Add => {
+                let (error, integer_op) = overflow_op!(
+                    self.context.overflow_policy(),
+                    VmErrorKind::Overflow,
+                    i64::checked_add,
+                    i64::wrapping_add,
+                    "attempt to add with overflow"
+                );
+
                 self.internal_num(
                     Protocol::ADD,
-                    || VmErrorKind::Overflow,
-                    i64::checked_add,
+                    error,
+                    integer_op,
                     std::ops::Add::add,
                     lhs,
                     rhs,
                 )?;
             }
             InstOp::Sub => {
+                let (error, integer_op) = overflow_op!(
+                    self.context.overflow_policy(),
+                    VmErrorKind::Underflow,
+                    i64::checked_sub,
+                    i64::wrapping_sub,
+                    "attempt to subtract with overflow"
+                );
+
                 self.internal_num(
                     Protocol::SUB,
-                    || VmErrorKind::Underflow,
-                    i64::checked_sub,
+                    error,
+                    integer_op,
                     std::ops::Sub::sub,
                     lhs,
                     rhs,
                 )?;
             }
             InstOp::Mul => {
+                let (error, integer_op) = overflow_op!(
+                    self.context.overflow_policy(),
+                    VmErrorKind::Overflow,
+                    i64::checked_mul,
+                    i64::wrapping_mul,
+                    "attempt to multiply with overflow"
+                );
+
                 self.internal_num(
                     Protocol::MUL,
-                    || VmErrorKind::Overflow,
-                    i64::checked_mul,
+                    error,
+                    integer_op,
                     std::ops::Mul::mul,
                     lhs,
                     rhs,
@@ -1713,16 +1851,44 @@ impl Vm {
                 self.internal_infallible_bitwise(Protocol::SHR, std::ops::Shr::shr, lhs, rhs)?;
             }
             InstOp::Gt => {
-                self.internal_boolean_ops(|a, b| a > b, |a, b| a > b, ">", lhs, rhs)?;
+                self.internal_boolean_ops(
+                    |a, b| a > b,
+                    |a, b| a > b,
+                    |o| o == cmp::Ordering::Greater,
+                    ">",
+                    lhs,
+                    rhs,
+                )?;
             }
             InstOp::Gte => {
-                self.internal_boolean_ops(|a, b| a >= b, |a, b| a >= b, ">=", lhs, rhs)?;
+                self.internal_boolean_ops(
+                    |a, b| a >= b,
+                    |a, b| a >= b,
+                    |o| o != cmp::Ordering::Less,
+                    ">=",
+                    lhs,
+                    rhs,
+                )?;
             }
             InstOp::Lt => {
-                self.internal_boolean_ops(|a, b| a < b, |a, b| a < b, "<", lhs, rhs)?;
+                self.internal_boolean_ops(
+                    |a, b| a < b,
+                    |a, b| a < b,
+                    |o| o == cmp::Ordering::Less,
+                    "<",
+                    lhs,
+                    rhs,
+                )?;
             }
             InstOp::Lte => {
-                self.internal_boolean_ops(|a, b| a <= b, |a, b| a <= b, "<=", lhs, rhs)?;
+                self.internal_boolean_ops(
+                    |a, b| a <= b,
+                    |a, b| a <= b,
+                    |o| o != cmp::Ordering::Greater,
+                    "<=",
+                    lhs,
+                    rhs,
+                )?;
             }
             InstOp::Eq => {
                 let rhs = self.stack.address(rhs)?;
@@ -1761,29 +1927,53 @@ impl Vm {
 
         match op {
             InstAssignOp::Add => {
+                let (error, integer_op) = overflow_op!(
+                    self.context.overflow_policy(),
+                    VmErrorKind::Overflow,
+                    i64::checked_add,
+                    i64::wrapping_add,
+                    "attempt to add with overflow"
+                );
+
                 self.internal_num_assign(
                     target,
                     Protocol::ADD_ASSIGN,
-                    || VmErrorKind::Overflow,
-                    i64::checked_add,
+                    error,
+                    integer_op,
                     std::ops::Add::add,
                 )?;
             }
             InstAssignOp::Sub => {
+                let (error, integer_op) = overflow_op!(
+                    self.context.overflow_policy(),
+                    VmErrorKind::Underflow,
+                    i64::checked_sub,
+                    i64::wrapping_sub,
+                    "attempt to subtract with overflow"
+                );
+
                 self.internal_num_assign(
                     target,
                     Protocol::SUB_ASSIGN,
-                    || VmErrorKind::Underflow,
-                    i64::checked_sub,
+                    error,
+                    integer_op,
                     std::ops::Sub::sub,
                 )?;
             }
             InstAssignOp::Mul => {
+                let (error, integer_op) = overflow_op!(
+                    self.context.overflow_policy(),
+                    VmErrorKind::Overflow,
+                    i64::checked_mul,
+                    i64::wrapping_mul,
+                    "attempt to multiply with overflow"
+                );
+
                 self.internal_num_assign(
                     target,
                     Protocol::MUL_ASSIGN,
-                    || VmErrorKind::Overflow,
-                    i64::checked_mul,
+                    error,
+                    integer_op,
                     std::ops::Mul::mul,
                 )?;
             }
@@ -2114,6 +2304,7 @@ impl Vm {
             .lookup_object_keys(slot)
             .ok_or_else(|| VmErrorKind::MissingStaticObjectKeys { slot })?;
 
+        allocation::take_elements::<(String, Value)>(keys.len())?;
         let mut object = Object::with_capacity(keys.len());
         let values = self.stack.drain_stack_top(keys.len())?;
 
@@ -2166,6 +2357,7 @@ impl Vm {
             .lookup_rtti(hash)
             .ok_or_else(|| VmErrorKind::MissingRtti { hash })?;
 
+        allocation::take_elements::<(String, Value)>(keys.len())?;
         let values = self.stack.drain_stack_top(keys.len())?;
         let mut data = Object::with_capacity(keys.len());
 
@@ -2206,6 +2398,7 @@ impl Vm {
             .lookup_variant_rtti(hash)
             .ok_or_else(|| VmErrorKind::MissingVariantRtti { hash })?;
 
+        allocation::take_elements::<(String, Value)>(keys.len())?;
         let mut data = Object::with_capacity(keys.len());
         let values = self.stack.drain_stack_top(keys.len())?;
 
@@ -2226,7 +2419,9 @@ impl Vm {
 
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_bytes(&mut self, slot: usize) -> Result<(), VmError> {
-        let bytes = self.unit.lookup_bytes(slot)?.to_owned();
+        let bytes = self.unit.lookup_bytes(slot)?;
+        allocation::take_bytes(bytes.len())?;
+        let bytes = bytes.to_owned();
         self.stack.push(Bytes::from_vec(bytes));
         Ok(())
     }
@@ -2245,6 +2440,7 @@ impl Vm {
             }
         }
 
+        allocation::take_bytes(out.capacity())?;
         self.stack.push(out);
         Ok(())
     }
@@ -2602,7 +2798,9 @@ impl Vm {
                     .lookup(hash)
                     .ok_or_else(|| VmErrorKind::MissingFunction { hash })?;
 
+                let _depth = self.enter_native_call();
                 handler(&mut self.stack, args)?;
+                allocation::take_returned(self.stack.last()?)?;
             }
         }
 
@@ -2644,23 +2842,78 @@ impl Vm {
                 }
             },
             None => {
-                let handler = match self.context.lookup(hash) {
-                    Some(handler) => handler,
-                    None => {
-                        return Err(VmError::from(VmErrorKind::MissingInstanceFunction {
-                            instance: instance.type_info()?,
-                            hash,
-                        }));
-                    }
-                };
+                if let Some(handler) = self.context.lookup(hash) {
+                    let _depth = self.enter_native_call();
+                    handler(&mut self.stack, args)?;
+                    allocation::take_returned(self.stack.last()?)?;
+                    return Ok(());
+                }
 
-                handler(&mut self.stack, args)?;
+                if !self.call_instance_fallback(type_hash, inst_fn, args)? {
+                    let instance = self.stack.at_offset_from_top(args)?;
+
+                    return Err(VmError::from(VmErrorKind::MissingInstanceFunction {
+                        instance: instance.type_info()?,
+                        hash,
+                    }));
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Call the fallback for an instance function which isn't registered for
+    /// the given type, if there is one.
+    ///
+    /// Returns `false` and leaves the stack untouched if there's no fallback.
+    fn call_instance_fallback(
+        &mut self,
+        type_hash: Hash,
+        inst_fn: Hash,
+        args: usize,
+    ) -> Result<bool, VmError> {
+        // NB: `clone` falls back to the clone protocol.
+        if inst_fn == Hash::instance_fn_name("clone") {
+            let hash = Hash::instance_function(type_hash, Protocol::CLONE);
+
+            if let Some(handler) = self.context.lookup(hash) {
+                let _depth = self.enter_native_call();
+                handler(&mut self.stack, args)?;
+                allocation::take_returned(self.stack.last()?)?;
+                return Ok(true);
+            }
+        }
+
+        // NB: types with a `len` function which can be indexed by integer can
+        // be iterated over, even if they don't implement `INTO_ITER`.
+        if inst_fn == *Protocol::INTO_ITER && args == 1 {
+            let len = Hash::instance_function(type_hash, Hash::instance_fn_name("len"));
+            let index_get = Hash::instance_function(type_hash, Protocol::INDEX_GET);
+
+            if let (Some(len), Some(..)) =
+                (self.context.lookup(len), self.context.lookup(index_get))
+            {
+                let target = self.stack.last()?.clone();
+                len(&mut self.stack, 1)?;
+                let len = usize::from_value(self.stack.pop()?)?;
+                self.stack
+                    .push(crate::Iterator::from_index_get(target, len));
+                return Ok(true);
+            }
+
+            // NB: types which implement `NEXT` are their own iterators, so the
+            // instance is left on the stack as the result.
+            let next = Hash::instance_function(type_hash, Protocol::NEXT);
+
+            if self.context.lookup(next).is_some() {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_call_fn(&mut self, args: usize) -> Result<Option<VmHalt>, VmError> {
         let function = self.stack.pop()?;
@@ -2715,6 +2968,7 @@ impl Vm {
         // NB: set up environment so that native function can access context and
         // unit.
         let _guard = crate::env::Guard::new(&self.context, &self.unit);
+        self.outer_call_depth = OUTER_CALL_DEPTH.with(|tls| tls.get());
 
         loop {
             if !budget::take() {
@@ -4,7 +4,9 @@ use crate::{
     VmHaltInfo,
 };
 use std::fmt;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Errors raised by the execution of the virtual machine.
@@ -32,7 +34,7 @@ impl VmError {
     {
         Ok(Self::from(VmErrorKind::BadArgumentAt {
             arg,
-            expected: T::type_info(),
+            expected: type_info_of::<T>(),
             actual: value.type_info()?,
         }))
     }
@@ -43,11 +45,39 @@ impl VmError {
         T: TypeOf,
     {
         Self::from(VmErrorKind::Expected {
-            expected: T::type_info(),
+            expected: type_info_of::<T>(),
             actual,
         })
     }
 
+    /// Construct an error for when the integer `from` can't be represented by
+    /// the integer type `T`, like when a negative number is passed to a
+    /// function expecting a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::{VmError, VmErrorKind};
+    /// use std::convert::TryFrom as _;
+    ///
+    /// fn index(n: i64) -> Result<usize, VmError> {
+    ///     usize::try_from(n).map_err(|_| VmError::integer_coercion::<usize>(n))
+    /// }
+    ///
+    /// let error = index(-1).unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     error.kind(),
+    ///     VmErrorKind::ValueToIntegerCoercionError { to: "usize", .. }
+    /// ));
+    /// ```
+    pub fn integer_coercion<T>(from: i64) -> Self {
+        Self::from(VmErrorKind::ValueToIntegerCoercionError {
+            from: VmIntegerRepr::from(from),
+            to: std::any::type_name::<T>(),
+        })
+    }
+
     /// Construct an expected any error.
     pub fn expected_any(actual: TypeInfo) -> Self {
         Self::from(VmErrorKind::ExpectedAny { actual })
@@ -121,6 +151,22 @@ impl VmError {
         }
     }
 
+    /// Record the native function which raised a bad argument error, unless
+    /// it's already known.
+    pub(crate) fn with_function(mut self, item: &Item) -> Self {
+        match &mut *self.kind {
+            VmErrorKind::BadArgumentCount { function, .. }
+            | VmErrorKind::BadArgument { function, .. }
+                if function.is_none() =>
+            {
+                *function = Some(item.clone());
+            }
+            _ => (),
+        }
+
+        self
+    }
+
     /// Test if the error is critical and should be propagated unaltered or not.
     ///
     /// Returns `true` if the error should be propagated.
@@ -203,6 +249,8 @@ pub enum VmErrorKind {
         lhs: TypeInfo,
         rhs: TypeInfo,
     },
+    #[error("values of type `{lhs}` and `{rhs}` can't be compared")]
+    Incomparable { lhs: TypeInfo, rhs: TypeInfo },
     #[error("unsupported vm operation `{op}{operand}`")]
     UnsupportedUnaryOperation { op: &'static str, operand: TypeInfo },
     #[error("`{actual}` does not implement the `{protocol}` protocol")]
@@ -218,19 +266,29 @@ pub enum VmErrorKind {
     MissingVariantRtti { hash: Hash },
     #[error("missing runtime information for type with hash `{hash}`")]
     MissingRtti { hash: Hash },
-    #[error("wrong number of arguments `{actual}`, expected `{expected}`")]
-    BadArgumentCount { actual: usize, expected: usize },
+    #[error(
+        "wrong number of arguments `{actual}`, expected `{expected}`{}",
+        InFunction(.function)
+    )]
+    BadArgumentCount {
+        actual: usize,
+        expected: usize,
+        /// The native function which was called, if known.
+        function: Option<Item>,
+    },
     #[error("bad argument #{arg}, expected `{expected}` but got `{actual}`")]
     BadArgumentAt {
         arg: usize,
         expected: TypeInfo,
         actual: TypeInfo,
     },
-    #[error("bad argument #{arg}: {error}")]
+    #[error("bad argument #{arg}{}: {error}", InFunction(.function))]
     BadArgument {
         #[source]
         error: VmError,
         arg: usize,
+        /// The native function which was called, if known.
+        function: Option<Item>,
     },
     #[error("the index set operation `{target}[{index}] = {value}` is not supported")]
     UnsupportedIndexSet {
@@ -269,6 +327,12 @@ pub enum VmErrorKind {
         index: VmIntegerRepr,
         len: VmIntegerRepr,
     },
+    #[error("range `{start}..{end}` is out of bounds: the len is {len}")]
+    RangeOutOfBounds {
+        start: VmIntegerRepr,
+        end: VmIntegerRepr,
+        len: VmIntegerRepr,
+    },
     #[error("missing field `{field}` on `{target}`")]
     MissingField { target: TypeInfo, field: String },
     #[error("missing dynamic field for struct field `{target}::{name}`")]
@@ -308,6 +372,10 @@ pub enum VmErrorKind {
     },
     #[error("expected a tuple of length `{expected}`, but found one with length `{actual}`")]
     ExpectedTupleLength { actual: usize, expected: usize },
+    #[error("expected an array of length `{expected}`, but found one with length `{actual}`")]
+    ExpectedArrayLength { actual: usize, expected: usize },
+    #[error("path `{}` is not valid UTF-8", path.display())]
+    NonUtf8Path { path: PathBuf },
     #[error("unexpectedly ran out of items to iterate over")]
     IterationError,
     #[error("missing variant name in runtime information")]
@@ -326,6 +394,12 @@ pub enum VmErrorKind {
     IndexOutOfBounds,
     #[error("unsupported range")]
     UnsupportedRange,
+    #[error("host function timed out after {duration:?}")]
+    HostTimeout { duration: Duration },
+    #[error("stack overflow, exceeded the maximum call depth of {max_call_depth}")]
+    StackOverflow { max_call_depth: usize },
+    #[error("allocating {requested} bytes would exceed the allocation budget")]
+    AllocationLimitExceeded { requested: usize },
 }
 
 impl VmErrorKind {
@@ -343,6 +417,31 @@ impl VmErrorKind {
     }
 }
 
+/// Get the type information of `T`, preferring what's registered in the
+/// current context since it might have been customized.
+fn type_info_of<T>() -> TypeInfo
+where
+    T: TypeOf,
+{
+    match crate::env::type_info(T::type_hash()) {
+        Some(type_info) => type_info,
+        None => T::type_info(),
+    }
+}
+
+/// Helper to display the native function an error was raised in, if known.
+struct InFunction<'a>(&'a Option<Item>);
+
+impl fmt::Display for InFunction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(item) = self.0 {
+            write!(f, " in call to `{}`", item)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// A type-erased rust number.
 #[derive(Debug, Clone)]
 pub struct VmIntegerRepr(num_bigint::BigInt);
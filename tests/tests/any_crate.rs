@@ -0,0 +1,29 @@
+use rune_tests::*;
+
+mod reexport {
+    pub use runestick as vm;
+}
+
+#[derive(reexport::vm::Any, Debug, Default)]
+#[rune(crate = "reexport::vm")]
+struct Foo {
+    #[rune(get, set, copy)]
+    number: i64,
+}
+
+#[test]
+fn test_any_crate_reexport() {
+    let mut module = runestick::Module::new();
+    module.ty::<Foo>().unwrap();
+
+    let foo = Foo { number: 41 };
+
+    let foo = rune_n! { module, (foo,), Foo =>
+        pub fn main(foo) {
+            foo.number = foo.number + 1;
+            foo
+        }
+    };
+
+    assert_eq!(foo.number, 42);
+}
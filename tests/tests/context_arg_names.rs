@@ -0,0 +1,44 @@
+use runestick::{Context, ContextError, Hash, Module};
+
+fn connect(host: String, port: i64) -> String {
+    format!("{}:{}", host, port)
+}
+
+#[test]
+fn test_function_arg_names() {
+    let mut module = Module::new();
+    module
+        .function_named_args(&["connect"], &["host", "port"], connect)
+        .unwrap();
+    module.function(&["unnamed"], connect).unwrap();
+
+    let mut context = Context::new();
+    context.install(&module).unwrap();
+
+    let arg_names = context
+        .lookup_arg_names(Hash::type_hash(&["connect"]))
+        .unwrap();
+
+    assert_eq!(arg_names.len(), 2);
+    assert_eq!(&*arg_names[0], "host");
+    assert_eq!(&*arg_names[1], "port");
+
+    assert!(context
+        .lookup_arg_names(Hash::type_hash(&["unnamed"]))
+        .is_none());
+}
+
+#[test]
+fn test_function_arg_names_mismatch() {
+    let mut module = Module::new();
+
+    match module.function_named_args(&["connect"], &["host"], connect) {
+        Err(ContextError::ArgumentNamesMismatch {
+            actual, expected, ..
+        }) => {
+            assert_eq!(actual, 1);
+            assert_eq!(expected, 2);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
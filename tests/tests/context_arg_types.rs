@@ -0,0 +1,73 @@
+use runestick::{Any, Context, Hash, Module, Stack, TypeOf, Value, VmError};
+
+#[derive(Any)]
+struct Thing;
+
+fn typed(_: i64, _: &str, _: Value, _: &Thing) {}
+
+impl Thing {
+    fn describe(&self, _: i64, _: Value) {}
+
+    async fn wait(&mut self, _: String) {}
+}
+
+fn raw(_: &mut Stack, _: usize) -> Result<(), VmError> {
+    Ok(())
+}
+
+#[test]
+fn test_function_arg_types() {
+    let mut module = Module::new();
+    module.ty::<Thing>().unwrap();
+    module.function(&["typed"], typed).unwrap();
+    module.raw_fn(&["raw"], raw).unwrap();
+
+    let mut context = Context::new();
+    context.install(&module).unwrap();
+
+    let arg_types = context
+        .lookup_arg_types(Hash::type_hash(&["typed"]))
+        .unwrap();
+
+    assert_eq!(
+        arg_types,
+        &[
+            Some(runestick::INTEGER_TYPE.hash),
+            Some(runestick::STRING_TYPE.hash),
+            None,
+            Some(<Thing as TypeOf>::type_hash()),
+        ]
+    );
+
+    assert!(context
+        .lookup_arg_types(Hash::type_hash(&["raw"]))
+        .is_none());
+}
+
+#[test]
+fn test_instance_function_arg_types() {
+    let mut module = Module::new();
+    module.ty::<Thing>().unwrap();
+    module.inst_fn("describe", Thing::describe).unwrap();
+    module.async_inst_fn("wait", Thing::wait).unwrap();
+
+    let mut context = Context::new();
+    context.install(&module).unwrap();
+
+    let thing = <Thing as TypeOf>::type_hash();
+
+    let arg_types = context
+        .lookup_arg_types(Hash::instance_function(thing, Hash::instance_fn_name("describe")))
+        .unwrap();
+
+    assert_eq!(
+        arg_types,
+        &[Some(thing), Some(runestick::INTEGER_TYPE.hash), None]
+    );
+
+    let arg_types = context
+        .lookup_arg_types(Hash::instance_function(thing, Hash::instance_fn_name("wait")))
+        .unwrap();
+
+    assert_eq!(arg_types, &[Some(thing), Some(runestick::STRING_TYPE.hash)]);
+}
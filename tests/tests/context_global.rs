@@ -0,0 +1,142 @@
+use rune::{Diagnostic, ErrorKind};
+use rune_tests::*;
+use runestick::{Any, Context, ContextError, Module};
+use std::sync::Arc;
+
+#[derive(Any, Clone)]
+struct Env {
+    #[rune(get, set)]
+    name: String,
+}
+
+fn context() -> Context {
+    let mut module = Module::new();
+    module.ty::<Env>().unwrap();
+    module.function(&["greet"], || "hello").unwrap();
+    module
+        .constant_lazy(&["LAZY"], || runestick::ConstValue::Integer(1))
+        .unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    context
+        .set_global(
+            &["env"],
+            Env {
+                name: String::from("production"),
+            },
+        )
+        .unwrap();
+
+    context
+        .set_global(&["config", "VERSION"], String::from("1.0"))
+        .unwrap();
+
+    context
+}
+
+#[test]
+fn test_global() {
+    let context = Arc::new(context());
+
+    let values: (String, String, String) = run(
+        &context,
+        r#"
+        use config::VERSION;
+
+        pub fn main() {
+            let copy = env;
+            copy.name = "changed";
+            (env.name, VERSION, copy.name)
+        }
+        "#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(
+        values,
+        (
+            String::from("production"),
+            String::from("1.0"),
+            String::from("changed")
+        )
+    );
+}
+
+#[test]
+fn test_global_replace() {
+    let mut context = context();
+    context
+        .set_global(&["config", "VERSION"], String::from("2.0"))
+        .unwrap();
+
+    let value: String = run(
+        &Arc::new(context),
+        "pub fn main() { config::VERSION }",
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(value, "2.0");
+}
+
+#[test]
+fn test_global_assign() {
+    let context = context();
+
+    let diagnostics = compile_source(&context, r#"pub fn main() { env = 42; }"#)
+        .unwrap_err()
+        .into_diagnostics();
+
+    assert!(matches!(
+        &diagnostics[..],
+        [Diagnostic::Error(error)] if matches!(error.kind(), ErrorKind::CompileError(..))
+    ));
+}
+
+#[test]
+fn test_global_conflict() {
+    let mut context = context();
+
+    assert!(matches!(
+        context.set_global(&["Env"], 42i64),
+        Err(ContextError::ConflictingMeta { .. })
+    ));
+
+    assert!(matches!(
+        context.set_global(&["greet"], 42i64),
+        Err(ContextError::ConflictingMeta { .. })
+    ));
+
+    assert!(matches!(
+        context.set_global(&["LAZY"], 42i64),
+        Err(ContextError::ConflictingConstantName { .. })
+    ));
+}
+
+#[test]
+fn test_install_conflicting_global() {
+    let mut context = context();
+
+    let mut module = Module::new();
+    module.function(&["env"], || 42i64).unwrap();
+
+    assert!(matches!(
+        context.install(&module),
+        Err(ContextError::ConflictingGlobal { .. })
+    ));
+
+    let mut module = Module::new();
+    module
+        .constant(&["config", "VERSION"], String::from("2.0"))
+        .unwrap();
+
+    assert!(matches!(
+        context.install(&module),
+        Err(ContextError::ConflictingGlobal { .. })
+    ));
+}
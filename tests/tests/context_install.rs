@@ -0,0 +1,358 @@
+use rune_tests::*;
+use runestick::{Any, Context, ContextError, Item, Module};
+use std::sync::Arc;
+
+#[derive(Any)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+impl Point {
+    fn sum(&self) -> i64 {
+        self.x + self.y
+    }
+}
+
+fn base_module() -> Module {
+    let mut module = Module::with_item(&["base"]);
+    module.ty::<Point>().unwrap();
+    module
+        .function(&["Point", "new"], |x, y| Point { x, y })
+        .unwrap();
+    module
+}
+
+fn extension_module() -> Module {
+    let mut module = Module::with_item(&["ext"]);
+    module.function(&["helper"], || 1i64).unwrap();
+    module.inst_fn("sum", Point::sum).unwrap();
+    module
+}
+
+#[test]
+fn test_install_same_module_twice() {
+    let mut context = Context::with_default_modules().unwrap();
+    context
+        .install(&runestick::modules::vec::module().unwrap())
+        .unwrap();
+
+    let mut module = Module::with_item(&["foo"]);
+    module.function(&["bar"], || 42i64).unwrap();
+
+    context.install(&module).unwrap();
+    context.install(&module).unwrap();
+}
+
+#[test]
+fn test_install_conflicting_module() {
+    let mut a = Module::with_item(&["foo"]);
+    a.function(&["bar"], || 42i64).unwrap();
+
+    let mut b = Module::with_item(&["foo"]);
+    b.function(&["bar"], |n: i64| n).unwrap();
+
+    let mut context = Context::new();
+    context.install(&a).unwrap();
+
+    assert!(matches!(
+        context.install(&b).unwrap_err(),
+        ContextError::ConflictingFunction { .. }
+    ));
+}
+
+#[test]
+fn test_install_same_signature_different_handler() {
+    let mut a = Module::with_item(&["foo"]);
+    a.function(&["bar"], || 42i64).unwrap();
+
+    let mut b = Module::with_item(&["foo"]);
+    b.function(&["bar"], || 43i64).unwrap();
+
+    let mut context = Context::new();
+    context.install(&a).unwrap();
+
+    assert!(matches!(
+        context.install(&b).unwrap_err(),
+        ContextError::ConflictingFunction { .. }
+    ));
+}
+
+#[test]
+fn test_install_declared_identity() {
+    fn module(value: i64) -> Module {
+        let mut module = Module::with_item(&["foo"]);
+        module.set_identity("foo");
+        module.function(&["bar"], || 42i64).unwrap();
+        module.constant(&["VALUE"], value).unwrap();
+        module
+    }
+
+    let mut context = Context::new();
+    context.install(&module(1)).unwrap();
+    context.install(&module(1)).unwrap();
+
+    assert!(matches!(
+        context.install(&module(2)).unwrap_err(),
+        ContextError::ConflictingFunction { .. }
+    ));
+}
+
+#[test]
+fn test_install_conflict_names_modules() {
+    let mut context = Context::new();
+    context.install(&base_module()).unwrap();
+
+    let mut b = Module::with_item(&["b"]);
+    b.inst_fn("sum", Point::sum).unwrap();
+    context.install(&b).unwrap();
+
+    let mut c = Module::with_item(&["c"]);
+    c.inst_fn("sum", |p: &Point| p.x).unwrap();
+
+    let error = context.install(&c).unwrap_err();
+
+    match &error {
+        ContextError::ConflictingInstanceFunction {
+            existing_module,
+            module,
+            ..
+        } => {
+            assert_eq!(*existing_module, Item::with_item(&["b"]));
+            assert_eq!(*module, Item::with_item(&["c"]));
+        }
+        error => panic!("unexpected error: {:?}", error),
+    }
+
+    let message = error.to_string();
+    assert!(message.contains("module `c`"), "{}", message);
+    assert!(message.contains("module `b`"), "{}", message);
+}
+
+#[test]
+fn test_install_extension_module() {
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&base_module()).unwrap();
+    context.install(&extension_module()).unwrap();
+    let context = Arc::new(context);
+
+    let value = run::<_, _, i64>(
+        &context,
+        "pub fn main() { base::Point::new(1, 2).sum() }",
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn test_install_extension_module_before_type() {
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&extension_module()).unwrap();
+
+    // The extension is installed even though its type isn't yet.
+    assert!(context.contains_name(&Item::with_item(&["ext", "helper"])));
+
+    match context.validate().unwrap_err() {
+        ContextError::OrphanInstanceFunction { type_info, name } => {
+            assert_eq!(type_info.to_string(), "Point");
+            assert_eq!(name, "sum");
+        }
+        actual => panic!("expected orphan instance function but got: {:?}", actual),
+    }
+
+    context.install(&base_module()).unwrap();
+    context.validate().unwrap();
+    let context = Arc::new(context);
+
+    let value = run::<_, _, i64>(
+        &context,
+        "pub fn main() { base::Point::new(1, 2).sum() }",
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn test_type_names() {
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&base_module()).unwrap();
+
+    let names = context.type_names().collect::<Vec<_>>();
+    assert!(names.contains(&&Item::with_item(&["base", "Point"])));
+    assert!(names.contains(&&Item::with_crate_item("std", &["option", "Option"])));
+    assert!(names.contains(&&Item::with_crate_item("std", &["result", "Result", "Ok"])));
+}
+
+#[test]
+fn test_macro_names() {
+    let context = rune_modules::default_context().unwrap();
+
+    let names = context.macro_names().collect::<Vec<_>>();
+    assert!(names.contains(&&Item::with_crate_item("std", &["stringify"])));
+    assert!(names.contains(&&Item::with_crate_item("std", &["io", "println"])));
+    assert!(names
+        .iter()
+        .all(|name| name.as_crate().is_some()));
+
+    assert_eq!(Context::new().macro_names().count(), 0);
+}
+
+#[test]
+fn test_registered_hashes() {
+    let mut module = Module::with_item(&["base"]);
+    module.ty::<Point>().unwrap();
+    let new = module
+        .function(&["Point", "new"], |x, y| Point { x, y })
+        .unwrap();
+    let sum = module.inst_fn("sum", Point::sum).unwrap();
+
+    let mut context = Context::new();
+    context.install(&module).unwrap();
+
+    assert!(context.lookup(new).is_some());
+    assert!(context.lookup(sum).is_some());
+    assert_eq!(
+        new,
+        runestick::Hash::type_hash(&Item::with_item(&["base", "Point", "new"]))
+    );
+}
+
+#[test]
+fn test_static_functions() {
+    let mut module = Module::with_item(&["base"]);
+    module.ty::<Point>().unwrap();
+    module
+        .static_fn::<Point, _, _>("new", |x, y| Point { x, y })
+        .unwrap();
+    module.function(&["Point", "origin"], || Point { x: 0, y: 0 }).unwrap();
+    module.inst_fn("sum", Point::sum).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let items = context
+        .iter_static_functions(<Point as runestick::TypeOf>::type_hash())
+        .map(|(_, signature)| signature.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(items, vec![String::from("base::Point::new(#0, #1)")]);
+
+    let context = Arc::new(context);
+
+    let value: i64 = run(
+        &context,
+        r#"pub fn main() { base::Point::new(1, 2).sum() }"#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn test_unregistered_return_type() {
+    #[derive(Any)]
+    struct Handle;
+
+    let mut module = Module::with_item(&["handles"]);
+    module.function(&["open"], || Handle).unwrap();
+    module
+        .function(&["try_open"], || Ok::<_, runestick::VmError>(Handle))
+        .unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.validate().unwrap();
+    context.install(&module).unwrap();
+
+    match context.validate().unwrap_err() {
+        ContextError::UnregisteredReturnType { name, type_hash } => {
+            assert!(name == Item::with_item(&["handles", "open"])
+                || name == Item::with_item(&["handles", "try_open"]));
+            assert_eq!(type_hash, <Handle as runestick::TypeOf>::type_hash());
+        }
+        actual => panic!("expected unregistered return type but got: {:?}", actual),
+    }
+
+    let mut module = Module::with_item(&["handles", "types"]);
+    module.ty::<Handle>().unwrap();
+    context.install(&module).unwrap();
+    context.validate().unwrap();
+}
+
+#[test]
+fn test_lookup_inst_fn() {
+    let mut context = Context::new();
+    context.install(&base_module()).unwrap();
+    context.install(&extension_module()).unwrap();
+
+    let type_hash = <Point as runestick::TypeOf>::type_hash();
+    let sum = runestick::Hash::instance_fn_name("sum");
+
+    let handler = context.lookup_inst_fn(type_hash, sum).unwrap();
+    assert!(context.lookup_inst_fn(type_hash, "sum").is_some());
+    assert!(context.lookup_inst_fn(type_hash, "missing").is_none());
+
+    let mut stack = runestick::Stack::new();
+    stack.push(Point { x: 1, y: 2 }.to_value().unwrap());
+    handler(&mut stack, 1).unwrap();
+    assert_eq!(stack.pop().unwrap().into_integer().unwrap(), 3);
+}
+
+#[test]
+fn test_install_type_conflict_names_modules() {
+    let mut context = Context::new();
+    context.install(&base_module()).unwrap();
+
+    let mut other = Module::with_item(&["other"]);
+    other.ty::<Point>().unwrap();
+
+    let error = context.install(&other).unwrap_err();
+
+    match &error {
+        ContextError::ConflictingTypeHash {
+            existing_module,
+            module,
+            ..
+        } => {
+            assert_eq!(*existing_module, Item::with_item(&["base"]));
+            assert_eq!(*module, Item::with_item(&["other"]));
+        }
+        error => panic!("unexpected error: {:?}", error),
+    }
+
+    let message = error.to_string();
+    assert!(message.contains("module `base`"), "{}", message);
+    assert!(message.contains("module `other`"), "{}", message);
+}
+
+#[test]
+fn test_install_constant_replaces_existing() {
+    let mut a = Module::with_item(&["foo"]);
+    a.constant(&["VALUE"], 1i64).unwrap();
+
+    let mut b = Module::with_item(&["foo"]);
+    b.constant(&["VALUE"], 2i64).unwrap();
+
+    let mut context = Context::new();
+    context.install(&a).unwrap();
+    context.install(&b).unwrap();
+
+    let value = rune_n! { b, (), i64 => pub fn main() { foo::VALUE } };
+    assert_eq!(value, 2);
+}
+
+#[test]
+fn test_global_conflict_names_root_module() {
+    let mut context = Context::new();
+    context.set_global(&["base", "Point"], 1i64).unwrap();
+
+    let error = context.install(&base_module()).unwrap_err();
+    let message = error.to_string();
+    assert!(message.contains("the root module"), "{}", message);
+    assert!(message.contains("module `base`"), "{}", message);
+}
@@ -0,0 +1,67 @@
+use rune_tests::*;
+use runestick::{Any, Module, Protocol};
+
+/// A set of flags.
+#[derive(Debug, Clone, Copy, Any)]
+struct Flags(i64);
+
+impl Flags {
+    fn new(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    fn bits(&self) -> i64 {
+        self.0
+    }
+
+    fn bit_and(&self, other: &Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    fn bit_or(&self, other: &Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    fn bit_xor(&self, other: &Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    fn bit_not(&self) -> Self {
+        Self(!self.0)
+    }
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.ty::<Flags>().unwrap();
+    module.function(&["Flags", "new"], Flags::new).unwrap();
+    module.inst_fn("bits", Flags::bits).unwrap();
+    module.inst_fn(Protocol::BIT_AND, Flags::bit_and).unwrap();
+    module.inst_fn(Protocol::BIT_OR, Flags::bit_or).unwrap();
+    module.inst_fn(Protocol::BIT_XOR, Flags::bit_xor).unwrap();
+    module.inst_fn(Protocol::BIT_NOT, Flags::bit_not).unwrap();
+    module
+}
+
+#[test]
+fn test_bitwise_protocols() {
+    let values = rune_n! { module(), (), (i64, i64, i64, i64) =>
+        pub fn main() {
+            let a = Flags::new(0b1100);
+            let b = Flags::new(0b1010);
+            ((a & b).bits(), (a | b).bits(), (a ^ b).bits(), (!a).bits())
+        }
+    };
+
+    assert_eq!(values, (0b1000, 0b1110, 0b0110, !0b1100));
+}
+
+#[test]
+fn test_bit_not_unsupported() {
+    assert_vm_error!(
+        r#"pub fn main() { !"foo" }"#,
+        UnsupportedUnaryOperation { op, .. } => {
+            assert_eq!(op, "!");
+        }
+    );
+}
@@ -0,0 +1,56 @@
+use rune_tests::*;
+use runestick::{Any, Module, Protocol, VmErrorKind};
+
+#[derive(Any, Debug, Clone)]
+struct Counter {
+    #[rune(get, set, copy)]
+    value: i64,
+}
+
+#[derive(Any, Debug)]
+struct Opaque;
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.ty::<Counter>().unwrap();
+    module.ty::<Opaque>().unwrap();
+    module.function(&["Counter", "new"], || Counter { value: 1 }).unwrap();
+    module.function(&["Opaque", "new"], || Opaque).unwrap();
+    module.inst_fn(Protocol::CLONE, Counter::clone).unwrap();
+    module
+}
+
+#[test]
+fn test_clone_protocol() {
+    let values = rune_n! { module(), (), (i64, i64) =>
+        pub fn main() {
+            let a = Counter::new();
+            let b = a.clone();
+            b.value = 2;
+            (a.value, b.value)
+        }
+    };
+
+    assert_eq!(values, (1, 2));
+}
+
+#[test]
+fn test_missing_clone_protocol() {
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module()).unwrap();
+    let context = std::sync::Arc::new(context);
+
+    let e = run::<_, _, ()>(
+        &context,
+        "pub fn main() { Opaque::new().clone(); }",
+        &["main"],
+        (),
+    )
+    .unwrap_err()
+    .expect_vm_error("expected vm error");
+
+    assert!(matches!(
+        e.into_unwound().0.into_kind(),
+        VmErrorKind::MissingInstanceFunction { .. }
+    ));
+}
@@ -0,0 +1,99 @@
+use rune_tests::*;
+use runestick::{Any, Iterator, Module, Protocol, VmError, VmErrorKind};
+
+/// A collection which can be indexed and has a length, but doesn't implement
+/// `INTO_ITER`.
+#[derive(Any, Debug)]
+struct Numbers {
+    values: Vec<i64>,
+}
+
+impl Numbers {
+    fn new() -> Self {
+        Self {
+            values: vec![1, 2, 3],
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn index_get(&self, index: usize) -> Result<i64, VmError> {
+        self.values.get(index).copied().ok_or_else(|| {
+            VmError::from(VmErrorKind::OutOfRange {
+                index: index.into(),
+                len: self.values.len().into(),
+            })
+        })
+    }
+}
+
+/// A collection which also implements `INTO_ITER`, which takes precedence.
+#[derive(Any, Debug)]
+struct Explicit;
+
+impl Explicit {
+    fn len(&self) -> usize {
+        3
+    }
+
+    fn index_get(&self, _: usize) -> i64 {
+        0
+    }
+
+    fn into_iter(&self) -> Iterator {
+        Iterator::from("Explicit", vec![42i64].into_iter())
+    }
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.ty::<Numbers>().unwrap();
+    module.ty::<Explicit>().unwrap();
+    module.function(&["Numbers", "new"], Numbers::new).unwrap();
+    module.function(&["Explicit", "new"], || Explicit).unwrap();
+    module.inst_fn("len", Numbers::len).unwrap();
+    module
+        .inst_fn(Protocol::INDEX_GET, Numbers::index_get)
+        .unwrap();
+    module.inst_fn("len", Explicit::len).unwrap();
+    module
+        .inst_fn(Protocol::INDEX_GET, Explicit::index_get)
+        .unwrap();
+    module
+        .inst_fn(Protocol::INTO_ITER, Explicit::into_iter)
+        .unwrap();
+    module
+}
+
+#[test]
+fn test_index_iter_fallback() {
+    let values = rune_n! { module(), (), Vec<i64> =>
+        pub fn main() {
+            let out = [];
+
+            for n in Numbers::new() {
+                out.push(n);
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(values, vec![1, 2, 3]);
+
+    let values = rune_n! { module(), (), Vec<i64> =>
+        pub fn main() {
+            let out = [];
+
+            for n in Explicit::new() {
+                out.push(n);
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(values, vec![42]);
+}
@@ -0,0 +1,77 @@
+use rune_tests::*;
+use runestick::{Any, Module, Protocol};
+
+/// A lazy source which only implements `NEXT`, making it its own iterator.
+#[derive(Any, Debug)]
+struct Countdown {
+    n: i64,
+}
+
+impl Countdown {
+    fn new(n: i64) -> Self {
+        Self { n }
+    }
+
+    fn next(&mut self) -> Option<i64> {
+        if self.n == 0 {
+            return None;
+        }
+
+        self.n -= 1;
+        Some(self.n)
+    }
+
+    fn remaining(&self) -> i64 {
+        self.n
+    }
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.ty::<Countdown>().unwrap();
+    module
+        .function(&["Countdown", "new"], Countdown::new)
+        .unwrap();
+    module.inst_fn("remaining", Countdown::remaining).unwrap();
+    module.inst_fn(Protocol::NEXT, Countdown::next).unwrap();
+    module
+}
+
+#[test]
+fn test_next_protocol() {
+    let values = rune_n! { module(), (), Vec<i64> =>
+        pub fn main() {
+            let out = [];
+
+            for n in Countdown::new(4) {
+                out.push(n);
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(values, vec![3, 2, 1, 0]);
+}
+
+#[test]
+fn test_next_protocol_is_lazy() {
+    let values = rune_n! { module(), (), (Vec<i64>, i64) =>
+        pub fn main() {
+            let countdown = Countdown::new(10);
+            let out = [];
+
+            for n in countdown {
+                out.push(n);
+
+                if n == 8 {
+                    break;
+                }
+            }
+
+            (out, countdown.remaining())
+        }
+    };
+
+    assert_eq!(values, (vec![9, 8], 8));
+}
@@ -0,0 +1,87 @@
+use rune_tests::*;
+use runestick::{Any, AnyObj, Module, Shared, VmErrorKind};
+
+#[derive(Any, Debug, Default)]
+struct Inner {
+    #[rune(get, set, copy)]
+    number: i64,
+}
+
+#[derive(Any, Debug, Default)]
+struct Outer {
+    #[rune(get, ref)]
+    inner: Inner,
+    #[rune(get, set, copy)]
+    count: i64,
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.ty::<Inner>().unwrap();
+    module.ty::<Outer>().unwrap();
+    module
+}
+
+#[test]
+fn test_getter_ref() {
+    let outer = Outer {
+        inner: Inner { number: 42 },
+        count: 0,
+    };
+
+    let number = rune_n! { module(), (outer,), i64 =>
+        pub fn main(outer) {
+            let inner = outer.inner;
+            inner.number + outer.inner.number
+        }
+    };
+
+    assert_eq!(number, 84);
+}
+
+#[test]
+fn test_getter_ref_is_shared() {
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module()).unwrap();
+    let context = std::sync::Arc::new(context);
+
+    // Neither the reference nor the value it was borrowed from can be
+    // modified while the reference is alive.
+    let sources = [
+        r#"pub fn main(outer) { let inner = outer.inner; inner.number = 10; }"#,
+        r#"pub fn main(outer) { let inner = outer.inner; outer.count = 10; }"#,
+    ];
+
+    for source in &sources {
+        let e = run::<_, _, ()>(&context, source, &["main"], (Outer::default(),))
+            .unwrap_err()
+            .expect_vm_error("expected vm error");
+
+        match e.into_unwound().0.into_kind() {
+            VmErrorKind::BadArgument { error, arg: 0, .. } => {
+                assert!(matches!(error.kind(), VmErrorKind::AccessError { .. }));
+            }
+            actual => panic!("expected access error but got: {:?}", actual),
+        }
+    }
+
+    let count = rune_n! { module(), (Outer::default(),), i64 =>
+        pub fn main(outer) {
+            let number = outer.inner.number;
+            outer.count = number + 1;
+            outer.count
+        }
+    };
+
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_managed_ref_is_not_mutable() {
+    let inner = Shared::new(Inner { number: 42 });
+    let mut any = AnyObj::from_managed_ref(inner.clone().into_ref().unwrap());
+
+    assert_eq!(any.downcast_borrow_ref::<Inner>().unwrap().number, 42);
+    assert!(any.downcast_borrow_mut::<Inner>().is_none());
+    assert!(inner.borrow_mut().is_err());
+}
@@ -1,5 +1,6 @@
 use rune::{Diagnostics, Options, Sources};
-use runestick::{Any, Context, Module, Source, Value, Vm};
+use rune_tests::*;
+use runestick::{Any, Context, Module, Source, Value, Vm, VmError, VmErrorKind};
 use std::sync::Arc;
 
 #[derive(Any, Debug, Default)]
@@ -53,3 +54,204 @@ fn test_getter_setter() {
 
     assert!(matches!(output, Value::Unit));
 }
+
+#[derive(Any, Debug, Default)]
+struct Temperature {
+    celsius: i64,
+}
+
+#[test]
+fn test_property() {
+    let mut module = Module::new();
+    module.ty::<Temperature>().unwrap();
+    module
+        .property(
+            "kelvin",
+            |t: &Temperature| t.celsius + 273,
+            |t: &mut Temperature, kelvin: i64| t.celsius = kelvin - 273,
+        )
+        .unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new(
+        "test",
+        r#"
+        pub fn main(t) {
+            let before = t.kelvin;
+            t.kelvin = before + 10;
+            before
+        }
+        "#,
+    ));
+
+    let mut diagnostics = Diagnostics::new();
+
+    let unit = rune::load_sources(
+        &context,
+        &Options::default(),
+        &mut sources,
+        &mut diagnostics,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let mut t = Temperature { celsius: 20 };
+    let output = vm.call(&["main"], (&mut t,)).unwrap();
+
+    assert!(matches!(output, Value::Integer(293)));
+    assert_eq!(t.celsius, 30);
+}
+
+#[derive(Any, Debug, Default)]
+struct User {
+    first: String,
+    last: String,
+}
+
+impl User {
+    fn name(&self) -> String {
+        format!("{} {}", self.first, self.last)
+    }
+
+    fn set_last(&mut self, last: String) {
+        self.last = last;
+    }
+}
+
+#[test]
+fn test_field_getter_setter() {
+    let mut module = Module::new();
+    module.ty::<User>().unwrap();
+    module.field_getter("name", User::name).unwrap();
+    module.field_setter("last", User::set_last).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    let context = Arc::new(context);
+
+    let user = User {
+        first: String::from("Ada"),
+        last: String::from("Byron"),
+    };
+
+    let (before, after) = run::<_, _, (String, String)>(
+        &context,
+        "pub fn main(u) { let before = u.name; u.last = \"Lovelace\"; (before, u.name) }",
+        &["main"],
+        (user,),
+    )
+    .unwrap();
+
+    assert_eq!(before, "Ada Byron");
+    assert_eq!(after, "Ada Lovelace");
+}
+
+#[derive(Any, Debug, Default)]
+struct Person {
+    #[rune(get, copy, set = "Person::set_age")]
+    age: i64,
+    #[rune(get, copy, set = "Person::set_height")]
+    height: i64,
+}
+
+impl Person {
+    fn set_age(&mut self, age: i64) -> Result<(), VmError> {
+        if age < 0 {
+            return Err(VmError::panic("age can't be negative"));
+        }
+
+        self.age = age;
+        Ok(())
+    }
+
+    fn set_height(&mut self, height: i64) {
+        self.height = height.max(0);
+    }
+}
+
+#[test]
+fn test_custom_setter() {
+    let mut module = Module::new();
+    module.ty::<Person>().unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    let context = Arc::new(context);
+
+    let person = run::<_, _, Person>(
+        &context,
+        "pub fn main(p) { p.age = 42; p.height = -10; p }",
+        &["main"],
+        (Person::default(),),
+    )
+    .unwrap();
+
+    assert_eq!(person.age, 42);
+    assert_eq!(person.height, 0);
+
+    let error = run::<_, _, ()>(
+        &context,
+        "pub fn main(p) { p.age = -1; }",
+        &["main"],
+        (person,),
+    )
+    .unwrap_err()
+    .expect_vm_error("assignment should fail");
+
+    assert!(matches!(
+        error.into_unwound().0.into_kind(),
+        VmErrorKind::Panic { .. }
+    ));
+}
+
+/// A `Copy` wrapper whose `Clone` impl panics, to make sure getters on fields
+/// marked with `#[rune(copy)]` never clone.
+#[derive(Any, Debug)]
+struct Meters(i64);
+
+impl Copy for Meters {}
+
+#[allow(clippy::non_canonical_clone_impl)]
+impl Clone for Meters {
+    fn clone(&self) -> Self {
+        panic!("copy fields should not be cloned")
+    }
+}
+
+impl Meters {
+    fn value(&self) -> i64 {
+        self.0
+    }
+}
+
+#[derive(Any, Debug)]
+struct Route {
+    #[rune(get, copy)]
+    length: Meters,
+}
+
+#[test]
+fn test_copy_getter() {
+    let mut module = Module::new();
+    module.ty::<Meters>().unwrap();
+    module.ty::<Route>().unwrap();
+    module.inst_fn("value", Meters::value).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    let context = Arc::new(context);
+
+    let value = run::<_, _, i64>(
+        &context,
+        "pub fn main(route) { route.length.value() }",
+        &["main"],
+        (Route { length: Meters(42) },),
+    )
+    .unwrap();
+
+    assert_eq!(value, 42);
+}
@@ -1,4 +1,5 @@
 use rune_tests::*;
+use runestick::{Iterator, Module};
 
 #[test]
 fn test_range_iter() {
@@ -56,6 +57,53 @@ fn test_next_back() {
     };
 }
 
+#[test]
+fn test_vec_rev_last() {
+    let values = rune!((Vec<i64>, Option<i64>, Option<i64>, Option<i64>) =>
+        pub fn main() {
+            let v = [1, 2, 3, 4];
+            (v.iter().rev().collect_vec(), v.iter().last(), v.iter().rev().last(), [].iter().last())
+        }
+    );
+
+    assert_eq!(values, (vec![4, 3, 2, 1], Some(4), Some(1), None));
+}
+
+#[test]
+fn test_take_last() {
+    let values = rune!((Option<i64>, Vec<i64>, Option<i64>) =>
+        pub fn main() {
+            let v = [1, 2, 3, 4, 5];
+            (v.iter().take(2).last(), v.iter().take(3).rev().collect_vec(), v.iter().skip(1).take(2).last())
+        }
+    );
+
+    assert_eq!(values, (Some(2), vec![3, 2, 1], Some(3)));
+}
+
+#[test]
+fn test_take_skip_past_end() {
+    let values = rune!((Vec<i64>, Vec<i64>, Vec<i64>) =>
+        pub fn main() {
+            let v = [1, 2, 3];
+            (v.iter().take(10).collect_vec(), v.iter().skip(10).collect_vec(), (0..).iter().skip(2).take(3).collect_vec())
+        }
+    );
+
+    assert_eq!(values, (vec![1, 2, 3], vec![], vec![2, 3, 4]));
+}
+
+#[test]
+fn test_object_last() {
+    let value = rune!(Option<(String, i64)> =>
+        pub fn main() {
+            #{"a": 1}.iter().last()
+        }
+    );
+
+    assert_eq!(value, Some((String::from("a"), 1)));
+}
+
 #[test]
 fn test_object_rev_error() {
     assert_vm_error!(
@@ -162,3 +210,123 @@ fn test_flat_map() {
 
     assert_eq!(actual, expected);
 }
+
+fn squares(n: i64) -> Iterator {
+    Iterator::from("squares", (0..n).map(|n| n * n))
+}
+
+#[test]
+fn test_len() {
+    let mut module = Module::new();
+    module.function(&["squares"], squares).unwrap();
+
+    let values = rune_n! { module, (), (usize, usize, Vec<i64>, usize) =>
+        pub fn main() {
+            let it = squares(5);
+            let before = it.len();
+            it.next();
+            let after = it.len();
+            let rest = it.collect_vec();
+            (before, after, rest, [1, 2, 3].iter().rev().len())
+        }
+    };
+
+    assert_eq!(values, (5, 4, vec![1, 4, 9, 16], 3));
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2, 3].iter().filter(|n| n > 1).len() }"#,
+        Panic { reason } => {
+            assert!(reason.to_string().ends_with("is not an exact-sized iterator"));
+        }
+    );
+}
+
+#[test]
+fn test_step_by() {
+    let values = rune! { (Vec<i64>, Vec<i64>, Vec<i64>, usize) =>
+        pub fn main() {
+            let v = [0, 1, 2, 3, 4, 5, 6];
+            let forward = [];
+
+            for x in v.iter().step_by(2) {
+                forward.push(x);
+            }
+
+            let backward = v.iter().step_by(3).rev().collect_vec();
+            let lazy = std::iter::range(0, 100).step_by(30).collect_vec();
+            (forward, backward, lazy, v.iter().step_by(4).len())
+        }
+    };
+
+    assert_eq!(
+        values,
+        (
+            vec![0, 2, 4, 6],
+            vec![6, 3, 0],
+            vec![0, 30, 60, 90],
+            2
+        )
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2, 3].iter().step_by(0) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "`step_by` requires a non-zero step");
+        }
+    );
+}
+
+#[test]
+fn test_take_inexact() {
+    let value = rune!(Option<i64> =>
+        pub fn main() {
+            [1, 2, 3, 4].iter().filter(|x| x > 1).take(2).last()
+        }
+    );
+
+    assert_eq!(value, Some(3));
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2, 3, 4].iter().filter(|x| x > 1).take(2).rev() }"#,
+        Panic { reason } => {
+            assert!(reason.to_string().ends_with("is not a double-ended iterator"));
+        }
+    );
+}
+
+#[test]
+fn test_step_by_inexact() {
+    let value = rune!(Option<i64> =>
+        pub fn main() {
+            [1, 2, 3, 4, 5, 6].iter().filter(|x| x > 1).step_by(2).last()
+        }
+    );
+
+    assert_eq!(value, Some(6));
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2, 3, 4].iter().filter(|x| x > 1).step_by(2).rev() }"#,
+        Panic { reason } => {
+            assert!(reason.to_string().ends_with("is not a double-ended iterator"));
+        }
+    );
+}
+
+#[test]
+fn test_skip_inexact() {
+    let value = rune!((Option<i64>, Vec<i64>) =>
+        pub fn main() {
+            let v = [1, 2, 3, 4, 5];
+            (v.iter().filter(|x| x > 1).skip(1).last(), v.iter().skip(1).rev().collect_vec())
+        }
+    );
+
+    assert_eq!(value, (Some(5), vec![5, 4, 3, 2]));
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2, 3, 4].iter().filter(|x| x > 1).skip(1).rev() }"#,
+        Panic { reason } => {
+            assert!(reason.to_string().ends_with("is not a double-ended iterator"));
+        }
+    );
+}
@@ -0,0 +1,47 @@
+use rune::{ast, macros, Diagnostic, Parser, TokenStream, WarningKind};
+use rune_tests::*;
+use runestick::{Module, Vm};
+use std::sync::Arc;
+
+/// A macro which doubles its argument, but warns that it's deprecated.
+fn double(stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut parser = Parser::from_token_stream(stream);
+    let expr = parser.parse::<ast::Expr>()?;
+    parser.eof()?;
+
+    macros::warning(&expr, "`double!` is deprecated, use `* 2` instead");
+    Ok(rune::quote!(#expr * 2).into_token_stream())
+}
+
+#[test]
+fn test_macro_warning() {
+    let mut module = Module::new();
+    module.macro_(&["double"], double).unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+
+    let source = "pub fn main() { double!(21) }";
+    let (unit, diagnostics) = compile_source(&context, source).unwrap();
+
+    let warnings = diagnostics
+        .into_diagnostics()
+        .into_iter()
+        .map(|d| match d {
+            Diagnostic::Warning(warning) => warning.into_kind(),
+            d => panic!("unexpected diagnostic: {:?}", d),
+        })
+        .collect::<Vec<_>>();
+
+    match &warnings[..] {
+        [WarningKind::MacroWarning { span, message }] => {
+            assert_eq!(&source[span.range()], "21");
+            assert_eq!(&**message, "`double!` is deprecated, use `* 2` instead");
+        }
+        warnings => panic!("unexpected warnings: {:?}", warnings),
+    }
+
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let value = i64::from_value(vm.call(&["main"], ()).unwrap()).unwrap();
+    assert_eq!(value, 42);
+}
@@ -0,0 +1,79 @@
+use rune_tests::*;
+use runestick::{Any, ContextError, Item, Module};
+
+#[derive(Any)]
+struct Queue {
+    items: Vec<i64>,
+}
+
+impl Queue {
+    fn push(&mut self, item: i64) {
+        self.items.push(item);
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+fn module() -> Module {
+    let mut module = Module::with_item(&["queue"]);
+    module.ty::<Queue>().unwrap();
+    module
+        .function(&["Queue", "new"], || Queue { items: Vec::new() })
+        .unwrap();
+    module.inst_fn("push", Queue::push).unwrap();
+    module.inst_fn("len", Queue::len).unwrap();
+    module
+        .alias_fn(
+            &Item::with_item(&["Queue", "new"]),
+            &Item::with_item(&["Queue", "empty"]),
+        )
+        .unwrap();
+    module.alias_inst_fn::<Queue>("push", "enqueue").unwrap();
+    module
+}
+
+#[test]
+fn test_aliases() {
+    let value = rune_n! { module(), (), usize =>
+        pub fn main() {
+            let a = queue::Queue::new();
+            let b = queue::Queue::empty();
+            a.push(1);
+            b.enqueue(2);
+            b.enqueue(3);
+            a.len() + b.len()
+        }
+    };
+
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn test_alias_errors() {
+    let mut module = module();
+
+    assert!(matches!(
+        module.alias_fn(&Item::with_item(&["missing"]), &Item::with_item(&["other"])),
+        Err(ContextError::MissingFunction { .. })
+    ));
+
+    assert!(matches!(
+        module.alias_fn(
+            &Item::with_item(&["Queue", "new"]),
+            &Item::with_item(&["Queue", "empty"])
+        ),
+        Err(ContextError::ConflictingFunctionName { .. })
+    ));
+
+    assert!(matches!(
+        module.alias_inst_fn::<Queue>("missing", "other"),
+        Err(ContextError::MissingInstanceFunction { .. })
+    ));
+
+    assert!(matches!(
+        module.alias_inst_fn::<Queue>("push", "len"),
+        Err(ContextError::ConflictingInstanceFunction { .. })
+    ));
+}
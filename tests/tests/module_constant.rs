@@ -0,0 +1,103 @@
+use rune_tests::*;
+use runestick::{ConstValue, ContextError, Item, Module, Object};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn test_constant_conflicts_with_function() {
+    let mut module = Module::new();
+    module.function(&["foo"], || 42i64).unwrap();
+
+    match module.constant(&["foo"], 42i64).unwrap_err() {
+        ContextError::ConflictingConstantFunction { name } => {
+            assert_eq!(name, Item::with_item(&["foo"]));
+        }
+        actual => panic!("expected conflicting constant but got: {:?}", actual),
+    }
+}
+
+#[test]
+fn test_function_conflicts_with_constant() {
+    let mut module = Module::new();
+    module.constant(&["foo"], 42i64).unwrap();
+
+    match module.function(&["foo"], || 42i64).unwrap_err() {
+        ContextError::ConflictingConstantFunction { name } => {
+            assert_eq!(name, Item::with_item(&["foo"]));
+        }
+        actual => panic!("expected conflicting function but got: {:?}", actual),
+    }
+
+    match module.async_function(&["foo"], || async { 42i64 }).unwrap_err() {
+        ContextError::ConflictingConstantFunction { name } => {
+            assert_eq!(name, Item::with_item(&["foo"]));
+        }
+        actual => panic!("expected conflicting function but got: {:?}", actual),
+    }
+}
+
+#[test]
+fn test_constant_object() {
+    let mut server = Object::new();
+    server.insert(String::from("host"), "localhost".to_value().unwrap());
+    server.insert(String::from("port"), 8080i64.to_value().unwrap());
+
+    let mut config = Object::new();
+    config.insert(String::from("server"), server.to_value().unwrap());
+    config.insert(String::from("retries"), (1i64, 2i64).to_value().unwrap());
+
+    let mut module = Module::with_item(&["config"]);
+    module.constant(&["CONFIG"], config).unwrap();
+
+    let value = rune_n! { module, (), (String, i64, i64) =>
+        pub fn main() {
+            let c = config::CONFIG;
+            (c.server.host, c.server.port, c.retries.1)
+        }
+    };
+
+    assert_eq!(value, (String::from("localhost"), 8080, 2));
+}
+
+#[test]
+fn test_constant_lazy() {
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut module = Module::with_item(&["table"]);
+
+    module
+        .constant_lazy(&["SQUARES"], {
+            let calls = calls.clone();
+
+            move || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                ConstValue::Vec((0..4).map(|n| ConstValue::Integer(n * n)).collect())
+            }
+        })
+        .unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    let context = Arc::new(context);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    let value: i64 = run(&context, "pub fn main() { 42 }", &["main"], ()).unwrap();
+    assert_eq!(value, 42);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    let source = "pub fn main() { table::SQUARES[3] + table::SQUARES.len() }";
+
+    let value: i64 = run(&context, source, &["main"], ()).unwrap();
+    assert_eq!(value, 13);
+
+    let value: i64 = run(&context, source, &["main"], ()).unwrap();
+    assert_eq!(value, 13);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    match module.constant_lazy(&["SQUARES"], || ConstValue::Unit).unwrap_err() {
+        ContextError::ConflictingConstantName { name, .. } => {
+            assert_eq!(name, Item::with_item(&["SQUARES"]));
+        }
+        actual => panic!("expected conflicting constant but got: {:?}", actual),
+    }
+}
@@ -0,0 +1,62 @@
+use rune_tests::*;
+use runestick::{Any, Module, Protocol};
+
+#[derive(Any, Debug)]
+struct Marker;
+
+impl Marker {
+    fn name(&self) -> String {
+        String::from("marker")
+    }
+
+    fn string_display(&self, s: &mut String) -> std::fmt::Result {
+        use std::fmt::Write as _;
+        write!(s, "<marker>")
+    }
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.ty_hidden::<Marker>().unwrap();
+    module.function(&["marker"], || Marker).unwrap();
+    module.inst_fn("name", Marker::name).unwrap();
+    module
+        .inst_fn(Protocol::STRING_DISPLAY, Marker::string_display)
+        .unwrap();
+    module
+}
+
+#[test]
+fn test_hidden_type_dispatch() {
+    let values = rune_n! { module(), (), (String, String) =>
+        pub fn main() {
+            let m = marker();
+            (m.name(), format!("{}", m))
+        }
+    };
+
+    assert_eq!(values, (String::from("marker"), String::from("<marker>")));
+}
+
+#[test]
+fn test_hidden_type_not_nameable() {
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module()).unwrap();
+    let context = std::sync::Arc::new(context);
+
+    let result = run::<_, _, String>(
+        &context,
+        "pub fn main() { Marker::name(marker()) }",
+        &["main"],
+        (),
+    );
+
+    assert!(matches!(result, Err(RunError::Diagnostics(..))));
+}
+
+#[test]
+fn test_hidden_type_not_in_type_names() {
+    let mut context = runestick::Context::new();
+    context.install(&module()).unwrap();
+    assert_eq!(context.type_names().count(), 0);
+}
@@ -0,0 +1,64 @@
+use rune_tests::*;
+use runestick::{Any, Module, RawStr, TypeInfo, VmErrorKind};
+use std::sync::{Arc, Mutex};
+
+#[derive(Any)]
+struct Handle(Arc<Mutex<i32>>);
+
+fn open() -> Handle {
+    Handle(Arc::new(Mutex::new(3)))
+}
+
+fn fd(handle: &Handle) -> i64 {
+    *handle.0.lock().unwrap() as i64
+}
+
+fn double(n: i64) -> i64 {
+    n * 2
+}
+
+fn context() -> Arc<runestick::Context> {
+    let mut module = Module::new();
+    module
+        .ty_with_info::<Handle>(TypeInfo::Any(RawStr::from_str("FileHandle")))
+        .unwrap();
+    module.function(&["open"], open).unwrap();
+    module.function(&["fd"], fd).unwrap();
+    module.function(&["double"], double).unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+fn error(source: &str) -> VmErrorKind {
+    run::<_, _, ()>(&context(), source, &["main"], ())
+        .unwrap_err()
+        .expect_vm_error("expected vm error")
+        .into_unwound()
+        .0
+        .into_kind()
+}
+
+#[test]
+fn test_custom_type_info() {
+    let value: i64 = run(&context(), "pub fn main() { fd(open()) }", &["main"], ()).unwrap();
+    assert_eq!(value, 3);
+
+    match error("pub fn main() { open()[0]; }") {
+        VmErrorKind::UnsupportedIndexGet { target, .. } => {
+            assert_eq!(target.to_string(), "FileHandle");
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    match error("pub fn main() { double(open()); }") {
+        VmErrorKind::BadArgument { error, .. } => match error.into_kind() {
+            VmErrorKind::Expected { actual, .. } => {
+                assert_eq!(actual.to_string(), "FileHandle");
+            }
+            kind => panic!("unexpected error: {:?}", kind),
+        },
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
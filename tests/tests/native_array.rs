@@ -0,0 +1,87 @@
+use rune_tests::*;
+use runestick::{Module, VmErrorKind};
+use std::sync::Arc;
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn context() -> Arc<runestick::Context> {
+    let mut module = Module::new();
+    module.function(&["dot"], dot).unwrap();
+    module.function(&["cross"], cross).unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+#[test]
+fn test_array_arguments() {
+    let value: f64 = run(
+        &context(),
+        "pub fn main() { dot([1.0, 2.0, 3.0], (4.0, 5.0, 6.0)) }",
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(value, 32.0);
+}
+
+#[test]
+fn test_array_return() {
+    let value: [f64; 3] = run(
+        &context(),
+        "pub fn main() { cross([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]) }",
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(value, [0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn test_array_wrong_length() {
+    let error = run::<_, _, f64>(
+        &context(),
+        "pub fn main() { dot([1.0, 2.0], [3.0, 4.0, 5.0]) }",
+        &["main"],
+        (),
+    )
+    .unwrap_err()
+    .expect_vm_error("expected vm error");
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::BadArgument { error, .. } => match error.into_kind() {
+            VmErrorKind::ExpectedArrayLength { actual, expected } => {
+                assert_eq!(actual, 2);
+                assert_eq!(expected, 3);
+            }
+            kind => panic!("unexpected error: {:?}", kind),
+        },
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
+
+#[test]
+fn test_array_tuple_reuse() {
+    let value: (f64, (f64, f64, f64)) = run(
+        &context(),
+        "pub fn main() { let t = (1.0, 2.0, 3.0); (dot(t, t), t) }",
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(value, (14.0, (1.0, 2.0, 3.0)));
+}
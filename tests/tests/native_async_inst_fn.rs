@@ -0,0 +1,77 @@
+use rune_tests::*;
+use runestick::{Any, Module, VmErrorKind};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// A future which yields once before completing.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[derive(Any, Default)]
+struct Counter {
+    #[rune(get, copy)]
+    value: i64,
+}
+
+impl Counter {
+    async fn add_later(&mut self, n: i64) {
+        self.value += 1;
+        YieldNow(false).await;
+        self.value += n - 1;
+    }
+}
+
+fn context() -> Arc<runestick::Context> {
+    let mut module = Module::new();
+    module.ty::<Counter>().unwrap();
+    module.async_inst_fn("add_later", Counter::add_later).unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+#[test]
+fn test_async_mut_self() {
+    let value: i64 = run(
+        &context(),
+        "pub async fn main(c) { c.add_later(2).await; c.add_later(3).await; c.value }",
+        &["main"],
+        (Counter::default(),),
+    )
+    .unwrap();
+
+    assert_eq!(value, 5);
+}
+
+#[test]
+fn test_async_mut_self_held_across_await() {
+    let e = run::<_, _, i64>(
+        &context(),
+        "pub async fn main(c) { let f = c.add_later(2); let v = c.value; f.await; v }",
+        &["main"],
+        (Counter::default(),),
+    )
+    .unwrap_err()
+    .expect_vm_error("expected vm error");
+
+    assert!(matches!(
+        e.into_unwound().0.into_kind(),
+        VmErrorKind::AccessError { .. }
+    ));
+}
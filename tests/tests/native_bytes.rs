@@ -0,0 +1,49 @@
+use rune_tests::*;
+use runestick::{Bytes, Module, VmErrorKind};
+
+fn reverse(data: Vec<u8>) -> Bytes {
+    Bytes::from_vec(data.into_iter().rev().collect())
+}
+
+fn checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.function(&["reverse"], reverse).unwrap();
+    module.function(&["checksum"], checksum).unwrap();
+    module
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    let values = rune_n! { module(), (), (Bytes, usize, u8, u8, u8) =>
+        pub fn main() {
+            let data = reverse(b"abc");
+            let len = data.len();
+            let first = data[0];
+            data[1] = b'x';
+            (data, len, first, checksum(b"\x01\x02\x03"), checksum(reverse([b'\x04', b'\x05'])))
+        }
+    };
+
+    assert_eq!(values, (Bytes::from_vec(b"cxa".to_vec()), 3, b'c', 6, 9));
+}
+
+#[test]
+fn test_bytes_index_out_of_range() {
+    let values = rune! { (Option<u8>, Option<u8>) =>
+        pub fn main() {
+            let data = b"ab";
+            (data.get(1), data.get(2))
+        }
+    };
+
+    assert_eq!(values, (Some(b'b'), None));
+
+    assert_vm_error!(
+        r#"pub fn main() { b"ab"[2] }"#,
+        VmErrorKind::OutOfRange { .. } => {}
+    );
+}
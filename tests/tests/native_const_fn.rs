@@ -0,0 +1,199 @@
+use rune_tests::*;
+use runestick::{Context, Inst, Module, Value, VmError, VmErrorKind};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn square(n: i64) -> i64 {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    n * n
+}
+
+fn checked_div(a: i64, b: i64) -> Result<i64, VmError> {
+    a.checked_div(b)
+        .ok_or_else(|| VmError::panic("division by zero"))
+}
+
+fn type_name(value: Value) -> Result<String, VmError> {
+    value.into_type_name()
+}
+
+fn explode(n: i64) -> i64 {
+    panic!("boom {}", n)
+}
+
+fn context() -> Context {
+    let mut module = Module::with_item(&["math"]);
+    module.const_function(&["square"], square).unwrap();
+    module.const_function(&["checked_div"], checked_div).unwrap();
+    module.const_function(&["type_name"], type_name).unwrap();
+    module.const_function(&["explode"], explode).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    context
+}
+
+#[test]
+fn test_const_fn_folding() {
+    let context = context();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        const SIDE = 3;
+
+        pub fn main(n) {
+            (math::square(SIDE + 1), math::square(n), math::checked_div(10, 2))
+        }
+        "#,
+    )
+    .unwrap();
+
+    // NB: the call with a constant argument is folded, the other one is
+    // compiled as a regular call.
+    let calls = unit
+        .iter_instructions()
+        .filter(|inst| matches!(inst, Inst::Call { .. }))
+        .count();
+
+    assert_eq!(calls, 1);
+
+    let before = CALLS.load(Ordering::SeqCst);
+
+    let vm = runestick::Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let output = vm.call(&["main"], (5i64,)).unwrap();
+    let output = <(i64, i64, i64) as runestick::FromValue>::from_value(output).unwrap();
+
+    assert_eq!(output, (16, 25, 5));
+    assert_eq!(CALLS.load(Ordering::SeqCst), before + 1);
+}
+
+#[test]
+fn test_const_fn_shadowed_const() {
+    let context = context();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        const n = 2;
+
+        pub fn main() {
+            let n = 10;
+            (math::checked_div(n, 5), n / 5)
+        }
+        "#,
+    )
+    .unwrap();
+
+    // NB: the local shadows the constant, so the call can't be folded.
+    let calls = unit
+        .iter_instructions()
+        .filter(|inst| matches!(inst, Inst::Call { .. }))
+        .count();
+
+    assert_eq!(calls, 1);
+
+    let vm = runestick::Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let output = vm.call(&["main"], ()).unwrap();
+    let output = <(i64, i64) as runestick::FromValue>::from_value(output).unwrap();
+
+    assert_eq!(output, (2, 2));
+}
+
+#[test]
+fn test_const_fn_error() {
+    let context = context();
+
+    // NB: the failing call isn't folded, so the error is raised at runtime.
+    let (unit, _) =
+        compile_source(&context, r#"pub fn main() { math::checked_div(1, 0) }"#).unwrap();
+
+    let vm = runestick::Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let error = vm.call(&["main"], ()).unwrap_err();
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::Panic { reason } => {
+            assert_eq!(reason.to_string(), "division by zero");
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
+
+#[test]
+fn test_const_fn_env() {
+    let context = context();
+
+    let (unit, _) =
+        compile_source(&context, r#"pub fn main() { math::type_name(42) }"#).unwrap();
+
+    // NB: the function needs the environment, which is available when it's
+    // folded at compile time.
+    let calls = unit
+        .iter_instructions()
+        .filter(|inst| matches!(inst, Inst::Call { .. }))
+        .count();
+
+    assert_eq!(calls, 0);
+
+    let vm = runestick::Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let output = vm.call(&["main"], ()).unwrap();
+    let output = <String as runestick::FromValue>::from_value(output).unwrap();
+
+    assert_eq!(output, "::std::int");
+}
+
+#[test]
+fn test_const_fn_catch_unwind() {
+    let mut context = context();
+    context.set_catch_unwind(true);
+
+    // NB: the panic is caught when folding, so the call is compiled as usual
+    // and panics again at runtime.
+    let (unit, _) = compile_source(&context, r#"pub fn main() { math::explode(1) }"#).unwrap();
+
+    let calls = unit
+        .iter_instructions()
+        .filter(|inst| matches!(inst, Inst::Call { .. }))
+        .count();
+
+    assert_eq!(calls, 1);
+
+    let vm = runestick::Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let error = vm.call(&["main"], ()).unwrap_err();
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::Panic { reason } => {
+            assert_eq!(reason.to_string(), "boom 1");
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
+
+#[test]
+fn test_const_fn_observed() {
+    let observed = Arc::new(AtomicUsize::new(0));
+
+    let mut context = context();
+
+    context.set_call_observer(Box::new({
+        let observed = observed.clone();
+
+        move |item, _, phase| {
+            if phase == runestick::CallPhase::Before
+                && *item == runestick::Item::with_item(&["math", "square"])
+            {
+                observed.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }));
+
+    compile_source(
+        &context,
+        r#"pub fn main() { (math::square(2), math::square(3)) }"#,
+    )
+    .unwrap();
+
+    assert_eq!(observed.load(Ordering::SeqCst), 2);
+}
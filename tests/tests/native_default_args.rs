@@ -0,0 +1,82 @@
+use rune_tests::*;
+use runestick::{ConstValue, ContextError, Item, Module, VmErrorKind};
+use std::sync::Arc;
+
+fn connect(host: &str, port: i64, secure: bool) -> String {
+    format!("{}:{} (secure: {})", host, port, secure)
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module
+        .function_defaults(
+            &["connect"],
+            &[ConstValue::Integer(8080), ConstValue::Bool(false)],
+            connect,
+        )
+        .unwrap();
+    module
+}
+
+#[test]
+fn test_default_args() {
+    let values = rune_n! { module(), (), (String, String, String) =>
+        pub fn main() {
+            (
+                connect("localhost"),
+                connect("localhost", 80),
+                connect("localhost", 443, true),
+            )
+        }
+    };
+
+    assert_eq!(
+        values,
+        (
+            String::from("localhost:8080 (secure: false)"),
+            String::from("localhost:80 (secure: false)"),
+            String::from("localhost:443 (secure: true)"),
+        )
+    );
+}
+
+#[test]
+fn test_default_args_count() {
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module()).unwrap();
+    let context = Arc::new(context);
+
+    for source in &[
+        "pub fn main() { connect() }",
+        r#"pub fn main() { connect("a", 1, true, 2) }"#,
+    ] {
+        let e = run::<_, _, String>(&context, source, &["main"], ())
+            .unwrap_err()
+            .expect_vm_error("expected vm error");
+
+        match e.into_unwound().0.into_kind() {
+            VmErrorKind::BadArgumentCount { expected, .. } => assert_eq!(expected, 3),
+            actual => panic!("unexpected error: {:?}", actual),
+        }
+    }
+}
+
+#[test]
+fn test_too_many_defaults() {
+    let mut module = Module::new();
+
+    match module
+        .function_defaults(&["one"], &[ConstValue::Unit, ConstValue::Unit], |_: i64| ())
+        .unwrap_err()
+    {
+        ContextError::TooManyDefaults {
+            name,
+            defaults,
+            args,
+        } => {
+            assert_eq!(name, Item::with_item(&["one"]));
+            assert_eq!((defaults, args), (2, 1));
+        }
+        actual => panic!("unexpected error: {:?}", actual),
+    }
+}
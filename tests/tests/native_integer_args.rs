@@ -0,0 +1,125 @@
+use rune_tests::*;
+use runestick::{Module, VmErrorKind};
+use std::sync::Arc;
+
+fn context() -> Arc<runestick::Context> {
+    let mut module = Module::new();
+    module.function(&["take_usize"], |n: usize| n).unwrap();
+    module.function(&["take_u32"], |n: u32| n).unwrap();
+    module.function(&["take_u64"], |a: i64, b: u64| a + b as i64).unwrap();
+    module
+        .function(&["scale"], |a: f64, b: i64| a * b as f64)
+        .unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+fn coercion_error(source: &str) -> (usize, String, &'static str) {
+    let e = run::<_, _, i64>(&context(), source, &["main"], ())
+        .unwrap_err()
+        .expect_vm_error("expected vm error");
+
+    match e.into_unwound().0.into_kind() {
+        VmErrorKind::BadArgument { error, arg, .. } => match error.into_kind() {
+            VmErrorKind::ValueToIntegerCoercionError { from, to } => (arg, from.to_string(), to),
+            actual => panic!("unexpected error: {:?}", actual),
+        },
+        actual => panic!("unexpected error: {:?}", actual),
+    }
+}
+
+#[test]
+fn test_unsigned_integer_args() {
+    let value: i64 = run(&context(), "pub fn main() { take_u32(42) }", &["main"], ()).unwrap();
+    assert_eq!(value, 42);
+
+    assert_eq!(
+        coercion_error("pub fn main() { take_usize(-1) }"),
+        (0, String::from("-1"), "usize")
+    );
+
+    assert_eq!(
+        coercion_error("pub fn main() { take_u32(4294967296) }"),
+        (0, String::from("4294967296"), "u32")
+    );
+
+    assert_eq!(
+        coercion_error("pub fn main() { take_u64(1, -2) }"),
+        (1, String::from("-2"), "u64")
+    );
+}
+
+#[test]
+fn test_bad_argument_function() {
+    let function = |source: &str| {
+        let e = run::<_, _, ()>(&context(), source, &["main"], ())
+            .unwrap_err()
+            .expect_vm_error("expected vm error");
+
+        let e = e.into_unwound().0;
+        let message = e.to_string();
+
+        match e.into_kind() {
+            VmErrorKind::BadArgument { function, .. }
+            | VmErrorKind::BadArgumentCount { function, .. } => {
+                (function.map(|item| item.to_string()), message)
+            }
+            actual => panic!("unexpected error: {:?}", actual),
+        }
+    };
+
+    assert_eq!(
+        function("pub fn main() { take_usize(-1); }"),
+        (
+            Some(String::from("take_usize")),
+            String::from(
+                "bad argument #0 in call to `take_usize`: failed to convert value `-1` to integer `usize`"
+            )
+        )
+    );
+
+    assert_eq!(
+        function("pub fn main() { let s = String::new(); s.push_str(1); }"),
+        (
+            Some(String::from("::std::string::String::push_str")),
+            String::from("bad argument #1 in call to `::std::string::String::push_str`: expected `String`, but found `integer`")
+        )
+    );
+
+    assert_eq!(
+        function("pub fn main() { take_u32(1, 2); }"),
+        (
+            Some(String::from("take_u32")),
+            String::from("wrong number of arguments `2`, expected `1` in call to `take_u32`")
+        )
+    );
+}
+
+#[test]
+fn test_primitive_arg_mismatch() {
+    let value: f64 = run(&context(), "pub fn main() { scale(1.5, 2) }", &["main"], ()).unwrap();
+    assert_eq!(value, 3.0);
+
+    let mismatch = |source: &str| {
+        let e = run::<_, _, f64>(&context(), source, &["main"], ())
+            .unwrap_err()
+            .expect_vm_error("expected vm error");
+
+        match e.into_unwound().0.into_kind() {
+            VmErrorKind::BadArgument { error, arg, .. } => (arg, error.to_string()),
+            actual => panic!("unexpected error: {:?}", actual),
+        }
+    };
+
+    assert_eq!(
+        mismatch("pub fn main() { scale(1, 2) }"),
+        (0, String::from("expected `float`, but found `integer`"))
+    );
+
+    assert_eq!(
+        mismatch("pub fn main() { scale(1.0, 2.0) }"),
+        (1, String::from("expected `integer`, but found `float`"))
+    );
+}
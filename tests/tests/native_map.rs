@@ -0,0 +1,118 @@
+use rune_tests::*;
+use runestick::{Module, TypeInfo, Value, VmErrorKind};
+use std::collections::{BTreeMap, HashMap};
+
+/// Render the given options sorted by key.
+fn configure(opts: HashMap<String, Value>) -> Result<String, runestick::VmError> {
+    let mut opts = opts.into_iter().collect::<Vec<_>>();
+    opts.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut out = Vec::new();
+
+    for (key, value) in opts {
+        let value = match value {
+            Value::Integer(n) => n.to_string(),
+            value => String::from_value(value)?,
+        };
+
+        out.push(format!("{}={}", key, value));
+    }
+
+    Ok(out.join(","))
+}
+
+fn defaults() -> HashMap<String, Value> {
+    let mut map = HashMap::new();
+    map.insert(String::from("host"), Value::from(String::from("localhost")));
+    map.insert(String::from("port"), Value::from(8080i64));
+    map
+}
+
+/// Render the given options in the order they're received.
+fn canonical(opts: BTreeMap<String, i64>) -> String {
+    let out = opts
+        .into_iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>();
+
+    out.join(",")
+}
+
+fn sorted(keys: Vec<String>) -> BTreeMap<String, i64> {
+    keys.into_iter().zip(0..).collect()
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.function(&["configure"], configure).unwrap();
+    module.function(&["defaults"], defaults).unwrap();
+    module.function(&["canonical"], canonical).unwrap();
+    module.function(&["sorted"], sorted).unwrap();
+    module
+}
+
+#[test]
+fn test_map_argument() {
+    let values = rune_n! { module(), (), (String, String) =>
+        pub fn main() {
+            let opts = defaults();
+            opts.port = 443;
+            (configure(#{host: "x", port: 80}), configure(opts))
+        }
+    };
+
+    assert_eq!(
+        values,
+        (
+            String::from("host=x,port=80"),
+            String::from("host=localhost,port=443")
+        )
+    );
+}
+
+#[test]
+fn test_sorted_map() {
+    let values = rune_n! { module(), (), (String, Vec<String>) =>
+        pub fn main() {
+            let keys = [];
+
+            for (key, _) in sorted(["c", "a", "b"]) {
+                keys.push(key);
+            }
+
+            (canonical(#{zeta: 1, alpha: 2, mid: 3}), keys)
+        }
+    };
+
+    assert_eq!(
+        values,
+        (
+            String::from("alpha=2,mid=3,zeta=1"),
+            vec![String::from("a"), String::from("b"), String::from("c")]
+        )
+    );
+}
+
+#[test]
+fn test_map_argument_expects_object() {
+    let context = {
+        let mut context = rune_modules::default_context().unwrap();
+        context.install(&module()).unwrap();
+        std::sync::Arc::new(context)
+    };
+
+    let error = run::<_, _, String>(&context, r#"pub fn main() { configure([1, 2]) }"#, &["main"], ())
+        .unwrap_err()
+        .expect_vm_error("expected vm error");
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::BadArgument { error, arg: 0, .. } => {
+            assert!(matches!(
+                error.kind(),
+                VmErrorKind::Expected { expected: TypeInfo::StaticType(ty), .. }
+                    if *ty == runestick::OBJECT_TYPE
+            ));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
@@ -0,0 +1,46 @@
+use rune_tests::*;
+use runestick::Module;
+
+fn find(names: Vec<String>, prefix: &str) -> Option<String> {
+    names.into_iter().find(|name| name.starts_with(prefix))
+}
+
+fn or_default(value: Option<i64>) -> i64 {
+    value.unwrap_or(-1)
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.function(&["find"], find).unwrap();
+    module.function(&["or_default"], or_default).unwrap();
+    module
+}
+
+#[test]
+fn test_option_return() {
+    let values = rune_n! { module(), (), (String, String) =>
+        fn lookup(prefix) {
+            match find(["alice", "bob"], prefix) {
+                Some(name) => name,
+                None => "nobody",
+            }
+        }
+
+        pub fn main() {
+            (lookup("b"), lookup("c"))
+        }
+    };
+
+    assert_eq!(values, (String::from("bob"), String::from("nobody")));
+}
+
+#[test]
+fn test_option_argument() {
+    let values = rune_n! { module(), (), (i64, i64) =>
+        pub fn main() {
+            (or_default(Some(42)), or_default(None))
+        }
+    };
+
+    assert_eq!(values, (42, -1));
+}
@@ -0,0 +1,59 @@
+use rune_tests::*;
+use runestick::Module;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+fn join(base: PathBuf, name: &str) -> PathBuf {
+    base.join(name)
+}
+
+fn file_name(path: &Path) -> Option<String> {
+    Some(path.file_name()?.to_str()?.to_owned())
+}
+
+fn context() -> Arc<runestick::Context> {
+    let mut module = Module::new();
+    module.function(&["join"], join).unwrap();
+    module.function(&["file_name"], file_name).unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+#[test]
+fn test_path_arguments() {
+    let values: (String, Option<String>) = run(
+        &context(),
+        r#"
+        pub fn main() {
+            let dir = "assets";
+            (join(dir, "config.toml"), file_name(join(`{dir}/nested`, "data.bin")))
+        }
+        "#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    let expected = Path::new("assets").join("config.toml");
+    assert_eq!(values.0, expected.to_str().unwrap());
+    assert_eq!(values.1.as_deref(), Some("data.bin"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_utf8_path() {
+    use runestick::{ToValue, VmErrorKind};
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = PathBuf::from(OsStr::from_bytes(b"invalid\xff"));
+
+    match path.to_value().unwrap_err().into_kind() {
+        VmErrorKind::NonUtf8Path { path } => {
+            assert_eq!(path.as_os_str().as_bytes(), b"invalid\xff");
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
@@ -0,0 +1,84 @@
+use rune_tests::*;
+use runestick::{Module, VmErrorKind};
+use std::ops::{Range, RangeInclusive};
+use std::sync::Arc;
+
+fn valid_range() -> Range<i64> {
+    1..3
+}
+
+fn valid_range_inclusive() -> RangeInclusive<i64> {
+    1..=3
+}
+
+fn span(range: Range<i64>) -> i64 {
+    range.end - range.start
+}
+
+fn span_inclusive(range: RangeInclusive<i64>) -> i64 {
+    range.end() - range.start() + 1
+}
+
+fn context() -> Arc<runestick::Context> {
+    let mut module = Module::new();
+    module.function(&["valid_range"], valid_range).unwrap();
+    module
+        .function(&["valid_range_inclusive"], valid_range_inclusive)
+        .unwrap();
+    module.function(&["span"], span).unwrap();
+    module
+        .function(&["span_inclusive"], span_inclusive)
+        .unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+#[test]
+fn test_return_range() {
+    let values: (Vec<i64>, Vec<i64>, Vec<i64>) = run(
+        &context(),
+        r#"
+        pub fn main() {
+            let v = [10, 20, 30, 40];
+            let indexes = [];
+
+            for i in valid_range() {
+                indexes.push(i);
+            }
+
+            (v[valid_range()], v[valid_range_inclusive()], indexes)
+        }
+        "#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(values, (vec![20, 30], vec![20, 30, 40], vec![1, 2]));
+}
+
+#[test]
+fn test_range_argument() {
+    let values: (i64, i64) = run(
+        &context(),
+        "pub fn main() { (span(2..7), span_inclusive(2..=7)) }",
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(values, (5, 6));
+
+    let error = run::<_, _, i64>(&context(), "pub fn main() { span(2..) }", &["main"], ())
+        .unwrap_err()
+        .expect_vm_error("expected vm error");
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::BadArgument { error, .. } => {
+            assert!(matches!(error.into_kind(), VmErrorKind::UnsupportedRange));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
@@ -0,0 +1,76 @@
+use rune_tests::*;
+use runestick::{Module, Stack, VmError, VmErrorKind};
+use std::sync::Arc;
+
+fn add(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    let b = stack.pop_arg::<i64>(1, args)?;
+    let a = stack.pop_arg::<i64>(0, args)?;
+    stack.push(a + b);
+    Ok(())
+}
+
+fn module() -> Module {
+    let registry = vec![(String::from("double"), 2), (String::from("triple"), 3)];
+
+    let mut module = Module::new();
+
+    for (name, factor) in registry {
+        module
+            .raw_fn_boxed(
+                &[name.as_str()],
+                Box::new(move |stack: &mut Stack, args: usize| -> Result<(), VmError> {
+                    let value = stack.pop_arg::<i64>(0, args)?;
+                    stack.push(value * factor);
+                    Ok(())
+                }),
+            )
+            .unwrap();
+    }
+
+    module.raw_fn_arity(&["add"], 2, add).unwrap();
+    module
+}
+
+#[test]
+fn test_raw_fn_boxed() {
+    let values = rune_n! { module(), (), (i64, i64) =>
+        pub fn main() {
+            (double(5), triple(5))
+        }
+    };
+
+    assert_eq!(values, (10, 15));
+}
+
+#[test]
+fn test_raw_fn_arity() {
+    let value = rune_n! { module(), (), i64 =>
+        pub fn main() {
+            add(1, 2)
+        }
+    };
+
+    assert_eq!(value, 3);
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module()).unwrap();
+
+    let error = run::<_, _, i64>(
+        &Arc::new(context),
+        "pub fn main() { add(1, 2, 3) }",
+        &["main"],
+        (),
+    )
+    .unwrap_err()
+    .expect_vm_error("expected vm error");
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::BadArgumentCount {
+            actual, expected, ..
+        } => {
+            assert_eq!(actual, 3);
+            assert_eq!(expected, 2);
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
@@ -0,0 +1,126 @@
+use rune_tests::*;
+use runestick::{Any, Module, Panic, VmError, VmErrorKind};
+use std::sync::Arc;
+
+fn parse(s: &str) -> Result<i64, String> {
+    s.parse().map_err(|_| format!("not a number: {}", s))
+}
+
+fn checked_parse(s: &str) -> Result<i64, VmError> {
+    s.parse().map_err(|_| VmError::from(VmErrorKind::Overflow))
+}
+
+fn unreachable() -> Result<(), Panic> {
+    Err(Panic::custom("unreachable"))
+}
+
+#[derive(Any, Debug)]
+struct ParseError {
+    input: String,
+}
+
+impl ParseError {
+    fn input(&self) -> String {
+        self.input.clone()
+    }
+}
+
+fn parse_all(inputs: Vec<String>) -> Vec<Result<i64, ParseError>> {
+    inputs
+        .into_iter()
+        .map(|input| input.parse().map_err(|_| ParseError { input }))
+        .collect()
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.ty::<ParseError>().unwrap();
+    module.inst_fn("input", ParseError::input).unwrap();
+    module.function(&["parse_all"], parse_all).unwrap();
+    module.function(&["parse"], parse).unwrap();
+    module.function(&["checked_parse"], checked_parse).unwrap();
+    module.function(&["unreachable"], unreachable).unwrap();
+    module
+}
+
+fn context() -> Arc<runestick::Context> {
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module()).unwrap();
+    Arc::new(context)
+}
+
+#[test]
+fn test_result_error_is_catchable() {
+    let values = rune_n! { module(), (), (i64, String, String) =>
+        fn sum(a, b) {
+            Ok(parse(a)? + parse(b)?)
+        }
+
+        pub fn main() {
+            let error = match sum("1", "x") {
+                Ok(..) => "ok",
+                Err(error) => error,
+            };
+
+            let caught = match parse("y") {
+                Ok(..) => "ok",
+                Err(error) => error,
+            };
+
+            (sum("1", "2")?, error, caught)
+        }
+    };
+
+    assert_eq!(
+        values,
+        (
+            3,
+            String::from("not a number: x"),
+            String::from("not a number: y")
+        )
+    );
+}
+
+#[test]
+fn test_vm_error_aborts() {
+    let context = context();
+
+    let value = run::<_, _, i64>(&context, r#"pub fn main() { checked_parse("7") }"#, &["main"], ())
+        .unwrap();
+    assert_eq!(value, 7);
+
+    let error = run::<_, _, i64>(&context, r#"pub fn main() { checked_parse("x") }"#, &["main"], ())
+        .unwrap_err()
+        .expect_vm_error("expected vm error");
+    let error = error.into_unwound().0;
+    assert!(matches!(error.kind(), VmErrorKind::Overflow));
+    assert!(error.unpack_critical().is_ok());
+
+    let error = run::<_, _, ()>(&context, r#"pub fn main() { unreachable() }"#, &["main"], ())
+        .unwrap_err()
+        .expect_vm_error("expected vm error");
+    let error = error.into_unwound().0;
+    assert!(matches!(error.kind(), VmErrorKind::Panic { .. }));
+    assert!(error.unpack_critical().is_err());
+}
+
+#[test]
+fn test_result_values() {
+    let values = rune_n! { module(), (), (i64, Vec<String>) =>
+        pub fn main() {
+            let sum = 0;
+            let errors = [];
+
+            for result in parse_all(["1", "x", "2", "y"]) {
+                match result {
+                    Ok(n) => sum += n,
+                    Err(error) => errors.push(error.input()),
+                }
+            }
+
+            (sum, errors)
+        }
+    };
+
+    assert_eq!(values, (3, vec![String::from("x"), String::from("y")]));
+}
@@ -0,0 +1,53 @@
+use rune_tests::*;
+use runestick::Module;
+use std::borrow::Cow;
+
+fn label(n: i64) -> &'static str {
+    if n > 0 {
+        "positive"
+    } else {
+        "not positive"
+    }
+}
+
+fn describe(n: i64) -> Cow<'static, str> {
+    if n == 0 {
+        Cow::Borrowed("zero")
+    } else {
+        Cow::Owned(format!("number {}", n))
+    }
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.function(&["label"], label).unwrap();
+    module.function(&["describe"], describe).unwrap();
+    module
+}
+
+#[test]
+fn test_str_return() {
+    let values = rune_n! { module(), (), (String, String) =>
+        pub fn main() {
+            let s = label(1);
+            s.push_str("!");
+            (s, label(0))
+        }
+    };
+
+    assert_eq!(
+        values,
+        (String::from("positive!"), String::from("not positive"))
+    );
+}
+
+#[test]
+fn test_cow_str_return() {
+    let values = rune_n! { module(), (), (String, String) =>
+        pub fn main() {
+            (describe(0), describe(42))
+        }
+    };
+
+    assert_eq!(values, (String::from("zero"), String::from("number 42")));
+}
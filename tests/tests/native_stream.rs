@@ -0,0 +1,118 @@
+use futures_util::stream::{self, StreamExt as _};
+use rune_tests::*;
+use runestick::{Module, Stream, VmError, VmErrorKind};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// The number of rows pulled out of the cursor streamed by `lazy_rows`.
+static PULLED: AtomicUsize = AtomicUsize::new(0);
+
+/// Stream `n` rows out of a cursor.
+fn rows(n: i64) -> Stream {
+    Stream::from_stream(stream::unfold(0, move |row| async move {
+        if row == n {
+            return None;
+        }
+
+        Some((Ok::<_, VmError>(row), row + 1))
+    }))
+}
+
+fn context() -> Arc<runestick::Context> {
+    let mut module = Module::new();
+    module.function(&["rows"], rows).unwrap();
+
+    module
+        .function(&["lazy_rows"], || {
+            Stream::from_stream(stream::iter(0i64..).map(|row| {
+                PULLED.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, VmError>(row)
+            }))
+        })
+        .unwrap();
+
+    module
+        .function(&["broken"], || {
+            Stream::from_stream(stream::iter(vec![
+                Ok(1i64),
+                Err(VmError::panic("cursor closed")),
+            ]))
+        })
+        .unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+#[test]
+fn test_native_stream() {
+    let value: i64 = run(
+        &context(),
+        r#"
+        pub async fn main() {
+            let rows = rows(4);
+            let sum = 0;
+
+            while let Some(row) = rows.next().await {
+                sum += row;
+            }
+
+            sum
+        }
+        "#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(value, 6);
+}
+
+#[test]
+fn test_native_stream_is_lazy() {
+    let value: Option<i64> = run(
+        &context(),
+        r#"
+        pub async fn main() {
+            let rows = lazy_rows();
+            rows.next().await;
+            rows.next().await
+        }
+        "#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(value, Some(1));
+    assert_eq!(PULLED.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_native_stream_error() {
+    let error = run::<_, _, i64>(
+        &context(),
+        r#"
+        pub async fn main() {
+            let rows = broken();
+            let sum = 0;
+
+            while let Some(row) = rows.next().await {
+                sum += row;
+            }
+
+            sum
+        }
+        "#,
+        &["main"],
+        (),
+    )
+    .unwrap_err()
+    .expect_vm_error("expected vm error");
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::Panic { reason } => assert_eq!(reason.to_string(), "cursor closed"),
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
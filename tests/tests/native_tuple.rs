@@ -0,0 +1,98 @@
+use rune_tests::*;
+use runestick::{Module, VmErrorKind};
+use std::sync::Arc;
+
+fn midpoint(p: (f64, f64), q: (f64, f64)) -> (f64, f64) {
+    ((p.0 + q.0) / 2.0, (p.1 + q.1) / 2.0)
+}
+
+fn label(entry: (String, i64, bool)) -> String {
+    format!("{}={}{}", entry.0, entry.1, if entry.2 { "!" } else { "" })
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module
+        .function(&["make"], |n: i64, s: String| (n, s, n > 0))
+        .unwrap();
+    module
+        .function(&["describe"], |(n, s, b): (i64, String, bool)| {
+            format!("{}:{}:{}", n, s, b)
+        })
+        .unwrap();
+    module
+}
+
+fn context() -> Arc<runestick::Context> {
+    let mut module = Module::new();
+    module.function(&["midpoint"], midpoint).unwrap();
+    module.function(&["label"], label).unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+#[test]
+fn test_tuple_round_trip() {
+    let value = rune_n! { module(), (), (i64, String, bool) =>
+        pub fn main() { make(42, "foo") }
+    };
+
+    assert_eq!(value, (42, String::from("foo"), true));
+
+    let value = rune_n! { module(), (), String =>
+        pub fn main() { describe(make(-1, "bar")) }
+    };
+
+    assert_eq!(value, "-1:bar:false");
+}
+
+#[test]
+fn test_tuple_arguments() {
+    let values: ((f64, f64), (f64, f64), String) = run(
+        &context(),
+        r#"
+        pub fn main() {
+            let p = (1.0, 2.0);
+            let m = midpoint(p, (3.0, 4.0));
+            (m, midpoint(p, p), label(("answer", 42, true)))
+        }
+        "#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(
+        values,
+        ((2.0, 3.0), (1.0, 2.0), String::from("answer=42!"))
+    );
+}
+
+#[test]
+fn test_tuple_argument_wrong_length() {
+    let error = run::<_, _, (f64, f64)>(
+        &context(),
+        "pub fn main() { midpoint((1.0, 2.0, 3.0), (4.0, 5.0)) }",
+        &["main"],
+        (),
+    )
+    .unwrap_err()
+    .expect_vm_error("expected vm error");
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::BadArgument { error, arg, .. } => {
+            assert_eq!(arg, 0);
+
+            match error.into_kind() {
+                VmErrorKind::ExpectedTupleLength { actual, expected } => {
+                    assert_eq!(actual, 3);
+                    assert_eq!(expected, 2);
+                }
+                kind => panic!("unexpected error: {:?}", kind),
+            }
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
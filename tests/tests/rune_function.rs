@@ -0,0 +1,29 @@
+use rune_tests::*;
+use runestick::{rune_function, Module};
+
+fn add(a: i64, b: i64) -> i64 {
+    a + b
+}
+
+fn answer() -> i64 {
+    42
+}
+
+fn len(s: &str) -> usize {
+    s.len()
+}
+
+#[test]
+fn test_rune_function() {
+    let mut module = Module::new();
+    rune_function!(module, math::add => add(a, b)).unwrap();
+    rune_function!(module, string::len => len(s)).unwrap();
+    rune_function!(module, answer => answer()).unwrap();
+
+    assert_eq! {
+        rune_n! { module, (), i64 =>
+            pub fn main() { math::add(answer(), string::len("foo")) }
+        },
+        45,
+    };
+}
@@ -0,0 +1,152 @@
+use rune_tests::*;
+use runestick::{allocation, Module, Value, VmErrorKind};
+use std::sync::Arc;
+
+fn repeat(n: i64) -> Vec<i64> {
+    vec![0; n as usize]
+}
+
+fn context() -> Arc<runestick::Context> {
+    let mut module = Module::new();
+    module.function(&["repeat"], repeat).unwrap();
+    module.function(&["identity"], |value: Value| value).unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+fn run_with_budget(limit: usize, source: &str) -> Result<i64, VmErrorKind> {
+    let context = context();
+
+    allocation::with(limit, || run::<_, _, i64>(&context, source, &["main"], ()))
+        .call()
+        .map_err(|e| e.expect_vm_error("expected vm error").into_unwound().0.into_kind())
+}
+
+#[test]
+fn test_allocation_unlimited() {
+    let value = run::<_, _, i64>(
+        &context(),
+        "pub fn main() { let v = []; for n in 0..1000 { v.push([n]); } v.len() }",
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(value, 1000);
+}
+
+#[test]
+fn test_allocation_limit_collections() {
+    const SOURCE: &str = r#"
+    pub fn main() {
+        let v = [];
+
+        for n in 0..1000 {
+            v.push([n, n]);
+        }
+
+        v.len()
+    }
+    "#;
+
+    assert!(matches!(
+        run_with_budget(usize::MAX - 1, SOURCE),
+        Ok(1000)
+    ));
+
+    assert!(matches!(
+        run_with_budget(1024, SOURCE),
+        Err(VmErrorKind::AllocationLimitExceeded { .. })
+    ));
+
+    assert!(matches!(
+        run_with_budget(1024, r#"pub fn main() { let s = `{1}{2}{3}`; 0 }"#),
+        Ok(0)
+    ));
+
+    assert!(matches!(
+        run_with_budget(16, r#"pub fn main() { let o = #{a: 1, b: 2, c: 3}; 0 }"#),
+        Err(VmErrorKind::AllocationLimitExceeded { .. })
+    ));
+}
+
+#[test]
+fn test_allocation_limit_native_return() {
+    assert!(matches!(
+        run_with_budget(1024, r#"pub fn main() { repeat(10).len() }"#),
+        Ok(10)
+    ));
+
+    assert!(matches!(
+        run_with_budget(1024, r#"pub fn main() { repeat(1000).len() }"#),
+        Err(VmErrorKind::AllocationLimitExceeded { .. })
+    ));
+
+    // NB: collections which are shared with the caller weren't allocated by
+    // the native function.
+    assert!(matches!(
+        run_with_budget(
+            256,
+            r#"pub fn main() { let v = [1, 2, 3, 4]; for _ in 0..100 { identity(v); } 0 }"#
+        ),
+        Ok(0)
+    ));
+}
+
+#[test]
+fn test_allocation_limit_growth() {
+    let cases = [
+        "let v = []; for n in 0..1000 { v.push(n); }",
+        "let v = []; v.extend(0..1000);",
+        "let v = []; v.reserve(1000);",
+        "let s = String::new(); for n in 0..1000 { s.push_str(\"abcdefgh\"); }",
+        "let s = String::new(); for n in 0..1000 { s += \"abcdefgh\"; }",
+        "let b = std::bytes::Bytes::new(); for n in 0..1000 { b.extend_str(\"abcdefgh\"); }",
+        "let o = #{}; for n in 0..1000 { o.insert(`{n}`, n); }",
+    ];
+
+    for case in cases.iter() {
+        let source = format!("pub fn main() {{ {} 0 }}", case);
+
+        assert!(
+            matches!(run_with_budget(usize::MAX - 1, &source), Ok(0)),
+            "{}",
+            case
+        );
+
+        assert!(
+            matches!(
+                run_with_budget(1024, &source),
+                Err(VmErrorKind::AllocationLimitExceeded { .. })
+            ),
+            "{}",
+            case
+        );
+    }
+}
+
+#[test]
+fn test_allocation_limit_with_capacity() {
+    // NB: these would abort the process if the capacity was allocated before
+    // it was taken from the budget.
+    let cases = [
+        "Vec::with_capacity(1099511627776)",
+        "String::with_capacity(1099511627776)",
+        "std::bytes::Bytes::with_capacity(1099511627776)",
+    ];
+
+    for case in cases.iter() {
+        let source = format!("pub fn main() {{ let _ = {}; 0 }}", case);
+
+        assert!(
+            matches!(
+                run_with_budget(1024, &source),
+                Err(VmErrorKind::AllocationLimitExceeded { .. })
+            ),
+            "{}",
+            case
+        );
+    }
+}
@@ -84,3 +84,59 @@ fn test_assign_assign_exprs() {
         }
     };
 }
+
+#[test]
+fn test_assign_nested_index() {
+    assert_eq! {
+        vec![vec![5, 2], vec![3, 4]],
+        rune! { Vec<Vec<i64>> =>
+            pub fn main() {
+                let matrix = [[1, 2], [3, 4]];
+                matrix[0][0] = 5;
+                matrix
+            }
+        }
+    };
+}
+
+#[test]
+fn test_assign_index_binop() {
+    assert_eq! {
+        (vec![vec![1, 12], vec![6, 4]], 3, String::from("hello world")),
+        rune! { (Vec<Vec<i64>>, i64, String) =>
+            pub fn main() {
+                let matrix = [[1, 2], [3, 4]];
+                let n = 1;
+                matrix[0][n] += 10;
+                matrix[n][0] *= n + 1;
+
+                let object = #{count: 1, name: "hello"};
+                object["count"] += 2;
+                object["name"] += " world";
+
+                (matrix, object.count, object.name)
+            }
+        }
+    };
+}
+
+#[test]
+fn test_assign_index_binop_evaluates_once() {
+    assert_eq! {
+        (vec![0, 5], 1),
+        rune! { (Vec<i64>, i64) =>
+            pub fn main() {
+                let calls = [0];
+                let values = [0, 0];
+
+                let index = || {
+                    calls[0] += 1;
+                    1
+                };
+
+                values[index()] += 5;
+                (values, calls[0])
+            }
+        }
+    };
+}
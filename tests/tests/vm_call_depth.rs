@@ -0,0 +1,75 @@
+use rune_tests::*;
+use runestick::{Function, Module, Value, VmError, VmErrorKind};
+
+#[test]
+fn test_runaway_recursion() {
+    assert_vm_error!(
+        r#"
+        fn recurse(n) { recurse(n + 1) + 1 }
+        pub fn main() { recurse(0) }
+        "#,
+        VmErrorKind::StackOverflow { max_call_depth } => {
+            assert_eq!(max_call_depth, 16384);
+        }
+    );
+}
+
+#[test]
+fn test_max_call_depth() {
+    let mut vm = rune_vm! {
+        fn depth(n) { if n == 0 { 0 } else { depth(n - 1) + 1 } }
+        pub fn main(n) { depth(n) }
+    };
+
+    vm.set_max_call_depth(10);
+
+    let value = vm.clone().execute(&["main"], (8i64,)).unwrap().complete().unwrap();
+    assert_eq!(value.into_integer().unwrap(), 8);
+
+    let error = vm.execute(&["main"], (10i64,)).unwrap().complete().unwrap_err();
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::StackOverflow { max_call_depth } => {
+            assert_eq!(max_call_depth, 10);
+        }
+        actual => panic!("expected stack overflow but was `{:?}`", actual),
+    }
+}
+
+#[test]
+fn test_max_call_depth_through_native() {
+    let mut module = Module::new();
+    module
+        .function(&["invoke"], |f: Function| -> Result<Value, VmError> {
+            f.call(())
+        })
+        .unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let mut vm = vm_with_source(
+        &context,
+        r#"
+        fn depth(n) { if n == 0 { 0 } else { invoke(|| depth(n - 1)) + 1 } }
+        pub fn main(n) { depth(n) }
+        "#,
+    )
+    .unwrap();
+
+    vm.set_max_call_depth(10);
+
+    let value = vm.clone().execute(&["main"], (3i64,)).unwrap().complete().unwrap();
+    assert_eq!(value.into_integer().unwrap(), 3);
+
+    // NB: each callback runs in a new virtual machine, which counts its calls
+    // on top of the calls of the virtual machine calling `invoke`.
+    let error = vm.execute(&["main"], (10i64,)).unwrap().complete().unwrap_err();
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::StackOverflow { max_call_depth } => {
+            assert_eq!(max_call_depth, 10);
+        }
+        actual => panic!("expected stack overflow but was `{:?}`", actual),
+    }
+}
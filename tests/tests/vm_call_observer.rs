@@ -0,0 +1,79 @@
+use rune_tests::*;
+use runestick::{Any, CallPhase, Item, Module, VmError};
+use std::sync::{Arc, Mutex};
+
+#[derive(Any)]
+struct Counter {
+    n: i64,
+}
+
+impl Counter {
+    fn increment(&mut self, amount: i64) {
+        self.n += amount;
+    }
+
+    fn fail(&self) -> Result<(), VmError> {
+        Err(VmError::panic("failed"))
+    }
+}
+
+#[test]
+fn test_call_observer() {
+    let mut module = Module::with_item(&["counter"]);
+    module.ty::<Counter>().unwrap();
+    module
+        .function(&["Counter", "new"], || Counter { n: 0 })
+        .unwrap();
+    module.inst_fn("increment", Counter::increment).unwrap();
+    module.inst_fn("fail", Counter::fail).unwrap();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    context.set_global(&["LIMIT"], 10i64).unwrap();
+
+    let observed = calls.clone();
+
+    context.set_call_observer(Box::new(move |item, args, phase| {
+        observed.lock().unwrap().push((item.clone(), args, phase));
+    }));
+
+    let context = Arc::new(context);
+
+    let result = run::<_, _, ()>(
+        &context,
+        r#"
+        pub fn main() {
+            let counter = counter::Counter::new();
+            counter.increment(LIMIT);
+            counter.fail();
+        }
+        "#,
+        &["main"],
+        (),
+    );
+
+    assert!(result.is_err());
+
+    let calls = calls.lock().unwrap();
+
+    let new = Item::with_item(&["counter", "Counter", "new"]);
+    let limit = Item::with_item(&["LIMIT"]);
+    let increment = Item::with_item(&["counter", "Counter", "increment"]);
+    let fail = Item::with_item(&["counter", "Counter", "fail"]);
+
+    assert_eq!(
+        &*calls,
+        &[
+            (new.clone(), 0, CallPhase::Before),
+            (new, 0, CallPhase::After { ok: true }),
+            (limit.clone(), 0, CallPhase::Before),
+            (limit, 0, CallPhase::After { ok: true }),
+            (increment.clone(), 2, CallPhase::Before),
+            (increment, 2, CallPhase::After { ok: true }),
+            (fail.clone(), 1, CallPhase::Before),
+            (fail, 1, CallPhase::After { ok: false }),
+        ]
+    );
+}
@@ -0,0 +1,49 @@
+use rune_tests::*;
+use runestick::{Module, VmErrorKind};
+use std::sync::Arc;
+
+fn explode(n: i64) -> i64 {
+    if n > 0 {
+        panic!("boom {}", n);
+    }
+
+    n
+}
+
+fn context(catch_unwind: bool) -> Arc<runestick::Context> {
+    let mut module = Module::new();
+    module.function(&["explode"], explode).unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    context.set_catch_unwind(catch_unwind);
+    Arc::new(context)
+}
+
+#[test]
+fn test_catch_unwind() {
+    let context = context(true);
+
+    let value: i64 = run(&context, "pub fn main() { explode(0) }", &["main"], ()).unwrap();
+    assert_eq!(value, 0);
+
+    let e = run::<_, _, i64>(&context, "pub fn main() { explode(1) }", &["main"], ())
+        .unwrap_err()
+        .expect_vm_error("expected vm error");
+
+    match e.into_unwound().0.into_kind() {
+        VmErrorKind::Panic { reason } => assert_eq!(reason.to_string(), "boom 1"),
+        actual => panic!("expected panic error but got: {:?}", actual),
+    }
+}
+
+#[test]
+fn test_no_catch_unwind() {
+    let context = context(false);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        run::<_, _, i64>(&context, "pub fn main() { explode(1) }", &["main"], ())
+    }));
+
+    assert!(result.is_err());
+}
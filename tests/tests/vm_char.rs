@@ -0,0 +1,49 @@
+use rune_tests::*;
+
+#[test]
+fn test_char_int_conversion() {
+    assert_eq! {
+        rune! { (Option<char>, Option<char>, Option<char>, i64, i64) =>
+            pub fn main() {
+                (
+                    char::from_int(65),
+                    char::from_int(-1),
+                    char::from_int(0xd800),
+                    'A'.to_int(),
+                    char::to_int('å'),
+                )
+            }
+        },
+        (Some('A'), None, None, 65, 229),
+    };
+}
+
+#[test]
+fn test_char_classification() {
+    assert_eq! {
+        rune! { (bool, bool, bool, bool, Option<u32>) =>
+            pub fn main() {
+                (
+                    'a'.is_alphabetic(),
+                    '1'.is_alphabetic(),
+                    '1'.is_numeric(),
+                    char::is_numeric('a'),
+                    'f'.to_digit(16),
+                )
+            }
+        },
+        (true, false, true, false, Some(15)),
+    };
+}
+
+#[test]
+fn test_char_case_conversion() {
+    assert_eq! {
+        rune! { (String, String, String) =>
+            pub fn main() {
+                ('a'.to_uppercase(), 'Å'.to_lowercase(), 'ß'.to_uppercase())
+            }
+        },
+        (String::from("A"), String::from("å"), String::from("SS")),
+    };
+}
@@ -0,0 +1,95 @@
+use rune_tests::*;
+use runestick::{Module, VmErrorKind};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn test_async_function_timeout() {
+    let mut module = Module::new();
+
+    module
+        .async_function_timeout(&["pending"], Duration::from_millis(10), || {
+            std::future::pending::<()>()
+        })
+        .unwrap();
+
+    module
+        .async_function_timeout(&["ready"], Duration::from_millis(10), || async { 42i64 })
+        .unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    let context = Arc::new(context);
+
+    let value: i64 = run(&context, "pub async fn main() { ready().await }", &["main"], ()).unwrap();
+    assert_eq!(value, 42);
+
+    let e = run::<_, _, ()>(&context, "pub async fn main() { pending().await }", &["main"], ())
+        .unwrap_err()
+        .expect_vm_error("expected vm error");
+
+    let (e, _) = e.into_unwound();
+
+    match e.into_kind() {
+        VmErrorKind::HostTimeout { duration } => {
+            assert_eq!(duration, Duration::from_millis(10));
+        }
+        actual => panic!("expected host timeout but was `{:?}`", actual),
+    }
+}
+
+#[test]
+fn test_async_function_timeout_completes_after_pending() {
+    let mut module = Module::new();
+
+    module
+        .async_function_timeout(&["slow"], Duration::from_secs(60), || async {
+            let mut yielded = false;
+
+            std::future::poll_fn(move |cx| {
+                if yielded {
+                    return std::task::Poll::Ready(());
+                }
+
+                yielded = true;
+                cx.waker().wake_by_ref();
+                std::task::Poll::Pending
+            })
+            .await;
+
+            42i64
+        })
+        .unwrap();
+
+    module
+        .async_function_timeout(&["pending"], Duration::from_millis(20), || {
+            std::future::pending::<()>()
+        })
+        .unwrap();
+
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&module).unwrap();
+    let context = Arc::new(context);
+
+    let source = r#"
+    pub async fn main() {
+        let a = slow().await;
+        let b = slow().await;
+        a + b
+    }
+    "#;
+
+    let value: i64 = run(&context, source, &["main"], ()).unwrap();
+    assert_eq!(value, 84);
+
+    for _ in 0..2 {
+        let e = run::<_, _, ()>(&context, "pub async fn main() { pending().await }", &["main"], ())
+            .unwrap_err()
+            .expect_vm_error("expected vm error");
+
+        assert!(matches!(
+            e.into_unwound().0.into_kind(),
+            VmErrorKind::HostTimeout { .. }
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+use rune_tests::*;
+use rune::Diagnostic;
+use runestick::{OverflowPolicy, VmErrorKind};
+use std::sync::Arc;
+
+fn context(policy: OverflowPolicy) -> Arc<runestick::Context> {
+    let mut context = rune_modules::default_context().unwrap();
+    context.set_overflow_policy(policy);
+    Arc::new(context)
+}
+
+#[test]
+fn test_overflow_wrapping() {
+    let context = context(OverflowPolicy::Wrapping);
+
+    let values = run::<_, _, (i64, i64, i64, i64)>(
+        &context,
+        r#"
+        pub fn main() {
+            let a = 9223372036854775807;
+            a += 1;
+            (9223372036854775807 + 2, -9223372036854775808 - 1, 4611686018427387904 * 2, a)
+        }
+        "#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(
+        values,
+        (
+            i64::MIN + 1,
+            i64::MAX,
+            i64::MIN,
+            i64::MIN
+        )
+    );
+}
+
+#[test]
+fn test_overflow_checked() {
+    let context = context(OverflowPolicy::Checked);
+
+    let error = run::<_, _, i64>(
+        &context,
+        r#"pub fn main() { let a = -9223372036854775808; a -= 1; a }"#,
+        &["main"],
+        (),
+    )
+    .unwrap_err()
+    .expect_vm_error("expected vm error");
+
+    assert!(matches!(error.into_unwound().0.kind(), VmErrorKind::Underflow));
+}
+
+#[test]
+fn test_overflow_panicking() {
+    let context = context(OverflowPolicy::Panicking);
+
+    let cases = [
+        ("9223372036854775807 + 1", "attempt to add with overflow"),
+        ("-9223372036854775808 - 1", "attempt to subtract with overflow"),
+        ("4611686018427387904 * 2", "attempt to multiply with overflow"),
+    ];
+
+    for (expr, expected) in cases.iter() {
+        let error = run::<_, _, i64>(
+            &context,
+            &format!("pub fn main() {{ {} }}", expr),
+            &["main"],
+            (),
+        )
+        .unwrap_err()
+        .expect_vm_error("expected vm error");
+
+        match error.into_unwound().0.kind() {
+            VmErrorKind::Panic { reason } => assert_eq!(reason.to_string(), *expected),
+            kind => panic!("expected panic, got {:?}", kind),
+        }
+    }
+
+    let value = run::<_, _, i64>(&context, r#"pub fn main() { 1 + 2 * 3 - 4 }"#, &["main"], ())
+        .unwrap();
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn test_overflow_vec_sum() {
+    let source = r#"pub fn main() { [9223372036854775807, 1, 1].sum() }"#;
+
+    let value = run::<_, _, Option<i64>>(
+        &context(OverflowPolicy::Wrapping),
+        source,
+        &["main"],
+        (),
+    )
+    .unwrap();
+    assert_eq!(value, Some(i64::MIN + 1));
+
+    let error = run::<_, _, Option<i64>>(&context(OverflowPolicy::Panicking), source, &["main"], ())
+        .unwrap_err()
+        .expect_vm_error("expected vm error");
+
+    match error.into_unwound().0.kind() {
+        VmErrorKind::Panic { reason } => {
+            assert_eq!(reason.to_string(), "attempt to add with overflow")
+        }
+        kind => panic!("expected panic, got {:?}", kind),
+    }
+}
+
+/// Get the message of the error at the root of the given error.
+fn root_cause(mut error: &dyn std::error::Error) -> String {
+    while let Some(source) = error.source() {
+        error = source;
+    }
+
+    error.to_string()
+}
+
+#[test]
+fn test_overflow_const() {
+    let source = r#"
+    const MAX = 9223372036854775807;
+    const WRAPPED = MAX + 1;
+    const FOLDED = { let n = MAX; n += 1; n - 1 };
+
+    pub fn main() { (WRAPPED, FOLDED) }
+    "#;
+
+    let value = run::<_, _, (i64, i64)>(
+        &context(OverflowPolicy::Wrapping),
+        source,
+        &["main"],
+        (),
+    )
+    .unwrap();
+    assert_eq!(value, (i64::MIN, i64::MAX));
+
+    let cases = [
+        (OverflowPolicy::Checked, "integer overflow"),
+        (OverflowPolicy::Panicking, "attempt to add with overflow"),
+    ];
+
+    for (policy, expected) in cases.iter() {
+        let context = context(*policy);
+        let diagnostics = compile_source(&context, source).unwrap_err();
+
+        // NB: both constants fail to evaluate.
+        match diagnostics.diagnostics() {
+            [Diagnostic::Error(a), Diagnostic::Error(b)] => {
+                assert_eq!(root_cause(a), *expected);
+                assert_eq!(root_cause(b), *expected);
+            }
+            diagnostics => panic!("unexpected diagnostics: {:?}", diagnostics),
+        }
+    }
+}
+
+#[test]
+fn test_overflow_const_direction() {
+    let context = context(OverflowPolicy::Checked);
+
+    let cases = [
+        ("9223372036854775807 - (0 - 1)", "integer overflow"),
+        ("0 - 9223372036854775807 - 2", "integer underflow"),
+        ("(0 - 4611686018427387904) * 3", "integer underflow"),
+    ];
+
+    for (expr, expected) in cases.iter() {
+        let source = format!("const VALUE = {}; pub fn main() {{ VALUE }}", expr);
+        let diagnostics = compile_source(&context, &source).unwrap_err();
+
+        match diagnostics.diagnostics() {
+            [Diagnostic::Error(error)] => assert_eq!(root_cause(error), *expected, "{}", expr),
+            diagnostics => panic!("unexpected diagnostics: {:?}", diagnostics),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use rune_tests::*;
+use runestick::VmErrorKind;
+
+#[test]
+fn test_string_split() {
+    assert_eq! {
+        rune!(Vec<String> => pub fn main() { "a,b,,c".split(",") }),
+        vec!["a", "b", "", "c"],
+    };
+
+    assert_eq! {
+        rune!(Vec<String> => pub fn main() { "a b c".split(' ') }),
+        vec!["a", "b", "c"],
+    };
+
+    assert_eq! {
+        rune!(Vec<String> => pub fn main() { "".split(",") }),
+        vec![""],
+    };
+
+    assert_eq! {
+        rune!(usize => pub fn main() { "1-2-3".split("-").len() }),
+        3,
+    };
+}
+
+#[test]
+fn test_string_splitn() {
+    assert_eq! {
+        rune!(Vec<String> => pub fn main() { "a,b,c".splitn(2, ",") }),
+        vec!["a", "b,c"],
+    };
+
+    assert_eq! {
+        rune!(Vec<String> => pub fn main() { "a,b,c".splitn(0, ",") }),
+        Vec::<String>::new(),
+    };
+}
+
+#[test]
+fn test_string_index_by_char() {
+    assert_eq! {
+        rune!(char => pub fn main() { "aäb"[1] }),
+        'ä',
+    };
+
+    assert_eq! {
+        rune!(String => pub fn main() { "häj då"[1..5] }),
+        "äj d",
+    };
+
+    assert_eq! {
+        rune!(String => pub fn main() { "häj"[1..=2] }),
+        "äj",
+    };
+
+    assert_eq! {
+        rune!(String => pub fn main() { "häj"[1..] }),
+        "äj",
+    };
+
+    assert_eq! {
+        rune!(Option<String> => pub fn main() { "häj".get(2..4) }),
+        None,
+    };
+
+    assert_eq! {
+        rune!(Option<char> => pub fn main() { "häj".get(3) }),
+        None,
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { "häj"[3] }"#,
+        VmErrorKind::OutOfRange { index, len } => {
+            assert_eq!(index.to_string(), "3");
+            assert_eq!(len.to_string(), "3");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { "häj"[2..1] }"#,
+        VmErrorKind::RangeOutOfBounds { start, end, len } => {
+            assert_eq!(start.to_string(), "2");
+            assert_eq!(end.to_string(), "1");
+            assert_eq!(len.to_string(), "3");
+        }
+    );
+}
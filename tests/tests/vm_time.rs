@@ -0,0 +1,98 @@
+use rune_tests::*;
+use runestick::Module;
+use std::time::Duration;
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module
+        .function(&["timeout_millis"], |timeout: Duration| {
+            timeout.as_millis() as i64
+        })
+        .unwrap();
+    module
+        .function(&["default_timeout"], || Duration::from_secs(2))
+        .unwrap();
+    module
+}
+
+#[test]
+fn test_duration() {
+    let values = rune_n! { module(), (), (i64, u64, bool, bool, f64) =>
+        use std::time::Duration;
+
+        pub fn main() {
+            let a = Duration::from_millis(1500);
+            let b = Duration::from_secs(1);
+            let sum = a + b;
+
+            (
+                timeout_millis(sum),
+                (a - b).as_millis(),
+                Duration::from_micros(1000) == Duration::from_millis(1),
+                b.checked_sub(a).is_none(),
+                default_timeout().as_secs_f64(),
+            )
+        }
+    };
+
+    assert_eq!(values, (2500, 500, true, true, 2.0));
+}
+
+#[test]
+fn test_duration_errors() {
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            std::time::Duration::from_secs(1) - std::time::Duration::from_secs(2)
+        }
+        "#,
+        Underflow => {}
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { std::time::Duration::from_secs_f64(-1.0) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "cannot convert `-1` seconds into a duration");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { std::time::Duration::from_secs_f64(18446744073709551615.0) }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "cannot convert `18446744073709552000` seconds into a duration"
+            );
+        }
+    );
+}
+
+#[test]
+fn test_instant() {
+    let values = rune_n! { module(), (), (bool, bool) =>
+        use std::time::{Duration, Instant};
+
+        pub fn main() {
+            let start = Instant::now();
+            let later = start + Duration::from_secs(10);
+
+            (
+                later.duration_since(start) == Duration::from_secs(10),
+                start.duration_since(later) == Duration::from_secs(0),
+            )
+        }
+    };
+
+    assert_eq!(values, (true, true));
+}
+
+#[test]
+fn test_duration_debug() {
+    let value = rune!(String =>
+        pub fn main() {
+            format!("{:?}", std::time::Duration::from_millis(1500))
+        }
+    );
+
+    assert_eq!(value, "1.5s");
+}
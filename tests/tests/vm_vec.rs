@@ -0,0 +1,903 @@
+use rune_tests::*;
+use runestick::{FromValue as _, Shared, VmErrorKind};
+use std::sync::Arc;
+
+/// Run `main` with an empty vector which is shared with the host, expecting
+/// it to panic with the given reason.
+///
+/// Returns the contents of the vector afterwards, so that tests can check how
+/// far an operation got before it failed.
+fn run_until_panic(source: &str, expected: &str) -> Vec<i64> {
+    let context = Arc::new(rune_modules::default_context().unwrap());
+    let log = Shared::new(runestick::Vec::new());
+
+    let error = run::<_, _, ()>(&context, source, &["main"], (log.clone(),))
+        .unwrap_err()
+        .expect_vm_error("expected vm error");
+
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::Panic { reason } => assert_eq!(reason.to_string(), expected),
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+
+    let log = log.borrow_ref().unwrap();
+    log.iter().map(|v| i64::from_value(v.clone()).unwrap()).collect()
+}
+
+#[test]
+fn test_vec_flatten() {
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { [[1, 2], [], [3]].flatten() }),
+        vec![1, 2, 3],
+    };
+
+    assert_eq! {
+        rune!(usize => pub fn main() { [[[1, 2]], [[3]]].flatten().len() }),
+        2,
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { [[1, 2], 3].flatten() }"#,
+        VmErrorKind::Expected { actual, .. } => {
+            assert_eq!(actual.to_string(), "integer");
+        }
+    );
+}
+
+#[test]
+fn test_vec_map_filter() {
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { [1, 2, 3].map(|n| n * 2) }),
+        vec![2, 4, 6],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { [1, 2, 3, 4].filter(|n| n % 2 == 0) }),
+        vec![2, 4],
+    };
+
+    assert_eq! {
+        rune! { Vec<i64> =>
+            pub fn main() {
+                let v = [1, 2, 3].map(|n| n + 1);
+                v.push(5);
+                v
+            }
+        },
+        vec![2, 3, 4, 5],
+    };
+
+    // NB: both stop calling the closure at the first error.
+    let log = run_until_panic(
+        r#"
+        pub fn main(log) {
+            [1, 2, 3].map(|n| { log.push(n); if n == 2 { panic("stop") } n })
+        }
+        "#,
+        "stop",
+    );
+
+    assert_eq!(log, vec![1, 2]);
+
+    let log = run_until_panic(
+        r#"
+        pub fn main(log) {
+            [1, 2, 3].filter(|n| { log.push(n); if n == 2 { panic("stop") } true })
+        }
+        "#,
+        "stop",
+    );
+
+    assert_eq!(log, vec![1, 2]);
+}
+
+#[test]
+fn test_vec_for_each_mut() {
+    assert_eq! {
+        rune! { Vec<i64> =>
+            pub fn main() {
+                let v = [1, 2, 3];
+                v.for_each_mut(|n| n * 10);
+                v
+            }
+        },
+        vec![10, 20, 30],
+    };
+
+    // NB: elements before the one which errors have already been replaced.
+    let v = run_until_panic(
+        r#"
+        pub fn main(v) {
+            v.extend([1, 2, 3]);
+            v.for_each_mut(|n| if n == 2 { panic("stop") } else { n * 10 });
+        }
+        "#,
+        "stop",
+    );
+
+    assert_eq!(v, vec![10, 2, 3]);
+}
+
+#[test]
+fn test_vec_sum_min_max() {
+    assert_eq!(rune!(Option<i64> => pub fn main() { [1, 2, 3].sum() }), Some(6));
+    assert_eq!(rune!(Option<f64> => pub fn main() { [1.5, 2.5].sum() }), Some(4.0));
+    assert_eq!(rune!(Option<i64> => pub fn main() { [].sum() }), None);
+
+    assert_eq! {
+        rune!(Option<String> => pub fn main() { ["a", "b", "c"].sum() }),
+        Some(String::from("abc")),
+    };
+
+    assert_eq!(rune!(Option<i64> => pub fn main() { [3, 1, 2].min() }), Some(1));
+    assert_eq!(rune!(Option<i64> => pub fn main() { [3, 1, 2].max() }), Some(3));
+    assert_eq!(rune!(Option<f64> => pub fn main() { [0.5, -1.0].min() }), Some(-1.0));
+    assert_eq!(rune!(Option<i64> => pub fn main() { [].max() }), None);
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2.0].sum() }"#,
+        VmErrorKind::UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, "+");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { [1.0, 0.0 / 0.0].max() }"#,
+        VmErrorKind::Incomparable { .. } => {}
+    );
+}
+
+#[test]
+fn test_vec_partition() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let (evens, odds) = [1, 2, 3, 4, 5].partition(|x| x % 2 == 0);
+                (evens, odds)
+            }
+        },
+        (vec![2, 4], vec![1, 3, 5]),
+    };
+
+    // NB: the predicate is called in order, and not for any elements after
+    // the one which errors.
+    let log = run_until_panic(
+        r#"
+        pub fn main(log) {
+            [1, 2, 3, 4].partition(|x| { log.push(x); if x == 3 { panic("stop") } x % 2 == 0 })
+        }
+        "#,
+        "stop",
+    );
+
+    assert_eq!(log, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_vec_all_any() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool) => pub fn main() {
+            let v = [1, 2, 3];
+            (v.all(|x| x > 0), v.all(|x| x > 1), v.any(|x| x > 2), v.any(|x| x > 3))
+        }),
+        (true, false, true, false),
+    };
+
+    assert_eq! {
+        rune!((bool, bool) => pub fn main() { ([].all(|x| false), [].any(|x| true)) }),
+        (true, false),
+    };
+
+    assert_eq! {
+        rune!(i64 => pub fn main() {
+            let calls = [];
+            [1, 2, 3, 4].all(|x| { calls.push(x); x < 2 });
+            [1, 2, 3, 4].any(|x| { calls.push(x); x > 2 });
+            calls.len()
+        }),
+        5,
+    };
+}
+
+#[test]
+fn test_vec_find() {
+    assert_eq! {
+        rune!(Option<usize> => pub fn main() { [1, 2, 3, 4].find_index(|x| x > 2) }),
+        Some(2),
+    };
+
+    assert_eq! {
+        rune!(Option<usize> => pub fn main() { [1, 2].find_index(|x| x > 2) }),
+        None,
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => pub fn main() { [1, 2, 3, 4].find(|x| x % 2 == 0) }),
+        Some(2),
+    };
+
+    assert_eq! {
+        rune! { (Option<usize>, Option<i64>) =>
+            pub fn main() {
+                let window = [1, 2, 3, 4, 5].get(2..)?;
+                (window.position(|x| x % 2 == 0), window.find(|x| x > 4))
+            }
+        },
+        (Some(1), Some(5)),
+    };
+
+    assert_eq! {
+        rune! { Vec<i64> =>
+            pub fn main() {
+                let seen = [];
+                [1, 2, 3, 4].find(|x| { seen.push(x); x == 2 });
+                seen
+            }
+        },
+        vec![1, 2],
+    };
+
+    // NB: the search stops at the first match, so an element which would
+    // error is never reached.
+    assert_eq! {
+        rune!(Option<i64> => pub fn main() {
+            [1, 2, 3].find(|x| if x == 3 { panic("stop") } else { x == 2 })
+        }),
+        Some(2),
+    };
+
+    let log = run_until_panic(
+        r#"
+        pub fn main(log) {
+            [1, 2, 3].find_index(|x| { log.push(x); if x == 2 { panic("stop") } x == 3 })
+        }
+        "#,
+        "stop",
+    );
+
+    assert_eq!(log, vec![1, 2]);
+}
+
+#[test]
+fn test_vec_sort_int() {
+    assert_eq! {
+        rune! { Vec<i64> =>
+            pub fn main() {
+                let v = [3, 1, 2];
+                v.sort_int();
+                v
+            }
+        },
+        vec![1, 2, 3],
+    };
+
+    // Sorting a mixed vector errors the same way every time, instead of
+    // depending on where the values happen to live in memory.
+    for _ in 0..2 {
+        assert_vm_error!(
+            r#"pub fn main() { let v = [3, "a", 1, 2.0]; v.sort_int(); v }"#,
+            VmErrorKind::Expected { expected, actual } => {
+                assert_eq!(expected.to_string(), "integer");
+                assert_eq!(actual.to_string(), "String");
+            }
+        );
+    }
+}
+
+#[test]
+fn test_vec_extend_from_slice() {
+    assert_eq! {
+        rune! { Vec<i64> =>
+            pub fn main() {
+                let v = [1, 2];
+                v.extend_from_slice([3, 4]);
+                v
+            }
+        },
+        vec![1, 2, 3, 4],
+    };
+
+    assert_eq! {
+        rune! { Vec<i64> =>
+            pub fn main() {
+                let v = [1, 2];
+                v.extend_from_slice(v);
+                v
+            }
+        },
+        vec![1, 2, 1, 2],
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { let v = [1]; v.extend_from_slice(2); v }"#,
+        VmErrorKind::BadArgument { arg, .. } => {
+            assert_eq!(arg, 1);
+        }
+    );
+}
+
+#[test]
+fn test_vec_partial_cmp() {
+    assert_eq! {
+        rune!(Option<Vec<i64>> => pub fn main() { [[2], [1, 5], [1, 2], [3]].min() }),
+        Some(vec![1, 2]),
+    };
+
+    assert_eq! {
+        rune!(Option<Vec<i64>> => pub fn main() { [[1, 2], [1, 2, 0], [1]].max() }),
+        Some(vec![1, 2, 0]),
+    };
+
+    assert_eq! {
+        rune!(bool => pub fn main() { [1, 2] == [1, 2] && [1, 2] != [1, 2, 3] }),
+        true,
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { [[1], [1.0]].min() }"#,
+        VmErrorKind::UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, "partial_cmp");
+        }
+    );
+}
+
+#[test]
+fn test_vec_cmp_ops() {
+    assert_eq! {
+        rune!((bool, bool, bool, bool, bool) => pub fn main() {
+            ([1, 2] < [1, 3], [1, 2] > [1], [1, 2] <= [1, 2], [2] >= [1, 5], [[1], [2]] < [[1], [1]])
+        }),
+        (true, true, true, true, false),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { #{} < #{} }"#,
+        VmErrorKind::UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, "<");
+        }
+    );
+}
+
+#[test]
+fn test_vec_range_index() {
+    assert_eq!(rune!(Vec<i64> => pub fn main() { [1, 2, 3, 4][1..3] }), vec![2, 3]);
+    assert_eq!(rune!(Vec<i64> => pub fn main() { [1, 2, 3, 4][1..=3] }), vec![2, 3, 4]);
+    assert_eq!(rune!(Vec<i64> => pub fn main() { [1, 2, 3, 4][2..] }), vec![3, 4]);
+    assert_eq!(rune!(Vec<i64> => pub fn main() { [1, 2, 3, 4][..2] }), vec![1, 2]);
+    assert_eq!(rune!(Vec<i64> => pub fn main() { [1, 2, 3, 4][4..] }), Vec::<i64>::new());
+
+    assert_eq! {
+        rune!(Option<Vec<i64>> => pub fn main() { [1, 2, 3].get(1..) }),
+        Some(vec![2, 3]),
+    };
+
+    assert_eq!(rune!(Option<i64> => pub fn main() { [1, 2, 3].get(1) }), Some(2));
+    assert_eq!(rune!(Option<i64> => pub fn main() { [1, 2, 3].get(3) }), None);
+    assert_eq!(rune!(Option<Vec<i64>> => pub fn main() { [1, 2, 3].get(2..4) }), None);
+    assert_eq!(rune!(Option<Vec<i64>> => pub fn main() { [1, 2, 3].get(2..1) }), None);
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2, 3][1..4] }"#,
+        VmErrorKind::RangeOutOfBounds { start, end, len } => {
+            assert_eq!(start.to_string(), "1");
+            assert_eq!(end.to_string(), "4");
+            assert_eq!(len.to_string(), "3");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2, 3][2..1] }"#,
+        VmErrorKind::RangeOutOfBounds { start, end, .. } => {
+            assert_eq!(start.to_string(), "2");
+            assert_eq!(end.to_string(), "1");
+        }
+    );
+}
+
+#[test]
+fn test_vec_drain() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let v = [1, 2, 3, 4, 5];
+                let drained = v.drain(1..3);
+                (v, drained)
+            }
+        },
+        (vec![1, 4, 5], vec![2, 3]),
+    };
+
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let v = [1, 2, 3];
+                let drained = v.drain(1..1);
+                (v, drained)
+            }
+        },
+        (vec![1, 2, 3], vec![]),
+    };
+
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let v = [1, 2, 3];
+                let drained = v.drain(..);
+                (v, drained)
+            }
+        },
+        (vec![], vec![1, 2, 3]),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { let v = [1, 2, 3]; v.drain(2..5) }"#,
+        VmErrorKind::RangeOutOfBounds { start, end, len } => {
+            assert_eq!(start.to_string(), "2");
+            assert_eq!(end.to_string(), "5");
+            assert_eq!(len.to_string(), "3");
+        }
+    );
+}
+
+#[test]
+fn test_vec_splice() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>, Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let a = [1, 2, 3, 4, 5];
+                let removed = a.splice(1..3, [7, 8, 9]);
+                let b = [1, 2, 3];
+                b.splice(1.., []);
+                (a, removed, b, [1, 2].splice(1..1, [3]))
+            }
+        },
+        (vec![1, 7, 8, 9, 4, 5], vec![2, 3], vec![1], vec![]),
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { let v = [1, 2, 3]; v.splice(..1, v); v }),
+        vec![1, 2, 3, 2, 3],
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { let v = [1, 2, 3]; v.splice(2..5, []) }"#,
+        VmErrorKind::RangeOutOfBounds { start, end, len } => {
+            assert_eq!(start.to_string(), "2");
+            assert_eq!(end.to_string(), "5");
+            assert_eq!(len.to_string(), "3");
+        }
+    );
+}
+
+#[test]
+fn test_vec_rchunks() {
+    assert_eq! {
+        rune! { Vec<Vec<i64>> =>
+            pub fn main() {
+                [1, 2, 3, 4, 5].rchunks(2).collect_vec()
+            }
+        },
+        vec![vec![4, 5], vec![2, 3], vec![1]],
+    };
+
+    assert_eq! {
+        rune! { Vec<Vec<i64>> =>
+            pub fn main() {
+                [1, 2, 3, 4].rchunks(2).rev().collect_vec()
+            }
+        },
+        vec![vec![1, 2], vec![3, 4]],
+    };
+
+    assert_eq! {
+        rune!(Vec<Vec<i64>> => pub fn main() { [].rchunks(3).collect_vec() }),
+        Vec::<Vec<i64>>::new(),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2, 3].rchunks(0) }"#,
+        VmErrorKind::Panic { reason } => {
+            assert_eq!(reason.to_string(), "chunk size must be non-zero");
+        }
+    );
+}
+
+#[test]
+fn test_vec_concat() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let a = [1, 2];
+                let b = [3];
+                let c = Vec::concat([a, [], b, [4, 5]]);
+                a.push(10);
+                (c, a)
+            }
+        },
+        (vec![1, 2, 3, 4, 5], vec![1, 2, 10]),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { Vec::concat([[1], 2]) }"#,
+        VmErrorKind::Expected { expected, actual } => {
+            assert_eq!(expected.to_string(), "Vec");
+            assert_eq!(actual.to_string(), "integer");
+        }
+    );
+}
+
+#[test]
+fn test_vec_add() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>, Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let v = [1, 2, 3, 4];
+                let a = v.get(..2)?;
+                let b = v.get(3..)?;
+                let c = a + b;
+                let d = b.concat_with(a) + [];
+                (c, d, a, v.concat_with(v))
+            }
+        },
+        (vec![1, 2, 4], vec![4, 1, 2], vec![1, 2], vec![1, 2, 3, 4, 1, 2, 3, 4]),
+    };
+}
+
+#[test]
+fn test_vec_is_empty() {
+    assert_eq! {
+        rune! { (bool, bool) =>
+            pub fn main() {
+                let v = [];
+                let empty = v.is_empty();
+                v.push(1);
+                (empty, v.is_empty())
+            }
+        },
+        (true, false),
+    };
+}
+
+#[test]
+fn test_vec_truncate() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let a = [1, 2, 3];
+                let b = [1, 2, 3];
+                a.truncate(5);
+                b.truncate(1);
+                (a, b)
+            }
+        },
+        (vec![1, 2, 3], vec![1]),
+    };
+}
+
+#[test]
+fn test_vec_into_iter() {
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { [1, 2, 3].into_iter().rev().collect_vec() }),
+        vec![3, 2, 1],
+    };
+
+    assert_eq! {
+        rune! { Vec<String> =>
+            pub fn main() {
+                let out = [];
+
+                for s in ["a", "b"].into_iter() {
+                    out.push(s);
+                }
+
+                out
+            }
+        },
+        vec![String::from("a"), String::from("b")],
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { let v = [1, 2]; let it = v.into_iter(); v.len() }"#,
+        BadArgument { error, arg: 0, .. } => {
+            assert!(matches!(error.kind(), VmErrorKind::AccessError { .. }));
+        }
+    );
+}
+
+#[test]
+fn test_vec_string_display() {
+    assert_eq! {
+        rune! { String =>
+            pub fn main() {
+                format!("{}", [1, [2.5, "a"], 'c', []])
+            }
+        },
+        "[1, [2.5, a], c, []]",
+    };
+
+    assert_eq! {
+        rune!(String => pub fn main() { format!("{}", [Some(1), None]) }),
+        "[Some(1), None]",
+    };
+}
+
+#[test]
+fn test_vec_capacity() {
+    assert_eq! {
+        rune! { (bool, usize, bool) =>
+            pub fn main() {
+                let v = Vec::with_capacity(16);
+                let empty = v.len();
+                v.push(1);
+                let before = v.capacity() >= 16;
+                v.reserve(100);
+                (before, empty, v.capacity() >= 101)
+            }
+        },
+        (true, 0, true),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { Vec::with_capacity(9223372036854775807) }"#,
+        Panic { reason } => {
+            assert!(reason.to_string().starts_with("failed to reserve capacity for 9223372036854775807 elements"));
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { let v = [1]; v.reserve(9223372036854775807) }"#,
+        Panic { .. } => {}
+    );
+}
+
+#[test]
+fn test_vec_binary_search() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>, i64) =>
+            pub fn main() {
+                let v = [1, 3, 5, 7];
+
+                let found = [1, 5, 7].iter().map(|n| v.binary_search(n)?).collect_vec();
+                let missing = [0, 4, 8].iter().map(|n| match v.binary_search(n) {
+                    Ok(..) => -1,
+                    Err(index) => index,
+                }).collect_vec();
+
+                let window = v.get(1..4)?.binary_search(7)?;
+                (found, missing, window)
+            }
+        },
+        (vec![0, 2, 3], vec![0, 2, 4], 2),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2, 3].binary_search("2") }"#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, "partial_cmp");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { [1.0, 2.0, 3.0].binary_search(0.0 / 0.0) }"#,
+        Incomparable { .. } => {}
+    );
+}
+
+#[test]
+fn test_vec_zip() {
+    assert_eq! {
+        rune! { (Vec<(i64, String)>, Vec<(String, i64)>, usize) =>
+            pub fn main() {
+                let numbers = [1, 2, 3];
+                let names = ["one", "two"];
+
+                (
+                    std::vec::zip(numbers, names),
+                    std::vec::zip(names, numbers),
+                    std::vec::zip(numbers, []).len(),
+                )
+            }
+        },
+        (
+            vec![(1, String::from("one")), (2, String::from("two"))],
+            vec![(String::from("one"), 1), (String::from("two"), 2)],
+            0,
+        ),
+    };
+}
+
+#[test]
+fn test_vec_iter_rev() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let v = [1, 2, 3];
+                let out = [];
+
+                for n in v.iter_rev() {
+                    out.push(n);
+                }
+
+                let it = v.iter_rev();
+                v.push(4);
+
+                (out, it.collect_vec(), v.iter_rev().rev().collect_vec())
+            }
+        },
+        (vec![3, 2, 1], vec![3, 2, 1], vec![1, 2, 3, 4]),
+    };
+}
+
+#[test]
+fn test_vec_starts_ends_with() {
+    assert_eq! {
+        rune! { (bool, bool, bool, bool, bool, bool) =>
+            pub fn main() {
+                let v = [1, "two", [3]];
+
+                (
+                    v.starts_with([1, "two"]),
+                    v.starts_with([]),
+                    v.starts_with([2]),
+                    v.ends_with(["two", [3]]),
+                    v.ends_with([[4]]),
+                    [1].ends_with([0, 1]),
+                )
+            }
+        },
+        (true, true, false, true, false, false),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, 2].starts_with([1, "2"]) }"#,
+        UnsupportedBinaryOperation { op, .. } => {
+            assert_eq!(op, "==");
+        }
+    );
+}
+
+#[test]
+fn test_vec_dedup_by_key() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let records = [
+                    #{id: 1, msg: "a"},
+                    #{id: 1, msg: "b"},
+                    #{id: 2, msg: "c"},
+                    #{id: 1, msg: "d"},
+                    #{id: 1, msg: "e"},
+                ];
+
+                records.dedup_by_key(|r| r.id);
+
+                let numbers = [1, 3, 2, 4, 6, 5];
+                numbers.dedup_by_key(|n| n % 2);
+
+                (records.iter().map(|r| r.id).collect_vec(), numbers)
+            }
+        },
+        (vec![1, 2, 1], vec![1, 2, 5]),
+    };
+
+    // NB: keys are computed for every element before any are removed, so a
+    // failing key function leaves the vector as it was.
+    let v = run_until_panic(
+        r#"
+        pub fn main(v) {
+            v.extend([1, 1, 2, 2, 3]);
+            v.dedup_by_key(|x| if x == 3 { panic("stop") } else { x });
+        }
+        "#,
+        "stop",
+    );
+
+    assert_eq!(v, vec![1, 1, 2, 2, 3]);
+}
+
+#[test]
+fn test_vec_group_by() {
+    assert_eq! {
+        rune! { Vec<Vec<i64>> =>
+            pub fn main() {
+                [1, 1, 2, 3, 3, 3, 1].group_by(|a, b| a == b)
+            }
+        },
+        vec![vec![1, 1], vec![2], vec![3, 3, 3], vec![1]],
+    };
+
+    assert_eq! {
+        rune! { Vec<Vec<i64>> =>
+            pub fn main() {
+                [1, 2, 3, 5, 6, 8].group_by(|a, b| a + 1 == b)
+            }
+        },
+        vec![vec![1, 2, 3], vec![5, 6], vec![8]],
+    };
+
+    assert_eq! {
+        rune! { usize =>
+            pub fn main() {
+                [].group_by(|a, b| a == b).len()
+            }
+        },
+        0,
+    };
+
+    // NB: the closure is called with neighbouring pairs in order, up until
+    // the one which errors.
+    let log = run_until_panic(
+        r#"
+        pub fn main(log) {
+            [1, 1, 2, 3, 4].group_by(|a, b| {
+                log.push(a * 10 + b);
+                if b == 3 { panic("stop") }
+                a == b
+            });
+        }
+        "#,
+        "stop",
+    );
+
+    assert_eq!(log, vec![11, 12, 23]);
+}
+
+#[test]
+fn test_vec_min_max_by_key() {
+    assert_eq! {
+        rune! { (String, String) =>
+            pub fn main() {
+                let records = [
+                    #{name: "a", score: 2},
+                    #{name: "b", score: 3},
+                    #{name: "c", score: 1},
+                    #{name: "d", score: 3},
+                    #{name: "e", score: 1},
+                ];
+
+                let max = records.max_by_key(|r| r.score).unwrap();
+                let min = records.min_by_key(|r| r.score).unwrap();
+                (max.name, min.name)
+            }
+        },
+        (String::from("d"), String::from("c")),
+    };
+
+    assert_eq!(rune!(Option<i64> => pub fn main() { [].max_by_key(|n| n) }), None);
+
+    // NB: the key of the current extreme is kept rather than recomputed, so
+    // the key function is called once for every element in order, and not
+    // after it fails.
+    let log = run_until_panic(
+        r#"
+        pub fn main(log) {
+            [3, 1, 2, 5].min_by_key(|n| { log.push(n); if n == 2 { panic("stop") } n })
+        }
+        "#,
+        "stop",
+    );
+
+    assert_eq!(log, vec![3, 1, 2]);
+
+    assert_vm_error!(
+        r#"pub fn main() { [1.0, 2.0].max_by_key(|n| n / 0.0 - n / 0.0) }"#,
+        VmErrorKind::Incomparable { .. } => {}
+    );
+}
+
+#[test]
+fn test_vec_reversed() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let v = [1, 2, 3, 4];
+                let r = v.reversed();
+                r.push(0);
+                (v, r, v.get(1..).unwrap().reversed())
+            }
+        },
+        (vec![1, 2, 3, 4], vec![4, 3, 2, 1, 0], vec![4, 3, 2]),
+    };
+
+    assert_eq!(rune!(Vec<i64> => pub fn main() { [].reversed() }), Vec::<i64>::new());
+}